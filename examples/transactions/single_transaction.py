@@ -32,7 +32,7 @@ async def main():
     outputs = [
         {"address": address, "amount": total},
     ]
-    tx = create_transaction(utxos, outputs, 0, None, 1)
+    tx = create_transaction(utxos, outputs, 0, None, 1, network_id="testnet-10")
 
     mass = calculate_transaction_mass("testnet-10", tx)
 
@@ -42,7 +42,7 @@ async def main():
     outputs = [
         {"address": address, "amount": int(total - (fee_rate * mass))},
     ]
-    tx = create_transaction(utxos, outputs, 0, None, 1)
+    tx = create_transaction(utxos, outputs, 0, None, 1, network_id="testnet-10")
 
     tx_signed = sign_transaction(tx, [private_key], True)
 
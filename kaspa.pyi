@@ -143,6 +143,15 @@ class Address:
         Returns:
             str: A shortened address string in the format `prefix:start....end`.
         """
+    def to_script_public_key(self) -> ScriptPublicKey:
+        r"""
+        Get the pay-to-address locking script for this address.
+        
+        The script is computed once and cached on this Address instance.
+        
+        Returns:
+            ScriptPublicKey: The locking script paying to this address.
+        """
     def __str__(self) -> builtins.str:
         r"""
         The string representation.
@@ -151,6 +160,93 @@ class Address:
             str: The address as a string
         """
 
+class AddressNetworkMismatchError(builtins.ValueError):
+    r"""
+    Raised when an address does not belong to the expected network.
+    """
+    ...
+
+@typing.final
+class AddressBook:
+    r"""
+    A mapping of addresses to human-readable labels.
+    
+    Each address has at most one label and each label names at most one
+    address, so lookups work in both directions.
+    """
+    def __new__(cls) -> AddressBook:
+        r"""
+        Create a new, empty address book.
+        
+        Returns:
+            AddressBook: A new AddressBook instance.
+        """
+    def add(self, address: Address | str, label: builtins.str) -> None:
+        r"""
+        Label an address, replacing any label it already has.
+        
+        Args:
+            address: The address as an Address or string.
+            label: The label for the address.
+        
+        Raises:
+            ValueError: If the label is already assigned to a different address.
+            Exception: If the address string is invalid.
+        """
+    def remove(self, address: Address | str) -> typing.Optional[builtins.str]:
+        r"""
+        Remove an address and its label.
+        
+        Args:
+            address: The address as an Address or string.
+        
+        Returns:
+            str | None: The removed label, or None if the address was not present.
+        """
+    def get_label(self, address: Address | str) -> typing.Optional[builtins.str]:
+        r"""
+        Get the label of an address.
+        
+        Args:
+            address: The address as an Address or string.
+        
+        Returns:
+            str | None: The label, or None if the address is not in the book.
+        """
+    def get_address(self, label: builtins.str) -> typing.Optional[Address]:
+        r"""
+        Get the address with a label.
+        
+        Args:
+            label: The label to look up.
+        
+        Returns:
+            Address | None: The address, or None if no address has the label.
+        """
+    def to_dict(self) -> dict:
+        r"""
+        Get a dictionary representation of the AddressBook.
+        
+        Returns:
+            dict: Address strings mapped to their labels.
+        """
+    @classmethod
+    def from_dict(cls, dict: dict) -> AddressBook:
+        r"""
+        Create an AddressBook from a dictionary.
+        
+        Args:
+            dict: Addresses (Address or string) mapped to their labels.
+        
+        Returns:
+            AddressBook: A new AddressBook instance.
+        
+        Raises:
+            ValueError: If two addresses share a label.
+            Exception: If an address is invalid.
+        """
+    def __len__(self) -> builtins.int: ...
+
 @typing.final
 class Balance:
     r"""
@@ -172,6 +268,21 @@ class Balance:
         Total outgoing balance in sompi.
         """
     @property
+    def mature_kas(self) -> builtins.str:
+        r"""
+        Total mature balance in KAS as an exact decimal string (e.g. "0.00000001").
+        """
+    @property
+    def pending_kas(self) -> builtins.str:
+        r"""
+        Total pending balance in KAS as an exact decimal string.
+        """
+    @property
+    def outgoing_kas(self) -> builtins.str:
+        r"""
+        Total outgoing balance in KAS as an exact decimal string.
+        """
+    @property
     def mature_utxo_count(self) -> builtins.int:
         r"""
         Number of mature UTXOs.
@@ -213,9 +324,13 @@ class Binary:
     data in multiple convenient forms from Python.
     
     Accepts:
-        - str: A hexadecimal string (e.g., "deadbeef").
+        - str: An even-length hexadecimal string, optionally prefixed with
+          `0x` (e.g., "deadbeef" or "0xdeadbeef").
         - bytes: Python bytes object.
+        - bytearray: Python bytearray object.
         - list[int]: A list of byte values (0-255).
+    
+    Any other value raises `ValueError`.
     """
     ...
 
@@ -288,27 +403,42 @@ class Generator:
     Handles UTXO selection, fee calculation, change outputs, and transaction
     splitting for large transfers.
     """
-    def __new__(cls, network_id: NetworkId, entries: UtxoEntries, change_address: Address, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[builtins.int] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None) -> Generator:
+    def __new__(cls, network_id: NetworkId, entries: UtxoEntries, change_address: typing.Optional[Address] = None, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[builtins.int] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None, no_change: typing.Optional[builtins.bool] = None, current_daa_score: typing.Optional[builtins.int] = None, change_script: typing.Optional[ScriptPublicKey] = None) -> Generator:
         r"""
         Create a new transaction generator.
         
         Args:
             network_id: The network to build transactions for.
             entries: List of UTXO entries to spend from.
-            change_address: Address to send change to.
+            change_address: Address to send change to. Required unless `change_script`
+                is given.
             outputs: Optional list of payment outputs.
-            payload: Optional transaction payload (OP_RETURN data).
+            payload: Optional payload attached to the final transaction. Its size is
+                included in the transaction mass (and fee).
             fee_rate: Optional fee rate multiplier.
-            priority_fee: Additional fee in sompi.
+            priority_fee: Additional fee in sompi added to the final transaction on top
+                of the network fee. Paid by the sender (deducted from change).
             priority_entries: UTXOs to use first.
             sig_op_count: Signature operations per input (default: 1).
             minimum_signatures: For multisig fee estimation.
+            no_change: Send all remaining funds (minus fees) to the single payment
+                output instead of creating a change output (default: False).
+            current_daa_score: If set, coinbase UTXOs that are not yet mature at this
+                DAA score are skipped.
+            change_script: Locking script to send change to, used instead of
+                `change_address`. Must be a standard pay-to-pubkey, pay-to-pubkey-ECDSA
+                or pay-to-script-hash script.
         
         Returns:
             Generator: A new Generator instance.
         
         Raises:
-            Exception: If generator creation fails.
+            ValueError: If neither `change_address` nor `change_script` is given, or
+                `change_script` is not a standard script.
+            Exception: If generator creation fails, or `no_change` is set with more than one output.
+        
+        Iterating the generator, or calling `estimate()`, raises `InsufficientFundsError`
+        if the entries cannot cover the outputs plus fees.
         """
     def estimate(self) -> GeneratorSummary:
         r"""
@@ -318,6 +448,7 @@ class Generator:
             GeneratorSummary: A summary with fee, transaction count, and other details.
         
         Raises:
+            InsufficientFundsError: If the entries cannot cover the outputs plus fees.
             Exception: If estimation fails.
         """
     def summary(self) -> GeneratorSummary:
@@ -339,6 +470,7 @@ class Generator:
             PendingTransaction | None: The next transaction to sign and submit.
         
         Raises:
+            InsufficientFundsError: If the entries cannot cover the outputs plus fees.
             Exception: If transaction generation fails.
         """
 
@@ -379,6 +511,14 @@ class GeneratorSummary:
             int: The aggregate fee amount.
         """
     @property
+    def total_fees_kas(self) -> builtins.str:
+        r"""
+        The total fees across all generated transactions in KAS.
+        
+        Returns:
+            str: The aggregate fee amount as an exact decimal string (e.g. "0.00002036").
+        """
+    @property
     def transactions(self) -> builtins.int:
         r"""
         The number of transactions generated.
@@ -387,6 +527,26 @@ class GeneratorSummary:
             int: The transaction count.
         """
     @property
+    def compound_transactions(self) -> builtins.int:
+        r"""
+        The number of intermediate (compound) transactions generated.
+        
+        Compound transactions consolidate UTXOs when the inputs needed for a
+        payment exceed the mass limit of a single transaction. Their outputs
+        feed the final payment transaction.
+        
+        Returns:
+            int: The compound transaction count.
+        """
+    @property
+    def compound_fees(self) -> builtins.int:
+        r"""
+        The total fees paid by compound transactions in sompi.
+        
+        Returns:
+            int: The aggregate compound transaction fee amount.
+        """
+    @property
     def final_amount(self) -> typing.Optional[builtins.int]:
         r"""
         The final transaction amount in sompi.
@@ -432,6 +592,33 @@ class Hash:
         Returns:
             str: A 64-character hex string.
         """
+    @staticmethod
+    def from_int(value: builtins.int) -> Hash:
+        r"""
+        Create a Hash from an integer.
+        
+        The integer is read as big-endian, so the most significant byte comes
+        first, matching the order of the hex string.
+        
+        Args:
+            value: An integer from 0 to 2**256 - 1.
+        
+        Returns:
+            Hash: A new Hash instance.
+        
+        Raises:
+            ValueError: If `value` is negative or does not fit in 32 bytes.
+        """
+    def to_int(self) -> builtins.int:
+        r"""
+        Convert the hash to an integer.
+        
+        The hash bytes are read as big-endian, so `Hash.from_int(h.to_int()) == h`
+        and `h.to_int() == int(h.to_string(), 16)`.
+        
+        Returns:
+            int: The hash as a non-negative integer.
+        """
     def __str__(self) -> builtins.str:
         r"""
         The string representation.
@@ -444,6 +631,12 @@ class Hash:
         The byte representation
         """
 
+class InsufficientFundsError(builtins.Exception):
+    r"""
+    Raised when UTXOs cannot cover the outputs plus fees. The `available`, `required` and `deficit` attributes hold the amounts in sompi.
+    """
+    ...
+
 @typing.final
 class Keypair:
     r"""
@@ -511,6 +704,38 @@ class Keypair:
         Returns:
             Address: The derived ECDSA address.
         """
+    def export(self, network: str | NetworkType) -> dict:
+        r"""
+        Export all key forms and the Schnorr address in one call.
+        
+        The WIF uses the Bitcoin-style encoding of a compressed key
+        (version byte 0x80 on mainnet, 0xef on other networks).
+        
+        Args:
+            network: The network type for address and WIF encoding.
+        
+        Returns:
+            dict: A dictionary with keys:
+                - 'private_key' (str): The private key as hex.
+                - 'wif' (str): The private key in Wallet Import Format.
+                - 'public_key' (str): The full public key as hex.
+                - 'xonly_public_key' (str): The x-only public key as hex.
+                - 'address' (str): The Schnorr address, same as `to_address()`.
+        """
+    def sign_message(self, message: builtins.str, no_aux_rand: builtins.bool = False) -> builtins.bytes:
+        r"""
+        Sign an arbitrary message with this keypair's private key.
+        
+        Args:
+            message: The message string to sign.
+            no_aux_rand: If True, use deterministic signing (default: False).
+        
+        Returns:
+            bytes: The 64-byte signature.
+        
+        Raises:
+            Exception: If signing fails.
+        """
     @staticmethod
     def random() -> Keypair:
         r"""
@@ -552,12 +777,15 @@ class Mnemonic:
             str: The raw entropy in hexadecimal.
         """
     @entropy.setter
-    def entropy(self, value: builtins.str) -> None:
+    def entropy(self, value: Binary) -> None:
         r"""
         Set the entropy directly.
         
         Args:
-            value: The entropy as a hex string (16 or 32 bytes).
+            value: The entropy as hex, bytes, or a list of ints (16 or 32 bytes).
+        
+        Raises:
+            ValueError: If the value is not valid binary data or not 16 or 32 bytes.
         """
     @property
     def phrase(self) -> builtins.str:
@@ -594,6 +822,9 @@ class Mnemonic:
         r"""
         Validate a mnemonic phrase.
         
+        Checks the word count, that every word is in the language word list,
+        and the BIP-39 checksum. Returns False rather than raising for invalid phrases.
+        
         Args:
             phrase: The mnemonic phrase to validate.
             language: Optional language for validation (default: English).
@@ -662,7 +893,6 @@ class NetworkId:
         Returns:
             int: The default P2P port number.
         """
-    def __eq__(self, other: builtins.object) -> builtins.bool: ...
     def __new__(cls, network_id: typing.Any) -> NetworkId:
         r"""
         Create a new NetworkId.
@@ -723,6 +953,23 @@ class NetworkId:
         Returns:
             str: The NetworkId as a string
         """
+    def __eq__(self, other: builtins.object) -> builtins.bool:
+        r"""
+        Compare with a NetworkId, a network string, or a NetworkType.
+        
+        Strings are parsed as a network id (e.g. "mainnet", "testnet-10").
+        A NetworkType matches any NetworkId of that type, regardless of suffix.
+        
+        Returns:
+            bool: True if the values refer to the same network, or
+                NotImplemented for other types.
+        """
+
+class NonStandardTransactionError(builtins.ValueError):
+    r"""
+    Raised when a transaction violates a mempool standardness rule. The `rule` attribute names the violated rule.
+    """
+    ...
 
 @typing.final
 class Notification:
@@ -770,6 +1017,50 @@ class PaymentOutput:
     Represents a single output in a transaction, specifying where funds
     should be sent and how much. Used with Generator and create_transactions.
     """
+    @property
+    def address(self) -> Address:
+        r"""
+        The destination address.
+        
+        Returns:
+            Address: The address receiving the payment.
+        """
+    @property
+    def amount(self) -> builtins.int:
+        r"""
+        The amount in sompi.
+        
+        Returns:
+            int: The payment amount in sompi.
+        """
+    def __new__(cls, address: Address | str, amount: builtins.int) -> PaymentOutput:
+        r"""
+        Create a new payment output.
+        
+        Args:
+            address: The destination address, as an Address or string.
+            amount: The amount to send in sompi.
+        
+        Returns:
+            PaymentOutput: A new PaymentOutput instance.
+        
+        Raises:
+            Exception: If the address is invalid.
+        """
+    def to_transaction_output(self) -> TransactionOutput:
+        r"""
+        Convert to a transaction output paying `amount` to `address`.
+        
+        Returns:
+            TransactionOutput: An output with the address's pay-to-address locking script.
+        """
+    def __int__(self) -> builtins.int:
+        r"""
+        The amount in sompi, so outputs can be used with `int()` and `sum()`.
+        
+        Returns:
+            int: The payment amount in sompi.
+        """
     def __eq__(self, other: PaymentOutput) -> builtins.bool: ...
 
 @typing.final
@@ -867,6 +1158,16 @@ class PendingTransaction:
         Returns:
             list[Address]: List of addresses.
         """
+    def input_addresses(self) -> builtins.list[typing.Optional[Address]]:
+        r"""
+        Get the address of the UTXO spent by each input, in input order.
+        
+        Use this to tell which key has to sign each input when the transaction
+        spends UTXOs from several addresses.
+        
+        Returns:
+            list[Address | None]: The address per input, or None if the UTXO has no address.
+        """
     def get_utxo_entries(self) -> builtins.list[UtxoEntryReference]:
         r"""
         Get the UTXO entries being spent by this transaction.
@@ -937,6 +1238,47 @@ class PendingTransaction:
             Exception: If submission fails.
         """
 
+@typing.final
+class PendingTransactionBatch:
+    r"""
+    An ordered batch of pending transactions produced by `create_transactions`.
+    
+    Transactions are kept in the order the Generator produced them. When
+    UTXOs need compounding, intermediate (batch) transactions come first and
+    the final transaction spends their outputs, so the batch must be
+    submitted in order.
+    
+    Supports `len()`, indexing, and iteration like a list.
+    """
+    @property
+    def transactions(self) -> builtins.list[PendingTransaction]:
+        r"""
+        The pending transactions in submission order.
+        
+        Returns:
+            list[PendingTransaction]: The pending transactions.
+        """
+    def submit_all(self, rpc_client: RpcClient) -> typing.Any:
+        r"""
+        Submit all transactions in order (async).
+        
+        Each transaction is submitted only after the previous one was accepted
+        by the node. Submission stops at the first failure.
+        
+        Args:
+            rpc_client: The RPC client for submission.
+        
+        Returns:
+            list[str]: The submitted transaction IDs, in submission order.
+        
+        Raises:
+            Exception: If any submission fails. Transactions before the failing
+                one remain submitted.
+        """
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(self, index: builtins.int) -> PendingTransaction: ...
+    def __iter__(self) -> typing.Iterator[typing.Any]: ...
+
 @typing.final
 class PrivateKey:
     r"""
@@ -1121,6 +1463,18 @@ class PublicKey:
         Returns:
             XOnlyPublicKey: The x-only representation.
         """
+    def verify_message(self, message: builtins.str, signature: Binary) -> builtins.bool:
+        r"""
+        Verify a message signature made with the matching private key.
+        
+        Args:
+            message: The original message.
+            signature: The 64-byte signature as bytes, hex, or a list of ints.
+        
+        Returns:
+            bool: True if the signature is valid, False otherwise (including
+                signatures of the wrong length).
+        """
     def fingerprint(self) -> typing.Optional[builtins.str]:
         r"""
         Get the key fingerprint (first 4 bytes of hash).
@@ -1240,6 +1594,28 @@ class PublicKeyGenerator:
         Raises:
             Exception: If derivation fails.
         """
+    def receive_addresses_parallel(self, network_type: str | NetworkType, start: builtins.int, end: builtins.int, threads: typing.Optional[builtins.int] = None) -> builtins.list[Address]:
+        r"""
+        Derive a range of receive addresses in parallel.
+        
+        Releases the GIL and spreads derivation across a thread pool. The
+        result is identical to `receive_addresses` and is returned in index
+        order. Useful when scanning large gap-limit ranges on wallet restore.
+        
+        Args:
+            network_type: The network type for address encoding.
+            start: Start index (inclusive).
+            end: End index (exclusive).
+            threads: Optional number of worker threads. Defaults to the
+                global pool, sized to the number of CPUs.
+        
+        Returns:
+            list[Address]: The derived addresses.
+        
+        Raises:
+            ValueError: If `threads` is 0.
+            Exception: If derivation fails.
+        """
     def receive_address(self, network_type: str | NetworkType, index: builtins.int) -> Address:
         r"""
         Derive a receive address at the given index.
@@ -1395,6 +1771,22 @@ class PublicKeyGenerator:
         Raises:
             Exception: If derivation fails.
         """
+    def derive_pubkey(self, path: str | DerivationPath) -> PublicKey:
+        r"""
+        Derive a public key at a path relative to the generator's account key.
+        
+        Only non-hardened components are allowed, since hardened derivation
+        requires the private key.
+        
+        Args:
+            path: A derivation path string (e.g., "m/0/5") or DerivationPath.
+        
+        Returns:
+            PublicKey: The derived public key.
+        
+        Raises:
+            Exception: If the path contains a hardened component or derivation fails.
+        """
     def to_string(self) -> builtins.str:
         r"""
         Get the string representation of this generator.
@@ -1596,6 +1988,22 @@ class RpcClient:
         Raises:
             Exception: If the event type is invalid.
         """
+    def add_utxos_changed_listener(self, addresses: typing.Sequence[Address], callback: typing.Any, *args: typing.Any, **kwargs: typing.Any) -> None:
+        r"""
+        Register a callback for UTXO changes of specific addresses.
+        
+        The callback receives the same event as a `utxos-changed` listener,
+        but `added` and `removed` only hold entries for `addresses`. Events
+        touching none of them are dropped before Python is invoked. The
+        addresses must still be subscribed via `subscribe_utxos_changed()`.
+        Remove the callback with `remove_event_listener("utxos-changed", callback)`.
+        
+        Args:
+            addresses: The addresses the callback is interested in.
+            callback: Function to call when UTXOs of the addresses change.
+            *args: Additional arguments to pass to callback.
+            **kwargs: Additional keyword arguments to pass to callback.
+        """
     def remove_event_listener(self, event: NotificationEvent, callback: typing.Optional[typing.Any] = None) -> None:
         r"""
         Remove an event listener.
@@ -1623,6 +2031,31 @@ class RpcClient:
         r"""
         Remove all registered event listeners.
         """
+    def call(self, method: builtins.str, params: typing.Optional[dict] = None, timeout: typing.Optional[builtins.int] = None) -> typing.Any:
+        r"""
+        Call an RPC method by name with raw parameters (async).
+        
+        An escape hatch for RPCs this client has no dedicated method for.
+        `params` is sent to the node as-is under the wRPC method `method`,
+        which may be given in camelCase (`"getServerInfo"`) or snake_case
+        (`"get_server_info"`). Neither the request nor the response is
+        validated: the response is the node's raw reply, and its shape may
+        change between node versions. Only available on JSON-encoded clients.
+        
+        Args:
+            method: The wRPC method name, in camelCase or snake_case.
+            params: The request parameters (default: empty).
+            timeout: Optional call timeout in milliseconds.
+        
+        Returns:
+            dict: The raw response.
+        
+        Raises:
+            ValueError: If `method` is not a wRPC method, or the client does not
+                use JSON encoding.
+            RpcTimeoutError: If the call exceeds `timeout`.
+            Exception: If the node rejects the request or the call fails.
+        """
     def subscribe_utxos_changed(self, addresses: typing.Sequence[Address]) -> typing.Any:
         r"""
         Subscribe to UTXO changes for specific addresses (async).
@@ -1677,48 +2110,54 @@ class RpcClient:
     def unsubscribe_sink_blue_score_changed(self) -> typing.Any: ...
     def subscribe_virtual_daa_score_changed(self) -> typing.Any: ...
     def unsubscribe_virtual_daa_score_changed(self) -> typing.Any: ...
-    def get_block_count(self, request: GetBlockCountRequest | None = None) -> GetBlockCountResponse: ...
-    def get_block_dag_info(self, request: GetBlockDagInfoRequest | None = None) -> GetBlockDagInfoResponse: ...
-    def get_coin_supply(self, request: GetCoinSupplyRequest | None = None) -> GetCoinSupplyResponse: ...
-    def get_connected_peer_info(self, request: GetConnectedPeerInfoRequest | None = None) -> GetConnectedPeerInfoResponse: ...
-    def get_info(self, request: GetInfoRequest | None = None) -> GetInfoResponse: ...
-    def get_peer_addresses(self, request: GetPeerAddressesRequest | None = None) -> GetPeerAddressesResponse: ...
-    def get_metrics(self, request: GetMetricsRequest | None = None) -> GetMetricsResponse: ...
-    def get_connections(self, request: GetConnectionsRequest | None = None) -> GetConnectionsResponse: ...
-    def get_sink(self, request: GetSinkRequest | None = None) -> GetSinkResponse: ...
-    def get_sink_blue_score(self, request: GetSinkBlueScoreRequest | None = None) -> GetSinkBlueScoreResponse: ...
-    def ping(self, request: PingRequest | None = None) -> PingResponse: ...
-    def shutdown(self, request: ShutdownRequest | None = None) -> ShutdownResponse: ...
-    def get_server_info(self, request: GetServerInfoRequest | None = None) -> GetServerInfoResponse: ...
-    def get_sync_status(self, request: GetSyncStatusRequest | None = None) -> GetSyncStatusResponse: ...
-    def get_fee_estimate(self, request: GetFeeEstimateRequest | None = None) -> GetFeeEstimateResponse: ...
-    def get_current_network(self, request: GetCurrentNetworkRequest | None = None) -> GetCurrentNetworkResponse: ...
-    def get_system_info(self, request: GetSystemInfoRequest | None = None) -> GetSystemInfoResponse: ...
-    def add_peer(self, request: AddPeerRequest) -> AddPeerResponse: ...
-    def ban(self, request: BanRequest) -> BanResponse: ...
-    def estimate_network_hashes_per_second(self, request: EstimateNetworkHashesPerSecondRequest) -> EstimateNetworkHashesPerSecondResponse: ...
-    def get_balance_by_address(self, request: GetBalanceByAddressRequest) -> GetBalanceByAddressResponse: ...
-    def get_balances_by_addresses(self, request: GetBalancesByAddressesRequest) -> GetBalancesByAddressesResponse: ...
-    def get_block(self, request: GetBlockRequest) -> GetBlockResponse: ...
-    def get_blocks(self, request: GetBlocksRequest) -> GetBlocksResponse: ...
-    def get_block_template(self, request: GetBlockTemplateRequest) -> GetBlockTemplateResponse: ...
-    def get_current_block_color(self, request: GetCurrentBlockColorRequest) -> GetCurrentBlockColorResponse: ...
-    def get_daa_score_timestamp_estimate(self, request: GetDaaScoreTimestampEstimateRequest) -> GetDaaScoreTimestampEstimateResponse: ...
-    def get_fee_estimate_experimental(self, request: GetFeeEstimateExperimentalRequest) -> GetFeeEstimateExperimentalResponse: ...
-    def get_headers(self, request: GetHeadersRequest) -> GetHeadersResponse: ...
-    def get_mempool_entries(self, request: GetMempoolEntriesRequest) -> GetMempoolEntriesResponse: ...
-    def get_mempool_entries_by_addresses(self, request: GetMempoolEntriesByAddressesRequest) -> GetMempoolEntriesByAddressesResponse: ...
-    def get_mempool_entry(self, request: GetMempoolEntryRequest) -> GetMempoolEntryResponse: ...
-    def get_subnetwork(self, request: GetSubnetworkRequest) -> GetSubnetworkResponse: ...
-    def get_utxos_by_addresses(self, request: GetUtxosByAddressesRequest) -> GetUtxosByAddressesResponse: ...
-    def get_utxo_return_address(self, request: GetUtxoReturnAddressRequest) -> GetUtxoReturnAddressResponse: ...
-    def get_virtual_chain_from_block(self, request: GetVirtualChainFromBlockRequest) -> GetVirtualChainFromBlockResponse: ...
-    def get_virtual_chain_from_block_v2(self, request: GetVirtualChainFromBlockV2Request) -> GetVirtualChainFromBlockV2Response: ...
-    def resolve_finality_conflict(self, request: ResolveFinalityConflictRequest) -> ResolveFinalityConflictResponse: ...
-    def submit_block(self, request: SubmitBlockRequest) -> SubmitBlockResponse: ...
-    def submit_transaction(self, request: SubmitTransactionRequest) -> SubmitTransactionResponse: ...
-    def submit_transaction_replacement(self, request: SubmitTransactionReplacementRequest) -> SubmitTransactionReplacementResponse: ...
-    def unban(self, request: UnbanRequest) -> UnbanResponse: ...
+    def get_block_count(self, request: GetBlockCountRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetBlockCountResponse: ...
+    def get_block_dag_info(self, request: GetBlockDagInfoRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetBlockDagInfoResponse: ...
+    def get_coin_supply(self, request: GetCoinSupplyRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetCoinSupplyResponse: ...
+    def get_connected_peer_info(self, request: GetConnectedPeerInfoRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetConnectedPeerInfoResponse: ...
+    def get_info(self, request: GetInfoRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetInfoResponse: ...
+    def get_peer_addresses(self, request: GetPeerAddressesRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetPeerAddressesResponse: ...
+    def get_metrics(self, request: GetMetricsRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetMetricsResponse: ...
+    def get_connections(self, request: GetConnectionsRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetConnectionsResponse: ...
+    def get_sink(self, request: GetSinkRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetSinkResponse: ...
+    def get_sink_blue_score(self, request: GetSinkBlueScoreRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetSinkBlueScoreResponse: ...
+    def ping(self, request: PingRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> PingResponse: ...
+    def shutdown(self, request: ShutdownRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> ShutdownResponse: ...
+    def get_server_info(self, request: GetServerInfoRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetServerInfoResponse: ...
+    def get_sync_status(self, request: GetSyncStatusRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetSyncStatusResponse: ...
+    def get_fee_estimate(self, request: GetFeeEstimateRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetFeeEstimateResponse: ...
+    def get_current_network(self, request: GetCurrentNetworkRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetCurrentNetworkResponse: ...
+    def get_system_info(self, request: GetSystemInfoRequest | None = None, timeout: typing.Optional[builtins.int] = None) -> GetSystemInfoResponse: ...
+    def add_peer(self, request: AddPeerRequest, timeout: typing.Optional[builtins.int] = None) -> AddPeerResponse: ...
+    def ban(self, request: BanRequest, timeout: typing.Optional[builtins.int] = None) -> BanResponse: ...
+    def estimate_network_hashes_per_second(self, request: EstimateNetworkHashesPerSecondRequest, timeout: typing.Optional[builtins.int] = None) -> EstimateNetworkHashesPerSecondResponse: ...
+    def get_balance_by_address(self, request: GetBalanceByAddressRequest, timeout: typing.Optional[builtins.int] = None) -> GetBalanceByAddressResponse: ...
+    def get_balances_by_addresses(self, request: GetBalancesByAddressesRequest, timeout: typing.Optional[builtins.int] = None) -> GetBalancesByAddressesResponse: ...
+    def get_block(self, request: GetBlockRequest, timeout: typing.Optional[builtins.int] = None) -> GetBlockResponse: ...
+    def get_blocks(self, request: GetBlocksRequest, timeout: typing.Optional[builtins.int] = None) -> GetBlocksResponse: ...
+    def get_block_template(self, request: GetBlockTemplateRequest, timeout: typing.Optional[builtins.int] = None) -> GetBlockTemplateResponse: ...
+    def get_current_block_color(self, request: GetCurrentBlockColorRequest, timeout: typing.Optional[builtins.int] = None) -> GetCurrentBlockColorResponse: ...
+    def get_daa_score_timestamp_estimate(self, request: GetDaaScoreTimestampEstimateRequest, timeout: typing.Optional[builtins.int] = None) -> GetDaaScoreTimestampEstimateResponse: ...
+    def get_fee_estimate_experimental(self, request: GetFeeEstimateExperimentalRequest, timeout: typing.Optional[builtins.int] = None) -> GetFeeEstimateExperimentalResponse: ...
+    def get_headers(self, request: GetHeadersRequest, timeout: typing.Optional[builtins.int] = None) -> GetHeadersResponse: ...
+    def get_mempool_entries(self, request: GetMempoolEntriesRequest, timeout: typing.Optional[builtins.int] = None) -> GetMempoolEntriesResponse: ...
+    def get_mempool_entries_by_addresses(self, request: GetMempoolEntriesByAddressesRequest, timeout: typing.Optional[builtins.int] = None) -> GetMempoolEntriesByAddressesResponse: ...
+    def get_mempool_entry(self, request: GetMempoolEntryRequest, timeout: typing.Optional[builtins.int] = None) -> GetMempoolEntryResponse: ...
+    def get_subnetwork(self, request: GetSubnetworkRequest, timeout: typing.Optional[builtins.int] = None) -> GetSubnetworkResponse: ...
+    def get_utxos_by_addresses(self, request: GetUtxosByAddressesRequest, timeout: typing.Optional[builtins.int] = None) -> GetUtxosByAddressesResponse: ...
+    def get_utxo_return_address(self, request: GetUtxoReturnAddressRequest, timeout: typing.Optional[builtins.int] = None) -> GetUtxoReturnAddressResponse: ...
+    def get_virtual_chain_from_block(self, request: GetVirtualChainFromBlockRequest, timeout: typing.Optional[builtins.int] = None) -> GetVirtualChainFromBlockResponse: ...
+    def get_virtual_chain_from_block_v2(self, request: GetVirtualChainFromBlockV2Request, timeout: typing.Optional[builtins.int] = None) -> GetVirtualChainFromBlockV2Response: ...
+    def resolve_finality_conflict(self, request: ResolveFinalityConflictRequest, timeout: typing.Optional[builtins.int] = None) -> ResolveFinalityConflictResponse: ...
+    def submit_block(self, request: SubmitBlockRequest, timeout: typing.Optional[builtins.int] = None) -> SubmitBlockResponse: ...
+    def submit_transaction(self, request: SubmitTransactionRequest, timeout: typing.Optional[builtins.int] = None) -> SubmitTransactionResponse: ...
+    def submit_transaction_replacement(self, request: SubmitTransactionReplacementRequest, timeout: typing.Optional[builtins.int] = None) -> SubmitTransactionReplacementResponse: ...
+    def unban(self, request: UnbanRequest, timeout: typing.Optional[builtins.int] = None) -> UnbanResponse: ...
+
+class RpcTimeoutError(builtins.TimeoutError):
+    r"""
+    Raised when an RPC call does not complete within its `timeout`.
+    """
+    ...
 
 @typing.final
 class ScriptBuilder:
@@ -1746,6 +2185,9 @@ class ScriptBuilder:
         
         Returns:
             ScriptBuilder: A new ScriptBuilder initialized with the script.
+        
+        Raises:
+            Exception: If the script exceeds the maximum script size.
         """
     def add_op(self, op: typing.Any) -> ScriptBuilder:
         r"""
@@ -1843,12 +2285,19 @@ class ScriptBuilder:
         Returns:
             str: The script bytes as a hex string.
         """
+    def to_bytes(self) -> builtins.bytes:
+        r"""
+        Get the script as bytes.
+        
+        Returns:
+            bytes: The script bytes.
+        """
     def drain(self) -> builtins.str:
         r"""
         Drain and return the script, clearing the builder.
         
         Returns:
-            str: The script as a string.
+            str: The script bytes as a hex string.
         """
     def create_pay_to_script_hash_script(self) -> ScriptPublicKey:
         r"""
@@ -1870,26 +2319,98 @@ class ScriptBuilder:
         Raises:
             Exception: If encoding fails.
         """
+    def __len__(self) -> builtins.int:
+        r"""
+        The current script length in bytes.
+        
+        Scripts are limited to 10,000 bytes; `add_*` methods raise once
+        the limit would be exceeded.
+        """
     def __eq__(self, other: ScriptBuilder) -> builtins.bool: ...
 
 @typing.final
-class ScriptPublicKey:
+class ScriptHash:
     r"""
-    A script public key.
+    A pay-to-script-hash (P2SH) redeem script with its derived forms.
     
-    Represents the locking conditions for an output. This script defines
-    the conditions that must be met to spend the associated funds.
+    Bundles the locking script, the P2SH address, and the unlocking
+    signature script for a single redeem script.
     """
     @property
-    def script(self) -> builtins.str:
+    def redeem_script(self) -> builtins.str:
         r"""
-        The script bytes as a hex string.
+        The redeem script as a hex string.
         
         Returns:
-            str: The script data encoded as hexadecimal.
+            str: The redeem script.
         """
-    def __eq__(self, other: builtins.object) -> builtins.bool: ...
-    def __new__(cls, version: builtins.int, script: Binary) -> ScriptPublicKey:
+    @property
+    def script_public_key(self) -> ScriptPublicKey:
+        r"""
+        The P2SH locking script for the redeem script.
+        
+        Returns:
+            ScriptPublicKey: The P2SH locking script.
+        """
+    def __new__(cls, redeem_script: Binary) -> ScriptHash:
+        r"""
+        Create a P2SH helper for a redeem script.
+        
+        Args:
+            redeem_script: The redeem script as hex, bytes, or list.
+        
+        Returns:
+            ScriptHash: A new ScriptHash instance.
+        """
+    def address(self, network: str | NetworkType) -> Address:
+        r"""
+        Get the P2SH address for the redeem script.
+        
+        Args:
+            network: The network type for address encoding.
+        
+        Returns:
+            Address: The P2SH address.
+        
+        Raises:
+            Exception: If address extraction fails.
+        """
+    def signature_script(self, signatures: typing.Sequence[Binary]) -> builtins.str:
+        r"""
+        Build the signature script that spends a P2SH output.
+        
+        Each signature is pushed as data, in the given order, followed by
+        the redeem script.
+        
+        Args:
+            signatures: The signatures (including sighash type byte) as hex,
+                bytes, or list, in the order the redeem script consumes them.
+        
+        Returns:
+            str: The signature script as a hex string.
+        
+        Raises:
+            Exception: If a signature or the redeem script cannot be pushed.
+        """
+
+@typing.final
+class ScriptPublicKey:
+    r"""
+    A script public key.
+    
+    Represents the locking conditions for an output. This script defines
+    the conditions that must be met to spend the associated funds.
+    """
+    @property
+    def script(self) -> builtins.str:
+        r"""
+        The script bytes as a hex string.
+        
+        Returns:
+            str: The script data encoded as hexadecimal.
+        """
+    def __eq__(self, other: builtins.object) -> builtins.bool: ...
+    def __new__(cls, version: builtins.int, script: Binary) -> ScriptPublicKey:
         r"""
         Create a new script public key.
         
@@ -1900,6 +2421,23 @@ class ScriptPublicKey:
         Returns:
             ScriptPublicKey: A new ScriptPublicKey instance.
         """
+    @staticmethod
+    def from_combined(data: Binary) -> ScriptPublicKey:
+        r"""
+        Create a script public key from its combined form.
+        
+        The combined form is the 2-byte big-endian script version followed
+        by the script, as returned by RPC (e.g. "0000" + script hex).
+        
+        Args:
+            data: The combined version and script as hex, bytes, or a list of ints.
+        
+        Returns:
+            ScriptPublicKey: A new ScriptPublicKey instance.
+        
+        Raises:
+            ValueError: If the data is shorter than the 2-byte version prefix.
+        """
     def __str__(self) -> builtins.str:
         r"""
         The string representation.
@@ -1912,6 +2450,64 @@ class ScriptPublicKey:
         The byte representation
         """
 
+@typing.final
+class SubnetworkId:
+    r"""
+    A 20-byte subnetwork identifier.
+    
+    Identifies the subnetwork a transaction belongs to. Regular transactions
+    use the native subnetwork and mining rewards the coinbase subnetwork.
+    """
+    def __eq__(self, other: builtins.object) -> builtins.bool: ...
+    @staticmethod
+    def native() -> SubnetworkId:
+        r"""
+        The subnetwork of regular (non-coinbase) transactions.
+        
+        Returns:
+            SubnetworkId: The native subnetwork ID.
+        """
+    @staticmethod
+    def coinbase() -> SubnetworkId:
+        r"""
+        The subnetwork of coinbase (mining reward) transactions.
+        
+        Returns:
+            SubnetworkId: The coinbase subnetwork ID.
+        """
+    @staticmethod
+    def from_hex(hex: builtins.str) -> SubnetworkId:
+        r"""
+        Create a subnetwork ID from a hex string.
+        
+        Args:
+            hex: A 40-character hex string.
+        
+        Returns:
+            SubnetworkId: A new SubnetworkId instance.
+        
+        Raises:
+            ValueError: If `hex` is not valid hex or does not decode to 20 bytes.
+        """
+    def to_string(self) -> builtins.str:
+        r"""
+        Convert the subnetwork ID to a hex string.
+        
+        Returns:
+            str: A 40-character hex string.
+        """
+    def __str__(self) -> builtins.str:
+        r"""
+        The string representation.
+        
+        Returns:
+            str: The SubnetworkId as a hex string
+        """
+    def __bytes__(self) -> bytes:
+        r"""
+        The byte representation
+        """
+
 @typing.final
 class Transaction:
     r"""
@@ -1925,6 +2521,9 @@ class Transaction:
         r"""
         The transaction ID (hash).
         
+        Computed from the current contents when read, so it reflects changes
+        made through the setters and directly to input or output objects.
+        
         Returns:
             str: The transaction ID as a hex string.
         """
@@ -2034,6 +2633,17 @@ class Transaction:
             value: The payload as bytes or hex string.
         """
     @property
+    def payload_bytes(self) -> builtins.bytes:
+        r"""
+        The transaction payload data as raw bytes.
+        
+        Copies the payload straight into a `bytes` object, skipping the hex
+        encoding done by `payload`. Prefer this for large payloads.
+        
+        Returns:
+            bytes: The payload bytes.
+        """
+    @property
     def mass(self) -> builtins.int:
         r"""
         The transaction mass used for fee calculation.
@@ -2050,15 +2660,15 @@ class Transaction:
             value: The transaction mass value.
         """
     @subnetwork_id.setter
-    def subnetwork_id(self, value: builtins.str) -> None:
+    def subnetwork_id(self, value: Binary) -> None:
         r"""
         Set the subnetwork identifier.
         
         Args:
-            value: The subnetwork ID as a hex string.
+            value: The subnetwork ID as hex, bytes, or a list of ints.
         
         Raises:
-            Exception: If the hex string is invalid or has incorrect length.
+            ValueError: If the hex string is invalid or the value is not 20 bytes.
         """
     def is_coinbase(self) -> builtins.bool:
         r"""
@@ -2067,14 +2677,38 @@ class Transaction:
         Returns:
             bool: True if this is a coinbase (mining reward) transaction.
         """
-    def finalize(self) -> Hash:
+    def finalize(self, validate: builtins.bool = False) -> Hash:
         r"""
         Finalize the transaction and compute its ID.
         
+        The ID is always recomputed from the current inputs, outputs, and
+        fields, and stored on the transaction.
+        
+        Args:
+            validate: Also check that `gas` is 0 if the transaction is on a
+                native or built-in subnetwork (default: False).
+        
         Returns:
             Hash: The computed transaction ID.
+        
+        Raises:
+            ValueError: If `validate` is set and `gas` is not allowed on the subnetwork.
+        """
+    def verify_signatures(self) -> builtins.bool:
+        r"""
+        Verify every input's signature script against the UTXO it spends.
+        
+        Runs the script engine over each input and its UTXO entry, so this
+        catches signing bugs before the transaction is submitted. All inputs
+        must have their UTXO entries attached.
+        
+        Returns:
+            bool: True if every input validates, False otherwise.
+        
+        Raises:
+            Exception: If an input is missing its UTXO entry.
         """
-    def __new__(cls, version: builtins.int, inputs: typing.Sequence[TransactionInput], outputs: typing.Sequence[TransactionOutput], lock_time: builtins.int, subnetwork_id: Binary, gas: builtins.int, payload: Binary, mass: builtins.int) -> Transaction:
+    def __new__(cls, version: builtins.int, inputs: typing.Sequence[TransactionInput], outputs: typing.Sequence[TransactionOutput], lock_time: builtins.int, subnetwork_id: Binary, gas: builtins.int, payload: Binary, mass: builtins.int, validate: builtins.bool = False) -> Transaction:
         r"""
         Create a new transaction.
         
@@ -2087,23 +2721,72 @@ class Transaction:
             gas: Gas limit for smart contract execution.
             payload: Optional transaction payload data.
             mass: Transaction mass (for fee calculation).
+            validate: Also check that `gas` is 0 if `subnetwork_id` is a native or
+                built-in subnetwork, where consensus forbids gas (default: False).
         
         Returns:
             Transaction: A new Transaction instance.
         
         Raises:
-            Exception: If the subnetwork_id is invalid or transaction creation fails.
+            ValueError: If the subnetwork_id is not 20 bytes, or `validate` is set
+                and `gas` is not allowed on the subnetwork.
+            Exception: If transaction creation fails.
+        """
+    def set_input_utxo(self, index: builtins.int, utxo: UtxoEntryReference) -> None:
+        r"""
+        Attach a UTXO entry to one of the transaction inputs.
+        
+        Inputs need their UTXO to be signed, e.g. when the transaction was
+        loaded from a dict without UTXO data. Any UTXO already attached to the
+        input is replaced. The transaction ID is unaffected.
+        
+        Args:
+            index: The index of the input.
+            utxo: The UTXO entry spent by the input.
+        
+        Raises:
+            IndexError: If `index` is out of range.
+            ValueError: If the UTXO outpoint differs from the input's previous outpoint.
         """
     def addresses(self, network_type: str | NetworkType) -> builtins.list[Address]:
         r"""
         Extract unique addresses from transaction inputs.
         
+        Inputs whose UTXO script does not encode an address (e.g. nonstandard
+        scripts) are skipped; `unknown_scripts()` returns those scripts.
+        
         Args:
             network_type: The network type to use for address encoding.
         
         Returns:
             list[Address]: List of unique addresses referenced by inputs.
         """
+    def unknown_scripts(self) -> builtins.list[ScriptPublicKey]:
+        r"""
+        Extract unique input scripts that do not encode an address.
+        
+        These are the inputs skipped by `addresses()`, such as nonstandard
+        scripts, so auditing a transaction does not lose them.
+        
+        Returns:
+            list[ScriptPublicKey]: Unique input scripts without an address.
+        """
+    def outpoint_for_output(self, index: builtins.int) -> TransactionOutpoint:
+        r"""
+        Get the outpoint referencing one of this transaction's outputs.
+        
+        Uses the current transaction ID, so the outpoint can be spent by a
+        follow-up transaction once this one is accepted.
+        
+        Args:
+            index: The index of the output.
+        
+        Returns:
+            TransactionOutpoint: The outpoint of the output at `index`.
+        
+        Raises:
+            IndexError: If `index` is out of range.
+        """
     def to_dict(self) -> dict:
         r"""
         Get a dictionary representation of the Transaction.
@@ -2240,7 +2923,7 @@ class TransactionInput:
             dict: the TransactionInput in dictionary form.
         """
     @classmethod
-    def from_dict(cls, dict: dict) -> TransactionInput:
+    def from_dict(cls, dict: dict, validate: builtins.bool = False) -> TransactionInput:
         r"""
         Create a TransactionInput from a dictionary.
         
@@ -2251,13 +2934,18 @@ class TransactionInput:
                 - 'sequence' (int): Sequence number
                 - 'sigOpCount' (int): Signature operation count
                 - 'utxo' (dict | None): Optional UTXO entry reference dict
+            validate: Also reject inputs whose signature script size exceeds
+                the standardness limit enforced by the mempool, or that spend
+                a P2SH output and declare more sig-ops than the standard P2SH
+                limit. Useful when the dict comes from untrusted JSON.
         
         Returns:
             TransactionInput: A new TransactionInput instance.
         
         Raises:
             KeyError: If required keys are missing.
-            ValueError: If values are invalid.
+            ValueError: If values are invalid, or `validate` is set and the
+                input is non-standard.
         """
     def __eq__(self, other: TransactionInput) -> builtins.bool: ...
 
@@ -2447,6 +3135,9 @@ class UtxoContext:
         Args:
             addresses: List of Address objects or address strings.
             current_daa_score: Optional current DAA score for scan context.
+        
+        Raises:
+            Exception: If any address does not belong to the processor's network.
         """
     def unregister_addresses(self, addresses: Sequence[Address] | Sequence[str]) -> typing.Any:
         r"""
@@ -2460,6 +3151,32 @@ class UtxoContext:
         r"""
         Return a range of mature UTXO entries.
         """
+    def iter_mature(self, page_size: builtins.int) -> UtxoPageIterator:
+        r"""
+        Iterate over the mature UTXO entries in pages.
+        
+        Each page is read from the context on demand, so at most `page_size`
+        entries are held at a time. Entries added or removed while iterating
+        may shift page boundaries.
+        
+        Args:
+            page_size: Maximum number of entries per page.
+        
+        Returns:
+            UtxoPageIterator: An iterator yielding lists of UtxoEntryReference.
+        
+        Raises:
+            ValueError: If `page_size` is 0.
+        """
+    def utxos_by_address(self) -> builtins.dict[builtins.str, builtins.list[UtxoEntryReference]]:
+        r"""
+        Group the mature UTXO entries by address.
+        
+        Entries without an associated address are omitted.
+        
+        Returns:
+            dict[str, list[UtxoEntryReference]]: Mature entries keyed by address string.
+        """
 
 @typing.final
 class UtxoEntries:
@@ -2484,6 +3201,16 @@ class UtxoEntries:
         Args:
             value: List of UtxoEntryReference objects.
         """
+    def __new__(cls, items: typing.Sequence[UtxoEntryReference]) -> UtxoEntries:
+        r"""
+        Create a new collection of UTXO entry references.
+        
+        Args:
+            items: List of UtxoEntryReference objects.
+        
+        Returns:
+            UtxoEntries: A new UtxoEntries instance.
+        """
     def sort(self) -> None:
         r"""
         Sort the UTXO entries by amount in ascending order.
@@ -2503,6 +3230,30 @@ class UtxoEntries:
         Returns:
             dict: the UtxoEntries in dictionary form.
         """
+    def to_bytes(self) -> bytes:
+        r"""
+        Serialize the UtxoEntries to a compact binary form.
+        
+        Suited to caching UTXO sets on disk; read back with
+        `UtxoEntries.from_bytes()`.
+        
+        Returns:
+            bytes: The serialized UtxoEntries.
+        """
+    @staticmethod
+    def from_bytes(data: Binary) -> UtxoEntries:
+        r"""
+        Create UtxoEntries from bytes produced by `to_bytes()`.
+        
+        Args:
+            data: The serialized UtxoEntries as bytes or hex string.
+        
+        Returns:
+            UtxoEntries: A new UtxoEntries instance.
+        
+        Raises:
+            ValueError: If the data is not a valid serialized UtxoEntries.
+        """
     def __eq__(self, other: UtxoEntries) -> builtins.bool: ...
 
 @typing.final
@@ -2576,6 +3327,24 @@ class UtxoEntry:
         Returns:
             bool: True if this is a coinbase UTXO.
         """
+    def is_mature(self, current_daa_score: builtins.int, network: str | NetworkId) -> builtins.bool:
+        r"""
+        Check whether this UTXO is spendable at the given DAA score.
+        
+        Coinbase UTXOs use the network's coinbase maturity period and all
+        other UTXOs the user transaction maturity period, including any
+        overrides set through `UtxoProcessor`.
+        
+        Args:
+            current_daa_score: The current virtual DAA score.
+            network: The network the UTXO belongs to (e.g. "mainnet", "testnet-10").
+        
+        Returns:
+            bool: True if the UTXO has reached maturity.
+        
+        Raises:
+            ValueError: If the network is not a known network.
+        """
     def to_dict(self) -> dict:
         r"""
         Get a dictionary representation of the UtxoEntry.
@@ -2605,7 +3374,37 @@ class UtxoEntry:
             KeyError: If required keys are missing.
             ValueError: If values are invalid.
         """
+    def to_bytes(self) -> bytes:
+        r"""
+        Serialize the UtxoEntry to a compact binary form.
+        
+        Suited to caching UTXOs on disk; read back with `UtxoEntry.from_bytes()`.
+        
+        Returns:
+            bytes: The serialized UtxoEntry.
+        """
+    @staticmethod
+    def from_bytes(data: Binary) -> UtxoEntry:
+        r"""
+        Create a UtxoEntry from bytes produced by `to_bytes()`.
+        
+        Args:
+            data: The serialized UtxoEntry as bytes or hex string.
+        
+        Returns:
+            UtxoEntry: A new UtxoEntry instance.
+        
+        Raises:
+            ValueError: If the data is not a valid serialized UtxoEntry.
+        """
     def __eq__(self, other: UtxoEntry) -> builtins.bool: ...
+    def __int__(self) -> builtins.int:
+        r"""
+        The amount in sompi, so entries can be used with `int()` and `sum()`.
+        
+        Returns:
+            int: The UTXO value in sompi.
+        """
 
 @typing.final
 class UtxoEntryReference:
@@ -2706,6 +3505,48 @@ class UtxoEntryReference:
             KeyError: If required keys are missing.
             ValueError: If values are invalid.
         """
+    @staticmethod
+    def from_output(tx_id: Hash, index: builtins.int, output: TransactionOutput, block_daa_score: builtins.int, is_coinbase: builtins.bool) -> UtxoEntryReference:
+        r"""
+        Create a UtxoEntryReference for an output of a transaction.
+        
+        Lets an output that was just created be spent by a follow-up
+        transaction. The entry's address is left unset.
+        
+        Args:
+            tx_id: The ID of the transaction containing the output.
+            index: The index of the output within the transaction.
+            output: The transaction output.
+            block_daa_score: The DAA score of the block accepting the transaction.
+            is_coinbase: Whether the transaction is a coinbase transaction.
+        
+        Returns:
+            UtxoEntryReference: A new UtxoEntryReference instance.
+        """
+    def __int__(self) -> builtins.int:
+        r"""
+        The amount in sompi, so entries can be used with `int()` and `sum()`.
+        
+        Returns:
+            int: The UTXO value in sompi.
+        """
+
+@typing.final
+class UtxoPageIterator:
+    r"""
+    Iterator over pages of mature UTXO entries, created by `UtxoContext.iter_mature()`.
+    """
+    def __iter__(self) -> UtxoPageIterator:
+        r"""
+        Return self as an iterator.
+        """
+    def __next__(self) -> typing.Optional[builtins.list[UtxoEntryReference]]:
+        r"""
+        Get the next page of mature UTXO entries, or None when exhausted.
+        
+        Returns:
+            list[UtxoEntryReference] | None: Up to `page_size` entries.
+        """
 
 @typing.final
 class UtxoProcessor:
@@ -2743,6 +3584,19 @@ class UtxoProcessor:
         r"""
         Stop UTXO processing (async).
         """
+    def wait_for_sync(self, timeout: typing.Optional[builtins.int] = None) -> typing.Any:
+        r"""
+        Wait until the processor reports that the node is synced (async).
+        
+        Resolves once the initial sync is complete, after which balances
+        and UTXO sets read from bound contexts are reliable.
+        
+        Args:
+            timeout: Optional maximum time to wait, in milliseconds.
+        
+        Raises:
+            TimeoutError: If the processor is not synced before the timeout elapses.
+        """
     def set_network_id(self, network_id: NetworkId) -> None:
         r"""
         Set the network id for the processor.
@@ -2876,16 +3730,33 @@ class XPrv:
         Returns:
             str: The chain code.
         """
-    def __new__(cls, seed: builtins.str) -> XPrv:
+    def __new__(cls, seed: Binary) -> XPrv:
         r"""
-        Create an XPrv from a seed hex string.
+        Create an XPrv from a seed.
         
         Args:
-            seed: A hex-encoded seed (typically from Mnemonic.to_seed()).
+            seed: A hex-encoded seed (typically from Mnemonic.to_seed()),
+                or the seed as bytes.
         
         Returns:
             XPrv: A new XPrv instance.
         
+        Raises:
+            ValueError: If the seed hex is invalid.
+            Exception: If the seed is invalid.
+        """
+    @staticmethod
+    def from_seed(seed: Binary) -> XPrv:
+        r"""
+        Create an XPrv from raw seed bytes.
+        
+        Args:
+            seed: The seed as bytes, a hex string, or a list of ints
+                (e.g. a BIP-39 seed derived with a passphrase).
+        
+        Returns:
+            XPrv: The master XPrv for the seed.
+        
         Raises:
             Exception: If the seed is invalid.
         """
@@ -2894,6 +3765,9 @@ class XPrv:
         r"""
         Create an XPrv from a serialized xprv string.
         
+        Any private key prefix is accepted ("kprv", "ktrv", "xprv", "tprv", ...).
+        The version bytes are checked against the prefix.
+        
         Args:
             xprv: A Base58-encoded extended private key string.
         
@@ -2903,16 +3777,30 @@ class XPrv:
         Raises:
             Exception: If the xprv string is invalid.
         """
-    def derive_child(self, child_number: builtins.int, hardened: typing.Optional[builtins.bool] = None) -> XPrv:
+    @staticmethod
+    def from_string(xprv: builtins.str) -> XPrv:
         r"""
-        Derive a child key at the given index.
+        Create an XPrv from a serialized string. Alias of `from_xprv`.
         
         Args:
-            child_number: The child index.
-            hardened: Whether to use hardened derivation (default: False).
+            xprv: A Base58-encoded extended private key string.
         
         Returns:
-            XPrv: The derived child XPrv.
+            XPrv: A new XPrv instance.
+        
+        Raises:
+            Exception: If the string or its version bytes are invalid.
+        """
+    def derive_child(self, child_number: builtins.int, hardened: typing.Optional[builtins.bool] = None) -> XPrv:
+        r"""
+        Derive a child key at the given index.
+        
+        Args:
+            child_number: The child index.
+            hardened: Whether to use hardened derivation (default: False).
+        
+        Returns:
+            XPrv: The derived child XPrv.
         
         Raises:
             Exception: If derivation fails.
@@ -2934,8 +3822,11 @@ class XPrv:
         r"""
         Serialize to string with custom prefix.
         
+        The version bytes are those of the given prefix, e.g. "ktrv" for
+        testnet keys.
+        
         Args:
-            prefix: The key prefix (e.g., "kprv", "xprv").
+            prefix: The key prefix (e.g., "kprv", "ktrv", "xprv").
         
         Returns:
             str: The serialized extended private key.
@@ -3113,6 +4004,52 @@ class NotificationEvent(enum.Enum):
     NewBlockTemplate = ...
     Connect = ...
     Disconnect = ...
+    @property
+    def kind(self) -> builtins.str:
+        r"""
+        The event name, matching the `type` key of the event dicts passed to callbacks.
+        
+        Returns:
+            str: The event name (e.g. "UtxosChanged").
+        """
+    @staticmethod
+    def as_utxos_changed(event: dict) -> typing.Optional[dict]:
+        r"""
+        Get the payload of a `UtxosChanged` event.
+        
+        A `NotificationEvent` only names an event type, so the payload is read
+        from the event dict a callback receives.
+        
+        Args:
+            event: The event dict passed to a callback.
+        
+        Returns:
+            dict | None: A dict with "added" and "removed" keys, or None if
+                `event` is not a `UtxosChanged` event.
+        """
+    @staticmethod
+    def as_block_added(event: dict) -> typing.Optional[dict]:
+        r"""
+        Get the payload of a `BlockAdded` event.
+        
+        A `NotificationEvent` only names an event type, so the payload is read
+        from the event dict a callback receives.
+        
+        Args:
+            event: The event dict passed to a callback.
+        
+        Returns:
+            dict | None: The notification data, holding the added block under
+                "block", or None if `event` is not a `BlockAdded` event.
+        """
+    @property
+    def is_control(self) -> builtins.bool:
+        r"""
+        Whether this is an RPC connection event (`Connect` or `Disconnect`).
+        
+        Returns:
+            bool: True for connection events, False for node notifications.
+        """
 
 @typing.final
 class AddressVersion(enum.Enum):
@@ -3126,6 +4063,36 @@ class AddressVersion(enum.Enum):
     PubKeyECDSA = ...
     ScriptHash = ...
 
+    @property
+    def value(self) -> builtins.int:
+        r"""
+        The address version byte (0, 1, or 8).
+        
+        Returns:
+            int: The version byte.
+        """
+    @staticmethod
+    def from_int(value: builtins.int) -> AddressVersion:
+        r"""
+        Get the address version for a version byte.
+        
+        Args:
+            value: The version byte (0, 1, or 8).
+        
+        Returns:
+            AddressVersion: The matching address version.
+        
+        Raises:
+            ValueError: If the byte is not a known address version.
+        """
+    def __str__(self) -> builtins.str:
+        r"""
+        The version name (e.g. `PubKey`).
+        
+        Returns:
+            str: The version name.
+        """
+
 @typing.final
 class Encoding(enum.Enum):
     r"""
@@ -3141,6 +4108,24 @@ class Language(enum.Enum):
     """
     English = ...
 
+    def word_list(self) -> builtins.list[builtins.str]:
+        r"""
+        The full BIP-39 word list for this language.
+        
+        Returns:
+            list[str]: The 2048 words, ordered by index.
+        """
+    def word_at(self, index: builtins.int) -> typing.Optional[builtins.str]:
+        r"""
+        Get the word at a given index of the word list.
+        
+        Args:
+            index: The word index (0-2047).
+        
+        Returns:
+            str | None: The word, or None if the index is out of range.
+        """
+
 @typing.final
 class NetworkType(enum.Enum):
     r"""
@@ -3450,6 +4435,75 @@ def address_from_script_public_key(script_public_key: ScriptPublicKey, network:
         Exception: If address extraction fails.
     """
 
+def address_indices_with_utxos(rpc: RpcClient, pubkey_generator: PublicKeyGenerator, network: str | NetworkType, gap_limit: builtins.int = 20) -> typing.Any:
+    r"""
+    Find the used receive and change addresses of an account (async).
+    
+    Scans the receive and change branches of `pubkey_generator` from index 0,
+    fetching UTXOs for `gap_limit` addresses at a time, and stops each branch
+    once `gap_limit` consecutive addresses after the last used one are empty.
+    An address counts as used if it currently holds UTXOs, so addresses that
+    were spent down to zero are treated as empty.
+    
+    Args:
+        rpc: A connected RPC client.
+        pubkey_generator: Generator for the account's public keys.
+        network: The network type for address encoding.
+        gap_limit: Number of consecutive empty addresses that ends the scan of a
+            branch (default: 20).
+    
+    Returns:
+        dict: A dictionary with "receive" and "change" keys, each a list of dicts
+            in index order with keys:
+            - 'index' (int): The address index within the branch.
+            - 'address' (Address): The derived address.
+            - 'utxos' (list[UtxoEntryReference]): The UTXOs held by the address.
+    
+    Raises:
+        ValueError: If `gap_limit` is 0.
+        Exception: If derivation or an RPC call fails.
+    """
+
+def attach_utxos(tx: Transaction, utxos: typing.Sequence[UtxoEntryReference]) -> None:
+    r"""
+    Attach UTXO entries to the inputs of a transaction by outpoint.
+    
+    Each input receives the entry whose outpoint matches its previous outpoint,
+    replacing any UTXO already attached. Use this before signing a transaction
+    reconstructed from storage without UTXO data. Entries that no input spends
+    are ignored.
+    
+    Args:
+        tx: The transaction to update in place.
+        utxos: UTXO entries spent by the transaction inputs.
+    
+    Raises:
+        ValueError: If no entry matches the previous outpoint of an input. The
+            transaction is left unchanged in that case.
+    """
+
+def bump_fee(tx: Transaction, additional_fee: builtins.int, change_output_index: builtins.int) -> Transaction:
+    r"""
+    Raise the fee of a transaction by taking it out of its change output.
+    
+    Returns a copy of `tx` with the output at `change_output_index` reduced by
+    `additional_fee` and all signature scripts cleared, ready to be signed again
+    and submitted with `RpcClient.submit_transaction_replacement()`. The
+    original transaction is left unchanged.
+    
+    Args:
+        tx: The transaction to replace.
+        additional_fee: Amount in sompi to add to the fee.
+        change_output_index: Index of the change output that pays for the bump.
+    
+    Returns:
+        Transaction: The unsigned replacement transaction.
+    
+    Raises:
+        IndexError: If `change_output_index` is out of range.
+        ValueError: If the change output cannot cover `additional_fee`.
+    """
+
 def calculate_storage_mass(network_id: NetworkId, input_values: typing.Sequence[builtins.int], output_values: typing.Sequence[builtins.int]) -> typing.Optional[builtins.int]:
     r"""
     Calculate the storage mass for a transaction.
@@ -3464,6 +4518,9 @@ def calculate_storage_mass(network_id: NetworkId, input_values: typing.Sequence[
     
     Returns:
         int | None: The storage mass, or None if not applicable.
+    
+    Raises:
+        ValueError: If `input_values` or `output_values` is empty.
     """
 
 def calculate_transaction_fee(network_id: NetworkId, tx: Transaction, minimum_signatures: typing.Optional[builtins.int] = None) -> typing.Optional[builtins.int]:
@@ -3479,6 +4536,7 @@ def calculate_transaction_fee(network_id: NetworkId, tx: Transaction, minimum_si
         int | None: The fee in sompi, or None if mass exceeds limits.
     
     Raises:
+        ValueError: If the transaction has no inputs or no outputs.
         Exception: If mass calculation fails.
     """
 
@@ -3495,9 +4553,57 @@ def calculate_transaction_mass(network_id: NetworkId, tx: Transaction, minimum_s
         int: The calculated transaction mass.
     
     Raises:
+        ValueError: If the transaction has no inputs or no outputs.
+        Exception: If mass calculation fails.
+    """
+
+def check_transaction_standard(tx: Transaction, network: NetworkId) -> None:
+    r"""
+    Check a transaction against the mempool standardness rules.
+    
+    A preflight for "will a node accept this?". The rules are checked in the
+    order below and the first violation is raised:
+    
+    - 'version': the transaction version is above the standard version.
+    - 'signature_script_size': an input signature script is too large.
+    - 'sig_op_count': an input spending a P2SH output declares more signature
+      operations than the standard P2SH limit.
+    - 'script_version': an output script version is above the standard version.
+    - 'script_class': an output script is not a standard script.
+    - 'dust': an output is too small to be worth spending at the minimum relay fee.
+    - 'mass': the mass, estimated with one signature per input, exceeds the
+      maximum standard transaction mass.
+    
+    Fees and the validity of the spent UTXOs are not checked.
+    
+    Args:
+        tx: The transaction to check. All inputs must have their UTXO entries attached.
+        network: Network whose consensus parameters are used to compute the mass.
+    
+    Raises:
+        NonStandardTransactionError: If the transaction violates a rule. The
+            `rule` attribute holds the rule name listed above.
+        ValueError: If the transaction has no inputs or no outputs.
         Exception: If mass calculation fails.
     """
 
+def create_data_script(data: Binary) -> ScriptPublicKey:
+    r"""
+    Create a provably unspendable data carrier script.
+    
+    The script is `OP_RETURN <data>`. Outputs locked to it can never be
+    spent, so it is suited to timestamping and commitments.
+    
+    Args:
+        data: The data to embed (at most 520 bytes).
+    
+    Returns:
+        ScriptPublicKey: The data carrier locking script.
+    
+    Raises:
+        Exception: If the data exceeds the maximum script element size.
+    """
+
 def create_input_signature(tx: Transaction, input_index: builtins.int, private_key: PrivateKey, sighash_type: str | SighashType | None = SighashType.All) -> builtins.str:
     r"""
     Create a signature for a specific transaction input.
@@ -3515,6 +4621,24 @@ def create_input_signature(tx: Transaction, input_index: builtins.int, private_k
         Exception: If signing fails.
     """
 
+def create_lock_time_script(lock_time: builtins.int, redeem_script: Binary) -> builtins.str:
+    r"""
+    Create an absolute time-locked script (CLTV).
+    
+    The script is `<lock_time> OP_CHECKLOCKTIMEVERIFY <redeem_script>`.
+    The spending transaction's lock time must be at least `lock_time`.
+    
+    Args:
+        lock_time: DAA score or timestamp before which the script cannot be spent.
+        redeem_script: The script to run once the time lock is satisfied.
+    
+    Returns:
+        str: The script as a hex string.
+    
+    Raises:
+        Exception: If the script cannot be built or exceeds the maximum script size.
+    """
+
 def create_multisig_address(minimum_signatures: builtins.int, keys: typing.Sequence[PublicKey], network_type: str | NetworkType, ecdsa: typing.Optional[builtins.bool] = False, account_kind: typing.Optional[AccountKind] = None) -> Address:
     r"""
     Create a multisig address from multiple public keys.
@@ -3533,7 +4657,25 @@ def create_multisig_address(minimum_signatures: builtins.int, keys: typing.Seque
         Exception: If address creation fails.
     """
 
-def create_transaction(utxo_entry_source: UtxoEntries, outputs: Outputs, priority_fee: builtins.int, payload: typing.Optional[Binary] = None, sig_op_count: typing.Optional[builtins.int] = None) -> Transaction:
+def create_sequence_lock_script(sequence: builtins.int, redeem_script: Binary) -> builtins.str:
+    r"""
+    Create a relative time-locked script (CSV).
+    
+    The script is `<sequence> OP_CHECKSEQUENCEVERIFY <redeem_script>`.
+    The spending input's sequence must be at least `sequence`.
+    
+    Args:
+        sequence: Relative time lock value.
+        redeem_script: The script to run once the time lock is satisfied.
+    
+    Returns:
+        str: The script as a hex string.
+    
+    Raises:
+        Exception: If the script cannot be built or exceeds the maximum script size.
+    """
+
+def create_transaction(utxo_entry_source: UtxoEntries, outputs: Outputs, priority_fee: builtins.int, payload: typing.Optional[Binary] = None, sig_op_count: typing.Optional[builtins.int] = None, network_id: typing.Optional[NetworkId] = None, auto_mass: builtins.bool = True) -> Transaction:
     r"""
     Create a single transaction from UTXOs.
     
@@ -3543,19 +4685,26 @@ def create_transaction(utxo_entry_source: UtxoEntries, outputs: Outputs, priorit
         priority_fee: Priority fee in sompi.
         payload: Optional transaction payload data.
         sig_op_count: Signature operations per input (default: 1).
+        network_id: Network whose consensus parameters are used to compute
+            the mass. The mass is left unset when omitted.
+        auto_mass: Compute and set the transaction mass when `network_id` is
+            given, assuming `sig_op_count` signatures per input (default: True).
     
     Returns:
         Transaction: The created transaction (unsigned).
     
     Raises:
-        Exception: If transaction creation fails or fee exceeds input amount.
+        Exception: If transaction creation fails, fee exceeds input amount,
+            or mass calculation fails.
     """
 
-def create_transactions(network_id: NetworkId, entries: UtxoEntries, change_address: Address, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[builtins.int] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None) -> dict:
+def create_transactions(network_id: NetworkId, entries: UtxoEntries, change_address: Address, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[builtins.int] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None, no_change: typing.Optional[builtins.bool] = None, current_daa_score: typing.Optional[builtins.int] = None) -> dict:
     r"""
     Create one or more transactions with automatic UTXO selection and change handling.
     
     Handles large transfers that may require multiple transactions due to mass limits.
+    Transactions are returned in submission order: intermediate (batch)
+    transactions precede the final transaction that spends their outputs.
     
     Args:
         network_id: The network to build transactions for.
@@ -3564,19 +4713,50 @@ def create_transactions(network_id: NetworkId, entries: UtxoEntries, change_addr
         outputs: Optional list of payment outputs.
         payload: Optional transaction payload data.
         fee_rate: Optional fee rate multiplier.
-        priority_fee: Additional fee in sompi.
+        priority_fee: Additional fee in sompi added to the final transaction on top
+            of the network fee. Paid by the sender (deducted from change).
         priority_entries: UTXOs to use first.
         sig_op_count: Signature operations per input (default: 1).
         minimum_signatures: For multisig fee estimation.
+        no_change: Send all remaining funds (minus fees) to the single payment
+            output instead of creating a change output (default: False).
+        current_daa_score: If set, coinbase UTXOs that are not yet mature at this
+            DAA score are skipped.
     
     Returns:
-        dict: Dictionary with "transactions" (list) and "summary" keys.
+        dict: Dictionary with "transactions" (PendingTransactionBatch) and "summary" keys.
     
     Raises:
+        InsufficientFundsError: If the UTXOs cannot cover the outputs plus fees.
         Exception: If transaction creation fails.
     """
 
-def estimate_transactions(network_id: NetworkId, entries: UtxoEntries, change_address: Address, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[builtins.int] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None) -> GeneratorSummary:
+def derive_account(mnemonic: Mnemonic, network_type: str | NetworkType, passphrase: typing.Optional[builtins.str] = None, account_index: builtins.int = 0) -> dict:
+    r"""
+    Derive a standard (BIP-44) account from a mnemonic in one call.
+    
+    Uses the Kaspa derivation path `m/44'/111111'/<account_index>'`, with
+    receive addresses under `/0/<index>` and change addresses under `/1/<index>`.
+    
+    Args:
+        mnemonic: The mnemonic phrase.
+        passphrase: Optional BIP-39 passphrase (default: none).
+        network_type: The network type for address encoding.
+        account_index: The account index (default: 0).
+    
+    Returns:
+        dict: A dictionary with keys:
+            - 'derivation_path' (str): The account derivation path.
+            - 'xprv' (XPrv): The account extended private key.
+            - 'xpub' (XPub): The account extended public key.
+            - 'receive_address' (Address): The first receive address.
+            - 'change_address' (Address): The first change address.
+    
+    Raises:
+        Exception: If derivation fails.
+    """
+
+def estimate_transactions(network_id: NetworkId, entries: UtxoEntries, change_address: Address, outputs: typing.Optional[Outputs] = None, payload: typing.Optional[Binary] = None, fee_rate: typing.Optional[builtins.float] = None, priority_fee: typing.Optional[builtins.int] = None, priority_entries: typing.Optional[UtxoEntries] = None, sig_op_count: typing.Optional[builtins.int] = None, minimum_signatures: typing.Optional[builtins.int] = None, no_change: typing.Optional[builtins.bool] = None, current_daa_score: typing.Optional[builtins.int] = None) -> GeneratorSummary:
     r"""
     Estimate transaction fees and count without creating transactions.
     
@@ -3587,18 +4767,59 @@ def estimate_transactions(network_id: NetworkId, entries: UtxoEntries, change_ad
         outputs: Optional list of payment outputs.
         payload: Optional transaction payload data.
         fee_rate: Optional fee rate multiplier.
-        priority_fee: Additional fee in sompi.
+        priority_fee: Additional fee in sompi added to the final transaction on top
+            of the network fee. Paid by the sender (deducted from change).
         priority_entries: UTXOs to use first.
         sig_op_count: Signature operations per input (default: 1).
         minimum_signatures: For multisig fee estimation.
+        no_change: Send all remaining funds (minus fees) to the single payment
+            output instead of creating a change output (default: False).
+        current_daa_score: If set, coinbase UTXOs that are not yet mature at this
+            DAA score are skipped.
     
     Returns:
         GeneratorSummary: Summary with fee, transaction count, and other details.
     
     Raises:
+        InsufficientFundsError: If the UTXOs cannot cover the outputs plus fees.
         Exception: If estimation fails.
     """
 
+def fee_for_mass(mass: builtins.int, fee_rate: builtins.float) -> builtins.int:
+    r"""
+    Calculate the absolute fee for a transaction mass at a given fee rate.
+    
+    The fee is `mass * fee_rate` rounded up to the next whole sompi, so the
+    resulting fee rate is never below `fee_rate`.
+    
+    Args:
+        mass: The transaction mass in grams.
+        fee_rate: The fee rate in sompi per gram.
+    
+    Returns:
+        int: The fee in sompi.
+    
+    Raises:
+        ValueError: If `fee_rate` is negative or not finite, or the fee overflows.
+    """
+
+def fee_rate_for_fee(fee: builtins.int, mass: builtins.int) -> builtins.float:
+    r"""
+    Calculate the fee rate paid by a fee for a transaction mass.
+    
+    The inverse of `fee_for_mass`: returns `fee / mass` without rounding.
+    
+    Args:
+        fee: The fee in sompi.
+        mass: The transaction mass in grams.
+    
+    Returns:
+        float: The fee rate in sompi per gram.
+    
+    Raises:
+        ValueError: If `mass` is zero.
+    """
+
 def is_script_pay_to_pubkey(script: Binary) -> builtins.bool:
     r"""
     Check if a script is a pay-to-pubkey (P2PK) script.
@@ -3641,6 +4862,20 @@ def kaspa_to_sompi(kaspa: builtins.float) -> builtins.int:
     
     Returns:
         int: The amount in sompi.
+    
+    Raises:
+        ValueError: If the amount is NaN, infinite, negative, or exceeds the maximum supply.
+    """
+
+def known_networks() -> builtins.list[NetworkId]:
+    r"""
+    List the public Kaspa networks.
+    
+    Includes mainnet followed by the active testnets (e.g. testnet-10).
+    Devnet and simnet are local networks and are not listed.
+    
+    Returns:
+        list[NetworkId]: The known network ids.
     """
 
 def maximum_standard_transaction_mass() -> builtins.int:
@@ -3651,15 +4886,71 @@ def maximum_standard_transaction_mass() -> builtins.int:
         int: The maximum standard transaction mass.
     """
 
-def pay_to_address_script(address: Address) -> ScriptPublicKey:
+def merge_hashes(left: Hash, right: Hash) -> Hash:
+    r"""
+    Merge two hashes into their parent merkle tree node.
+    
+    Uses Kaspa's `MerkleBranchHash` (keyed BLAKE2b) over `left` followed by `right`.
+    
+    Args:
+        left: The left child hash.
+        right: The right child hash.
+    
+    Returns:
+        Hash: The parent node hash.
+    """
+
+def merkle_root(hashes: typing.Sequence[Hash]) -> Hash:
+    r"""
+    Compute the merkle root of a list of hashes.
+    
+    Follows the Kaspa consensus rules used for block transaction and accepted
+    ID merkle roots: a missing right sibling is replaced by the zero hash, and
+    an empty list yields the zero hash.
+    
+    Args:
+        hashes: The leaf hashes, in order.
+    
+    Returns:
+        Hash: The merkle root.
+    """
+
+def minimum_relay_fee(network_id: NetworkId, tx: Transaction, minimum_signatures: typing.Optional[builtins.int] = None) -> builtins.int:
+    r"""
+    Calculate the minimum fee a node will relay for a transaction.
+    
+    The fee is derived from the transaction's overall mass (compute and
+    storage) at the minimum relay fee rate. Transactions paying less are
+    rejected from the mempool.
+    
+    Args:
+        network_id: The network identifier.
+        tx: The transaction to calculate the minimum fee for.
+        minimum_signatures: Minimum signatures per input (default: 1).
+    
+    Returns:
+        int: The minimum relay fee in sompi.
+    
+    Raises:
+        ValueError: If the transaction has no inputs or no outputs.
+        Exception: If mass calculation fails or the mass exceeds the maximum
+            standard transaction mass.
+    """
+
+def pay_to_address_script(address: Address, network: str | NetworkType | None = None) -> ScriptPublicKey:
     r"""
     Create a pay-to-address locking script.
     
     Args:
         address: The destination address.
+        network: Optional network the address is expected to belong to.
     
     Returns:
         ScriptPublicKey: The locking script for the address.
+    
+    Raises:
+        AddressNetworkMismatchError: If `network` is given and the address
+            prefix belongs to a different network.
     """
 
 def pay_to_script_hash_script(redeem_script: Binary) -> ScriptPublicKey:
@@ -3688,6 +4979,72 @@ def pay_to_script_hash_signature_script(redeem_script: Binary, signature: Binary
         Exception: If script creation fails.
     """
 
+def select_coins(utxos: UtxoEntries, target: builtins.int, fee_rate: builtins.float, change_script: ScriptPublicKey, network_id: typing.Optional[NetworkId] = None, current_daa_score: typing.Optional[builtins.int] = None) -> dict:
+    r"""
+    Select UTXOs covering a target amount and compute the fee and change.
+    
+    UTXOs are selected largest first until they cover `target` plus the fee
+    for the resulting transaction: one input per selected UTXO, one output
+    for `target` and, if worthwhile, one change output paying to
+    `change_script`. If a change output would cost more in fees (including
+    storage mass) than it returns, it is omitted and the remainder is added
+    to the fee. The target output is assumed to use a script of the same size
+    as `change_script`, and each input to carry one signature.
+    
+    Args:
+        utxos: List of UTXO entries to select from.
+        target: Amount to pay in sompi, excluding fees.
+        fee_rate: Fee rate in sompi per gram.
+        change_script: Locking script for the change output.
+        network_id: Network whose consensus parameters are used to compute
+            the mass (default: mainnet).
+        current_daa_score: If set, coinbase UTXOs that are not yet mature at this
+            DAA score are never selected.
+    
+    Returns:
+        dict: A dictionary with keys:
+            - 'inputs' (list[UtxoEntryReference]): The selected UTXOs.
+            - 'fee' (int): The fee in sompi.
+            - 'change' (int): The change amount in sompi, 0 if there is no change output.
+    
+    Raises:
+        ValueError: If `fee_rate` is negative or not finite.
+        InsufficientFundsError: If the UTXOs cannot cover the target amount and fee.
+    """
+
+def send(rpc: RpcClient, from_private_keys: typing.Sequence[PrivateKey], outputs: Outputs, change_address: Address, network: NetworkId, fee_rate: typing.Optional[builtins.float] = None, priority_fee: builtins.int = 0, dry_run: builtins.bool = False) -> typing.Any:
+    r"""
+    Send funds from the addresses of one or more private keys (async).
+    
+    Fetches the UTXOs of the Schnorr addresses derived from `from_private_keys`,
+    skipping immature coinbase outputs, builds the transactions with a
+    Generator, signs them with the same keys and submits them in order. Pass
+    `dry_run=True` to review the signed transactions before submitting them.
+    
+    Args:
+        rpc: A connected RPC client.
+        from_private_keys: Private keys whose addresses fund the transfer.
+        outputs: List of payment outputs.
+        change_address: Address to send change to.
+        network: The network to build transactions for.
+        fee_rate: Optional fee rate multiplier.
+        priority_fee: Additional fee in sompi added to the final transaction on top
+            of the network fee (default: 0).
+        dry_run: Build and sign the transactions without submitting them
+            (default: False).
+    
+    Returns:
+        list[str]: The submitted transaction IDs, in submission order.
+        dict: With `dry_run=True`, a dictionary with "transactions"
+            (PendingTransactionBatch, signed) and "summary" (GeneratorSummary)
+            keys. Submit the batch with `PendingTransactionBatch.submit_all()`.
+    
+    Raises:
+        InsufficientFundsError: If the UTXOs cannot cover the outputs plus fees.
+        Exception: If fetching UTXOs, building, signing or submitting a
+            transaction fails. Transactions before the failing one remain submitted.
+    """
+
 def sign_message(message: builtins.str, private_key: PrivateKey, no_aux_rand: builtins.bool = False) -> builtins.str:
     r"""
     Sign an arbitrary message with a private key.
@@ -3735,6 +5092,65 @@ def sign_transaction(tx: Transaction, signer: list, verify_sig: builtins.bool) -
         Exception: If signing or verification fails.
     """
 
+def sign_transactions(txs: typing.Sequence[Transaction], signer: list, verify_sig: builtins.bool = False) -> builtins.list[Transaction]:
+    r"""
+    Sign a batch of transactions with one or more private keys.
+    
+    Equivalent to calling `sign_transaction` for each transaction, but done
+    in a single call with the GIL released for the whole batch.
+    
+    Args:
+        txs: The transactions to sign.
+        signer: List of PrivateKey objects for signing.
+        verify_sig: Whether to verify signatures after signing (default: False).
+    
+    Returns:
+        list[Transaction]: The signed transactions, in the same order.
+    
+    Raises:
+        Exception: If signing or verification of any transaction fails.
+    """
+
+def signature_from_compact(signature: Binary, sighash_type: str | SighashType | None = SighashType.All) -> builtins.str:
+    r"""
+    Encode a compact 64-byte Schnorr signature for use in a signature script.
+    
+    The signature is validated, appended with the sighash type byte, and
+    prefixed with its data push opcode, producing the same encoding as
+    `create_input_signature`.
+    
+    Args:
+        signature: The 64-byte compact signature as hex string, bytes, or list of ints.
+        sighash_type: The signature hash type (default: All).
+    
+    Returns:
+        str: The script-encoded signature as a hex string.
+    
+    Raises:
+        ValueError: If the signature is malformed.
+    """
+
+def signature_to_compact(signature: Binary) -> builtins.str:
+    r"""
+    Convert a signature into its compact 64-byte Schnorr form.
+    
+    Accepts either the script-encoded signature produced by
+    `create_input_signature` / `sign_script_hash` (66 bytes: a data push
+    opcode, the 64-byte signature and the sighash type byte) or a 65-byte
+    signature with a trailing sighash type byte. Kaspa uses Schnorr
+    signatures, which have no DER encoding; the compact form is the raw
+    64-byte signature accepted by most secp256k1 libraries.
+    
+    Args:
+        signature: The signature as hex string, bytes, or list of ints.
+    
+    Returns:
+        str: The 64-byte compact signature as a hex string.
+    
+    Raises:
+        ValueError: If the signature is malformed.
+    """
+
 def sompi_to_kaspa(sompi: builtins.int) -> builtins.float:
     r"""
     Convert sompi to KAS (1 KAS = 100,000,000 sompi).
@@ -3746,6 +5162,38 @@ def sompi_to_kaspa(sompi: builtins.int) -> builtins.float:
         float: The amount in KAS.
     """
 
+def sompi_to_kaspa_string(sompi: builtins.int) -> builtins.str:
+    r"""
+    Convert sompi to a KAS string without a network suffix.
+    
+    The conversion is exact (no floating point). Trailing zeros are trimmed.
+    
+    Args:
+        sompi: The amount in sompi.
+    
+    Returns:
+        str: Formatted string like "1.5".
+    """
+
+def sompi_to_kaspa_string_with_decimals(sompi: builtins.int, min_decimals: builtins.int = 0, max_decimals: builtins.int = 8) -> builtins.str:
+    r"""
+    Convert sompi to a KAS string with controlled number of decimal places.
+    
+    The conversion is exact (no floating point). Trailing zeros are trimmed
+    down to `min_decimals`, and digits beyond `max_decimals` are truncated.
+    
+    Args:
+        sompi: The amount in sompi.
+        min_decimals: Minimum number of decimal places (default: 0).
+        max_decimals: Maximum number of decimal places (default: 8).
+    
+    Returns:
+        str: Formatted string like "1.50".
+    
+    Raises:
+        ValueError: If `min_decimals` > `max_decimals` or `max_decimals` > 8.
+    """
+
 def sompi_to_kaspa_string_with_suffix(sompi: builtins.int, network: str | NetworkType) -> builtins.str:
     r"""
     Convert sompi to a formatted KAS string with network suffix.
@@ -3758,6 +5206,28 @@ def sompi_to_kaspa_string_with_suffix(sompi: builtins.int, network: str | Networ
         str: Formatted string like "1.5 KAS" or "1.5 TKAS".
     """
 
+def transaction_diff(a: Transaction, b: Transaction) -> dict:
+    r"""
+    Compare two transactions and report where they differ.
+    
+    Transactions are compared in their dictionary form (see
+    `Transaction.to_dict()`). Inputs and outputs are compared index by index;
+    an index present in only one of the transactions is reported as differing.
+    
+    Args:
+        a: The first transaction.
+        b: The second transaction.
+    
+    Returns:
+        dict: A dictionary with keys:
+            - 'fields' (list[str]): Top-level fields that differ (e.g. 'payload', 'mass').
+            - 'inputs' (list[int]): Indices of inputs that differ.
+            - 'outputs' (list[int]): Indices of outputs that differ.
+    
+    Raises:
+        Exception: If a transaction cannot be converted to a dictionary.
+    """
+
 def update_transaction_mass(network_id: NetworkId, tx: Transaction, minimum_signatures: typing.Optional[builtins.int] = None) -> builtins.bool:
     r"""
     Calculate and update the mass field of an unsigned transaction.
@@ -3771,6 +5241,7 @@ def update_transaction_mass(network_id: NetworkId, tx: Transaction, minimum_sign
         bool: True if mass is within limits and was updated, False if too large.
     
     Raises:
+        ValueError: If the transaction has no inputs or no outputs.
         Exception: If mass calculation fails.
     """
 
@@ -3790,6 +5261,87 @@ def verify_message(message: builtins.str, signature: builtins.str, public_key: P
         Exception: If the signature format is invalid.
     """
 
+def verify_message_by_address(message: builtins.str, signature: Binary, address: Address | str) -> builtins.bool:
+    r"""
+    Verify a message signature against the signer's address.
+    
+    The public key is taken from the address payload, so only `PubKey`
+    (Schnorr) and `PubKeyECDSA` addresses can be verified.
+    
+    Args:
+        message: The original message.
+        signature: The 64-byte signature as hex, bytes, or a list of ints.
+        address: The claimed signer's address, as an Address or string.
+    
+    Returns:
+        bool: True if the signature is valid for the address, False otherwise.
+    
+    Raises:
+        Exception: If the signature or address is malformed, or the address
+            is a `ScriptHash` address.
+    """
+
+def verify_messages(items: typing.Sequence[tuple[builtins.str, Binary, PublicKey]]) -> builtins.list[builtins.bool]:
+    r"""
+    Verify a batch of message signatures in a single call.
+    
+    Args:
+        items: A list of `(message, signature, public_key)` tuples. Signatures
+            may be hex, bytes, or a list of ints.
+    
+    Returns:
+        list[bool]: One result per item, in input order. Malformed signatures
+            yield False.
+    """
+
+def verify_script(signature_script: Binary, script_public_key: ScriptPublicKey, tx: Transaction, input_index: builtins.int) -> builtins.bool:
+    r"""
+    Run the script VM for a single transaction input.
+    
+    The input's signature script is replaced by `signature_script` and the
+    UTXO it spends is given `script_public_key` as its locking script; the
+    rest of the transaction (including all UTXO entries, which signature
+    hashing depends on) is taken from `tx`.
+    
+    Args:
+        signature_script: The unlocking script to execute.
+        script_public_key: The locking script it must satisfy.
+        tx: The spending transaction, with UTXO entries attached to every input.
+        input_index: Index of the input being spent.
+    
+    Returns:
+        bool: True if the script executes successfully, False if execution fails.
+    
+    Raises:
+        IndexError: If `input_index` is out of range.
+        Exception: If UTXO entries are missing.
+    """
+
+def wait_for_acceptance(rpc: RpcClient, transaction_id: builtins.str, timeout: builtins.int, start_hash: typing.Optional[builtins.str] = None) -> typing.Any:
+    r"""
+    Wait until a submitted transaction is accepted by the virtual chain (async).
+    
+    Polls the virtual chain from `start_hash` until a chain block accepts the
+    transaction or `timeout` elapses. Pass the sink captured before submitting
+    (`(await rpc.get_sink())["sink"]`) so an acceptance that happens before this
+    call is still seen. Without it, polling starts from the current sink and an
+    earlier acceptance is missed. Only blocks on the current selected chain
+    count, so an acceptance by a block removed in a reorg is not reported.
+    
+    Args:
+        rpc: A connected RPC client.
+        transaction_id: The ID of the submitted transaction.
+        timeout: Maximum time to wait in milliseconds.
+        start_hash: Chain block to start polling from (default: the current sink).
+    
+    Returns:
+        bool: True if the transaction was accepted, False if `timeout` elapsed first.
+    
+    Raises:
+        ValueError: If `transaction_id` or `start_hash` is not a valid hash.
+        Exception: If an RPC call fails.
+    """
+
 # =============================================================================
 # RPC Types (from kaspa_rpc.pyi)
 # =============================================================================
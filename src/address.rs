@@ -1,9 +1,20 @@
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::OnceLock;
 
 use kaspa_addresses::{Address, AddressError, Prefix, Version};
-use pyo3::{exceptions::PyException, prelude::*};
+use kaspa_consensus_core::network::NetworkType;
+use kaspa_consensus_core::tx::ScriptPublicKey;
+use kaspa_txscript::standard;
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+    types::{PyDict, PyType},
+};
 use pyo3_stub_gen::derive::*;
 
+use crate::consensus::core::script_public_key::PyScriptPublicKey;
+
 crate::wrap_unit_enum_for_py!(
     /// Kaspa Address version (`PubKey`, `PubKeyECDSA`, `ScriptHash`)
     ///-  PubKey addresses always have the version byte set to 0
@@ -16,6 +27,49 @@ crate::wrap_unit_enum_for_py!(
     }
 );
 
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyAddressVersion {
+    /// The address version byte (0, 1, or 8).
+    ///
+    /// Returns:
+    ///     int: The version byte.
+    #[getter]
+    pub fn get_value(&self) -> u8 {
+        Version::from(self.clone()) as u8
+    }
+
+    /// Get the address version for a version byte.
+    ///
+    /// Args:
+    ///     value: The version byte (0, 1, or 8).
+    ///
+    /// Returns:
+    ///     AddressVersion: The matching address version.
+    ///
+    /// Raises:
+    ///     ValueError: If the byte is not a known address version.
+    #[staticmethod]
+    pub fn from_int(value: u8) -> PyResult<PyAddressVersion> {
+        match value {
+            0 => Ok(PyAddressVersion::PubKey),
+            1 => Ok(PyAddressVersion::PubKeyECDSA),
+            8 => Ok(PyAddressVersion::ScriptHash),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown address version byte: {value}"
+            ))),
+        }
+    }
+
+    /// The version name (e.g. `PubKey`).
+    ///
+    /// Returns:
+    ///     str: The version name.
+    pub fn __str__(&self) -> String {
+        Version::from(self.clone()).to_string()
+    }
+}
+
 impl FromStr for PyAddressVersion {
     type Err = PyErr;
 
@@ -56,8 +110,12 @@ impl<'py> FromPyObject<'_, 'py> for PyAddressVersion {
 /// comprised of a public key, the public key version and the resulting checksum.
 #[gen_stub_pyclass]
 #[pyclass(name = "Address", eq)]
-#[derive(Clone, Debug, PartialEq)]
-pub struct PyAddress(pub Address);
+#[derive(Clone, Debug)]
+pub struct PyAddress(
+    pub Address,
+    // Locking script for this address, computed on first use.
+    OnceLock<ScriptPublicKey>,
+);
 
 #[gen_stub_pymethods]
 #[pymethods]
@@ -74,9 +132,10 @@ impl PyAddress {
     ///     Exception: If the address string is invalid.
     #[new]
     pub fn constructor(address: &str) -> PyResult<PyAddress> {
-        Ok(PyAddress(address.try_into().map_err(
-            |err: AddressError| PyException::new_err(err.to_string()),
-        )?))
+        let address: Address = address
+            .try_into()
+            .map_err(|err: AddressError| PyException::new_err(err.to_string()))?;
+        Ok(address.into())
     }
 
     /// Check if an address string is valid.
@@ -160,6 +219,19 @@ impl PyAddress {
         self.0.short(n)
     }
 
+    /// Get the pay-to-address locking script for this address.
+    ///
+    /// The script is computed once and cached on this Address instance.
+    ///
+    /// Returns:
+    ///     ScriptPublicKey: The locking script paying to this address.
+    pub fn to_script_public_key(&self) -> PyScriptPublicKey {
+        self.1
+            .get_or_init(|| standard::pay_to_address_script(&self.0))
+            .clone()
+            .into()
+    }
+
     /// The string representation.
     ///
     /// Returns:
@@ -169,9 +241,16 @@ impl PyAddress {
     }
 }
 
+// Compares the address only; the cached script is derived from it.
+impl PartialEq for PyAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
 impl From<Address> for PyAddress {
     fn from(value: Address) -> Self {
-        PyAddress(value)
+        PyAddress(value, OnceLock::new())
     }
 }
 
@@ -187,6 +266,175 @@ impl TryFrom<String> for PyAddress {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let inner =
             Address::try_from(value).map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(PyAddress(inner))
+        Ok(inner.into())
+    }
+}
+
+/// A mapping of addresses to human-readable labels.
+///
+/// Each address has at most one label and each label names at most one
+/// address, so lookups work in both directions.
+#[gen_stub_pyclass]
+#[pyclass(name = "AddressBook")]
+#[derive(Clone, Default)]
+pub struct PyAddressBook {
+    labels: HashMap<Address, String>,
+    addresses: HashMap<String, Address>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyAddressBook {
+    /// Create a new, empty address book.
+    ///
+    /// Returns:
+    ///     AddressBook: A new AddressBook instance.
+    #[new]
+    pub fn constructor() -> Self {
+        Self::default()
+    }
+
+    /// Label an address, replacing any label it already has.
+    ///
+    /// Args:
+    ///     address: The address as an Address or string.
+    ///     label: The label for the address.
+    ///
+    /// Raises:
+    ///     ValueError: If the label is already assigned to a different address.
+    ///     Exception: If the address string is invalid.
+    pub fn add(
+        &mut self,
+        #[gen_stub(override_type(type_repr = "Address | str"))] address: Bound<'_, PyAny>,
+        label: String,
+    ) -> PyResult<()> {
+        let address = Address::from(extract_address(&address)?);
+        if let Some(existing) = self.addresses.get(&label) {
+            if *existing != address {
+                return Err(PyValueError::new_err(format!(
+                    "Label `{label}` is already assigned to `{existing}`"
+                )));
+            }
+        }
+
+        if let Some(previous) = self.labels.insert(address.clone(), label.clone()) {
+            self.addresses.remove(&previous);
+        }
+        self.addresses.insert(label, address);
+        Ok(())
+    }
+
+    /// Remove an address and its label.
+    ///
+    /// Args:
+    ///     address: The address as an Address or string.
+    ///
+    /// Returns:
+    ///     str | None: The removed label, or None if the address was not present.
+    pub fn remove(
+        &mut self,
+        #[gen_stub(override_type(type_repr = "Address | str"))] address: Bound<'_, PyAny>,
+    ) -> PyResult<Option<String>> {
+        let address = Address::from(extract_address(&address)?);
+        let label = self.labels.remove(&address);
+        if let Some(label) = &label {
+            self.addresses.remove(label);
+        }
+        Ok(label)
+    }
+
+    /// Get the label of an address.
+    ///
+    /// Args:
+    ///     address: The address as an Address or string.
+    ///
+    /// Returns:
+    ///     str | None: The label, or None if the address is not in the book.
+    pub fn get_label(
+        &self,
+        #[gen_stub(override_type(type_repr = "Address | str"))] address: Bound<'_, PyAny>,
+    ) -> PyResult<Option<String>> {
+        let address = Address::from(extract_address(&address)?);
+        Ok(self.labels.get(&address).cloned())
+    }
+
+    /// Get the address with a label.
+    ///
+    /// Args:
+    ///     label: The label to look up.
+    ///
+    /// Returns:
+    ///     Address | None: The address, or None if no address has the label.
+    pub fn get_address(&self, label: &str) -> Option<PyAddress> {
+        self.addresses.get(label).cloned().map(PyAddress::from)
+    }
+
+    /// Get a dictionary representation of the AddressBook.
+    ///
+    /// Returns:
+    ///     dict: Address strings mapped to their labels.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (address, label) in &self.labels {
+            dict.set_item(address.to_string(), label)?;
+        }
+        Ok(dict)
+    }
+
+    /// Create an AddressBook from a dictionary.
+    ///
+    /// Args:
+    ///     dict: Addresses (Address or string) mapped to their labels.
+    ///
+    /// Returns:
+    ///     AddressBook: A new AddressBook instance.
+    ///
+    /// Raises:
+    ///     ValueError: If two addresses share a label.
+    ///     Exception: If an address is invalid.
+    #[classmethod]
+    fn from_dict(_cls: &Bound<'_, PyType>, dict: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let mut book = Self::default();
+        for (address, label) in dict.iter() {
+            book.add(address, label.extract()?)?;
+        }
+        Ok(book)
+    }
+
+    fn __len__(&self) -> usize {
+        self.labels.len()
+    }
+}
+
+// Accept either an `Address` instance or an address string.
+pub(crate) fn extract_address(value: &Bound<'_, PyAny>) -> PyResult<PyAddress> {
+    if let Ok(address) = value.extract::<PyAddress>() {
+        Ok(address)
+    } else if let Ok(s) = value.extract::<String>() {
+        PyAddress::try_from(s).map_err(|err| PyException::new_err(format!("{}", err)))
+    } else {
+        Err(PyException::new_err(
+            "Addresses must be either an Address instance or a string",
+        ))
+    }
+}
+
+pyo3_stub_gen::create_exception!(
+    kaspa,
+    AddressNetworkMismatchError,
+    PyValueError,
+    "Raised when an address does not belong to the expected network."
+);
+
+// Ensure `address` belongs to `network_type`, raising `AddressNetworkMismatchError` otherwise.
+pub(crate) fn check_address_network(address: &Address, network_type: NetworkType) -> PyResult<()> {
+    let expected = Prefix::from(network_type);
+    if address.prefix == expected {
+        Ok(())
+    } else {
+        Err(AddressNetworkMismatchError::new_err(format!(
+            "Address `{}` has prefix `{}`, expected `{}` for network `{}`",
+            address, address.prefix, expected, network_type
+        )))
     }
 }
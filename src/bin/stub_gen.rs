@@ -116,6 +116,8 @@ fn snake_to_pascal(s: &str) -> String {
 ///   to `def method_name(self, request: MethodNameRequest | None = None) -> MethodNameResponse: ...`
 /// - `def method_name(self, request: dict) -> typing.Any: ...`
 ///   to `def method_name(self, request: MethodNameRequest) -> MethodNameResponse: ...`
+///
+/// Trailing parameters (e.g. `timeout`) are left untouched.
 fn fix_rpc_method_signatures(content: String) -> String {
     let mut result = String::with_capacity(content.len());
 
@@ -180,10 +182,11 @@ fn transform_rpc_method_line(line: &str) -> String {
             &format!("{} | None = None", request_type),
         );
     }
-    // Replace required dict parameter: `request: dict)`
-    // with `request: XxxRequest)`
-    else if transformed.contains("request: dict)") {
-        transformed = transformed.replace("request: dict)", &format!("request: {})", request_type));
+    // Replace required dict parameter: `request: dict` (followed by `)` or
+    // further parameters such as `timeout`) with `request: XxxRequest`
+    else if transformed.contains("request: dict)") || transformed.contains("request: dict,") {
+        transformed =
+            transformed.replacen("request: dict", &format!("request: {}", request_type), 1);
     }
 
     // Replace return type: `-> typing.Any` with `-> XxxResponse`
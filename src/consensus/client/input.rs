@@ -1,11 +1,16 @@
 use crate::{
-    consensus::client::{outpoint::PyTransactionOutpoint, utxo::PyUtxoEntryReference},
+    consensus::client::{
+        outpoint::PyTransactionOutpoint,
+        utils::shared_inner_eq,
+        utxo::{PyUtxoEntryReference, utxo_entries_eq},
+    },
     consensus::convert::TryToPyDict,
     types::PyBinary,
 };
 use kaspa_consensus_client::{TransactionInput, UtxoEntryReference};
+use kaspa_txscript::script_class::ScriptClass;
 use pyo3::{
-    exceptions::PyKeyError,
+    exceptions::{PyKeyError, PyValueError},
     prelude::*,
     types::{PyDict, PyType},
 };
@@ -158,24 +163,35 @@ impl PyTransactionInput {
     ///         - 'sequence' (int): Sequence number
     ///         - 'sigOpCount' (int): Signature operation count
     ///         - 'utxo' (dict | None): Optional UTXO entry reference dict
+    ///     validate: Also reject inputs whose signature script size exceeds
+    ///         the standardness limit enforced by the mempool, or that spend
+    ///         a P2SH output and declare more sig-ops than the standard P2SH
+    ///         limit. Useful when the dict comes from untrusted JSON.
     ///
     /// Returns:
     ///     TransactionInput: A new TransactionInput instance.
     ///
     /// Raises:
     ///     KeyError: If required keys are missing.
-    ///     ValueError: If values are invalid.
+    ///     ValueError: If values are invalid, or `validate` is set and the
+    ///         input is non-standard.
     #[classmethod]
-    fn from_dict(_cls: &Bound<'_, PyType>, dict: &Bound<'_, PyDict>) -> PyResult<Self> {
-        Self::try_from(dict)
+    #[pyo3(signature = (dict, validate=false))]
+    fn from_dict(
+        _cls: &Bound<'_, PyType>,
+        dict: &Bound<'_, PyDict>,
+        validate: bool,
+    ) -> PyResult<Self> {
+        let input = Self::try_from(dict)?;
+        if validate {
+            check_input_standard(&input.0)?;
+        }
+        Ok(input)
     }
 
     // Cannot be derived via pyclass(eq) as wrapped PyTransactionInput type does not derive PartialEq/Eq
     fn __eq__(&self, other: &PyTransactionInput) -> bool {
-        match (bincode::serialize(&self.0), bincode::serialize(&other.0)) {
-            (Ok(a), Ok(b)) => a == b,
-            _ => false,
-        }
+        inputs_eq(&self.0, &other.0)
     }
 }
 
@@ -207,15 +223,7 @@ impl TryFrom<&Bound<'_, PyDict>> for PyTransactionInput {
                 if sig_item.is_none() {
                     None
                 } else {
-                    let sig_hex: String = sig_item.extract()?;
-                    if sig_hex.is_empty() {
-                        Some(Vec::new())
-                    } else {
-                        let mut data = vec![0u8; sig_hex.len() / 2];
-                        faster_hex::hex_decode(sig_hex.as_bytes(), &mut data)
-                            .map_err(|e| PyKeyError::new_err(format!("Invalid hex: {}", e)))?;
-                        Some(data)
-                    }
+                    Some(PyBinary::try_from(&sig_item)?.into())
                 }
             } else {
                 None
@@ -255,3 +263,71 @@ impl TryFrom<&Bound<'_, PyDict>> for PyTransactionInput {
         Ok(Self(input))
     }
 }
+
+/// Largest signature script a standard input may carry, in bytes.
+pub(crate) const MAX_STANDARD_SIGNATURE_SCRIPT_SIZE: usize = 1650;
+
+/// Largest sig-op count a standard input spending a P2SH output may declare.
+pub(crate) const MAX_STANDARD_P2SH_SIG_OPS: u8 = 15;
+
+// Mirrors the per-input checks of the mempool's standardness rules.
+pub(crate) fn check_input_standard(input: &TransactionInput) -> PyResult<()> {
+    match input_standard_violation(input) {
+        Some((_, message)) => Err(PyValueError::new_err(message)),
+        None => Ok(()),
+    }
+}
+
+// Returns the name of the first standardness rule the input violates, and a
+// message describing it.
+pub(crate) fn input_standard_violation(input: &TransactionInput) -> Option<(&'static str, String)> {
+    let input = input.inner();
+
+    let script_size = input.signature_script.as_ref().map_or(0, Vec::len);
+    if script_size > MAX_STANDARD_SIGNATURE_SCRIPT_SIZE {
+        return Some((
+            "signature_script_size",
+            format!(
+                "Signature script is {script_size} bytes, exceeding the standard limit of {MAX_STANDARD_SIGNATURE_SCRIPT_SIZE} bytes"
+            ),
+        ));
+    }
+
+    // The limit only applies to P2SH redeem scripts, so inputs without a UTXO
+    // attached can't be checked
+    let spends_p2sh = input.utxo.as_ref().is_some_and(|utxo| {
+        matches!(
+            ScriptClass::from_script(&utxo.utxo.script_public_key),
+            ScriptClass::ScriptHash
+        )
+    });
+    if spends_p2sh && input.sig_op_count > MAX_STANDARD_P2SH_SIG_OPS {
+        return Some((
+            "sig_op_count",
+            format!(
+                "Sig-op count {} exceeds the standard P2SH limit of {MAX_STANDARD_P2SH_SIG_OPS}",
+                input.sig_op_count
+            ),
+        ));
+    }
+
+    None
+}
+
+pub(crate) fn inputs_eq(a: &TransactionInput, b: &TransactionInput) -> bool {
+    shared_inner_eq(
+        || a.inner(),
+        || b.inner(),
+        |a, b| {
+            a.previous_outpoint == b.previous_outpoint
+                && a.signature_script == b.signature_script
+                && a.sequence == b.sequence
+                && a.sig_op_count == b.sig_op_count
+                && match (&a.utxo, &b.utxo) {
+                    (Some(a), Some(b)) => utxo_entries_eq(&a.utxo, &b.utxo),
+                    (None, None) => true,
+                    _ => false,
+                }
+        },
+    )
+}
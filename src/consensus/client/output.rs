@@ -6,9 +6,9 @@ use pyo3::{
 };
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 
-use crate::{
-    consensus::{convert::TryToPyDict, core::script_public_key::PyScriptPublicKey},
-    types::PyBinary,
+use crate::consensus::{
+    client::utils::shared_inner_eq, convert::TryToPyDict,
+    core::script_public_key::PyScriptPublicKey,
 };
 
 /// A transaction output defining a payment destination.
@@ -102,10 +102,7 @@ impl PyTransactionOutput {
 
     // Cannot be derived via pyclass(eq) as wrapped PyTransactionOutput type does not derive PartialEq/Eq
     fn __eq__(&self, other: &PyTransactionOutput) -> bool {
-        match (bincode::serialize(&self.0), bincode::serialize(&other.0)) {
-            (Ok(a), Ok(b)) => a == b,
-            _ => false,
-        }
+        outputs_eq(&self.0, &other.0)
     }
 }
 
@@ -129,11 +126,8 @@ impl TryFrom<&Bound<'_, PyDict>> for PyTransactionOutput {
         let spk_obj = dict.as_any().get_item("scriptPublicKey")?;
         let spk = if let Ok(spk) = spk_obj.extract::<PyScriptPublicKey>() {
             spk
-        } else if let Ok(dict) = spk_obj.cast::<PyDict>() {
-            PyScriptPublicKey::constructor(
-                dict.as_any().get_item("version")?.extract::<u16>()?,
-                dict.as_any().get_item("script")?.extract::<PyBinary>()?,
-            )?
+        } else if let Ok(spk_dict) = spk_obj.cast::<PyDict>() {
+            PyScriptPublicKey::try_from(spk_dict)?
         } else {
             return Err(PyValueError::new_err(
                 "Value for `scriptPublicKey` must be type ScriptPublicKey or dict",
@@ -143,3 +137,11 @@ impl TryFrom<&Bound<'_, PyDict>> for PyTransactionOutput {
         Ok(Self::ctor(value, spk))
     }
 }
+
+pub(crate) fn outputs_eq(a: &TransactionOutput, b: &TransactionOutput) -> bool {
+    shared_inner_eq(
+        || a.inner(),
+        || b.inner(),
+        |a, b| a.value == b.value && a.script_public_key == b.script_public_key,
+    )
+}
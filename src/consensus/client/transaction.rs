@@ -1,20 +1,28 @@
 use crate::address::PyAddress;
-use crate::consensus::client::input::PyTransactionInput;
-use crate::consensus::client::output::PyTransactionOutput;
+use crate::consensus::client::input::{PyTransactionInput, inputs_eq};
+use crate::consensus::client::outpoint::PyTransactionOutpoint;
+use crate::consensus::client::output::{PyTransactionOutput, outputs_eq};
+use crate::consensus::client::utils::shared_inner_eq;
+use crate::consensus::client::utxo::PyUtxoEntryReference;
 use crate::consensus::convert::TryToPyDict;
 use crate::consensus::core::network::PyNetworkType;
+use crate::consensus::core::script_public_key::PyScriptPublicKey;
+use crate::consensus::core::subnets::parse_subnetwork_id;
 use crate::crypto::hashes::PyHash;
+use crate::crypto::txscript::utils::execute_input_script;
 use crate::types::PyBinary;
-use kaspa_consensus_client::{Transaction, TransactionInput, TransactionOutput};
+use kaspa_addresses::Prefix;
+use kaspa_consensus_client::{
+    Transaction, TransactionInput, TransactionOutput, UtxoEntryReference,
+};
 use kaspa_consensus_core::network::NetworkType;
 use kaspa_consensus_core::subnets;
-use kaspa_consensus_core::subnets::SubnetworkId;
 use kaspa_consensus_core::tx as cctx;
 use kaspa_txscript::extract_script_pub_key_address;
 use kaspa_utils::hex::FromHex;
-use pyo3::exceptions::PyKeyError;
+use pyo3::exceptions::{PyIndexError, PyKeyError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyList, PyType};
+use pyo3::types::{PyBytes, PyList, PyType};
 use pyo3::{exceptions::PyException, types::PyDict};
 use pyo3_stub_gen::derive::*;
 use workflow_core::hex::ToHex;
@@ -32,6 +40,17 @@ impl PyTransaction {
     pub fn inner(&self) -> &Transaction {
         &self.0
     }
+
+    // Recompute the id from the current contents and store it. Inputs and
+    // outputs share their inner values with the Python objects handed out for
+    // them, so edits can happen without going through this wrapper; the id is
+    // therefore computed when read rather than tracked on change. The stored id
+    // lives in the inner value shared by every wrapper of this transaction.
+    fn update_id(&self) -> kaspa_hashes::Hash {
+        let id = cctx::Transaction::from(self).id();
+        self.0.inner().id = id;
+        id
+    }
 }
 
 #[gen_stub_pymethods]
@@ -48,22 +67,61 @@ impl PyTransaction {
 
     /// Finalize the transaction and compute its ID.
     ///
+    /// The ID is always recomputed from the current inputs, outputs, and
+    /// fields, and stored on the transaction.
+    ///
+    /// Args:
+    ///     validate: Also check that `gas` is 0 if the transaction is on a
+    ///         native or built-in subnetwork (default: False).
+    ///
     /// Returns:
     ///     Hash: The computed transaction ID.
+    ///
+    /// Raises:
+    ///     ValueError: If `validate` is set and `gas` is not allowed on the subnetwork.
     #[pyo3(name = "finalize")]
-    pub fn finalize(&self) -> PyResult<PyHash> {
-        let tx: cctx::Transaction = self.into();
-        self.0.inner().id = tx.id();
-        Ok(self.0.inner().id.into())
+    #[pyo3(signature = (validate=false))]
+    pub fn finalize(&self, validate: bool) -> PyResult<PyHash> {
+        if validate {
+            check_gas(&self.0)?;
+        }
+        Ok(self.update_id().into())
+    }
+
+    /// Verify every input's signature script against the UTXO it spends.
+    ///
+    /// Runs the script engine over each input and its UTXO entry, so this
+    /// catches signing bugs before the transaction is submitted. All inputs
+    /// must have their UTXO entries attached.
+    ///
+    /// Returns:
+    ///     bool: True if every input validates, False otherwise.
+    ///
+    /// Raises:
+    ///     Exception: If an input is missing its UTXO entry.
+    #[pyo3(name = "verify_signatures")]
+    pub fn verify_signatures(&self) -> PyResult<bool> {
+        let (tx, utxos) = self
+            .0
+            .tx_and_utxos()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let populated = cctx::PopulatedTransaction::new(&tx, utxos);
+
+        let valid =
+            (0..tx.inputs.len()).all(|index| execute_input_script(&populated, index).is_ok());
+        Ok(valid)
     }
 
     /// The transaction ID (hash).
     ///
+    /// Computed from the current contents when read, so it reflects changes
+    /// made through the setters and directly to input or output objects.
+    ///
     /// Returns:
     ///     str: The transaction ID as a hex string.
     #[getter]
     pub fn get_id(&self) -> String {
-        self.0.inner().id.to_string()
+        self.update_id().to_string()
     }
 
     /// Create a new transaction.
@@ -77,13 +135,18 @@ impl PyTransaction {
     ///     gas: Gas limit for smart contract execution.
     ///     payload: Optional transaction payload data.
     ///     mass: Transaction mass (for fee calculation).
+    ///     validate: Also check that `gas` is 0 if `subnetwork_id` is a native or
+    ///         built-in subnetwork, where consensus forbids gas (default: False).
     ///
     /// Returns:
     ///     Transaction: A new Transaction instance.
     ///
     /// Raises:
-    ///     Exception: If the subnetwork_id is invalid or transaction creation fails.
+    ///     ValueError: If the subnetwork_id is not 20 bytes, or `validate` is set
+    ///         and `gas` is not allowed on the subnetwork.
+    ///     Exception: If transaction creation fails.
     #[new]
+    #[pyo3(signature = (version, inputs, outputs, lock_time, subnetwork_id, gas, payload, mass, validate=false))]
     pub fn constructor(
         version: u16,
         inputs: Vec<PyTransactionInput>,
@@ -93,11 +156,9 @@ impl PyTransaction {
         gas: u64,
         payload: PyBinary,
         mass: u64,
+        validate: bool,
     ) -> PyResult<Self> {
-        let subnetwork_id: SubnetworkId =
-            subnetwork_id.data.as_slice().try_into().map_err(|err| {
-                PyException::new_err(format!("subnetwork_id conversion error: {}", err))
-            })?;
+        let subnetwork_id = parse_subnetwork_id(&subnetwork_id.data)?;
 
         let inner = Transaction::new(
             None,
@@ -111,8 +172,11 @@ impl PyTransaction {
             mass,
         )
         .map_err(|err| PyException::new_err(err.to_string()))?;
+        if validate {
+            check_gas(&inner)?;
+        }
 
-        Ok(Self(inner))
+        Ok(Self::from(inner))
     }
 
     /// The list of transaction inputs.
@@ -140,8 +204,43 @@ impl PyTransaction {
         self.0.inner().inputs = value.into_iter().map(TransactionInput::from).collect();
     }
 
+    /// Attach a UTXO entry to one of the transaction inputs.
+    ///
+    /// Inputs need their UTXO to be signed, e.g. when the transaction was
+    /// loaded from a dict without UTXO data. Any UTXO already attached to the
+    /// input is replaced. The transaction ID is unaffected.
+    ///
+    /// Args:
+    ///     index: The index of the input.
+    ///     utxo: The UTXO entry spent by the input.
+    ///
+    /// Raises:
+    ///     IndexError: If `index` is out of range.
+    ///     ValueError: If the UTXO outpoint differs from the input's previous outpoint.
+    pub fn set_input_utxo(&mut self, index: usize, utxo: PyUtxoEntryReference) -> PyResult<()> {
+        let inputs = &self.0.inner().inputs;
+        let input = inputs.get(index).ok_or_else(|| {
+            PyIndexError::new_err(format!(
+                "input index {index} out of range for transaction with {} inputs",
+                inputs.len()
+            ))
+        })?;
+        let utxo: UtxoEntryReference = utxo.into();
+        let mut input = input.inner();
+        if input.previous_outpoint != utxo.utxo.outpoint {
+            return Err(PyValueError::new_err(format!(
+                "UTXO outpoint does not match the previous outpoint of input {index}"
+            )));
+        }
+        input.utxo = Some(utxo);
+        Ok(())
+    }
+
     /// Extract unique addresses from transaction inputs.
     ///
+    /// Inputs whose UTXO script does not encode an address (e.g. nonstandard
+    /// scripts) are skipped; `unknown_scripts()` returns those scripts.
+    ///
     /// Args:
     ///     network_type: The network type to use for address encoding.
     ///
@@ -168,6 +267,29 @@ impl PyTransaction {
         Ok(list.into_iter().map(PyAddress::from).collect())
     }
 
+    /// Extract unique input scripts that do not encode an address.
+    ///
+    /// These are the inputs skipped by `addresses()`, such as nonstandard
+    /// scripts, so auditing a transaction does not lose them.
+    ///
+    /// Returns:
+    ///     list[ScriptPublicKey]: Unique input scripts without an address.
+    pub fn unknown_scripts(&self) -> Vec<PyScriptPublicKey> {
+        let mut scripts = Vec::new();
+        for input in &self.0.inner().inputs {
+            if let Some(utxo) = input.get_utxo()
+                && utxo.utxo.address.is_none()
+                // Whether a script decodes doesn't depend on the address prefix
+                && extract_script_pub_key_address(&utxo.utxo.script_public_key, Prefix::Mainnet)
+                    .is_err()
+                && !scripts.contains(&utxo.utxo.script_public_key)
+            {
+                scripts.push(utxo.utxo.script_public_key.clone());
+            }
+        }
+        scripts.into_iter().map(PyScriptPublicKey::from).collect()
+    }
+
     /// The list of transaction outputs.
     ///
     /// Returns:
@@ -193,6 +315,29 @@ impl PyTransaction {
         self.0.inner().outputs = value.into_iter().map(TransactionOutput::from).collect();
     }
 
+    /// Get the outpoint referencing one of this transaction's outputs.
+    ///
+    /// Uses the current transaction ID, so the outpoint can be spent by a
+    /// follow-up transaction once this one is accepted.
+    ///
+    /// Args:
+    ///     index: The index of the output.
+    ///
+    /// Returns:
+    ///     TransactionOutpoint: The outpoint of the output at `index`.
+    ///
+    /// Raises:
+    ///     IndexError: If `index` is out of range.
+    pub fn outpoint_for_output(&self, index: u32) -> PyResult<PyTransactionOutpoint> {
+        let outputs = self.0.inner().outputs.len();
+        if index as usize >= outputs {
+            return Err(PyIndexError::new_err(format!(
+                "output index {index} out of range for transaction with {outputs} outputs"
+            )));
+        }
+        Ok(PyTransactionOutpoint::ctor(self.update_id().into(), index))
+    }
+
     /// The transaction version number.
     ///
     /// Returns:
@@ -260,19 +405,13 @@ impl PyTransaction {
     /// Set the subnetwork identifier.
     ///
     /// Args:
-    ///     value: The subnetwork ID as a hex string.
+    ///     value: The subnetwork ID as hex, bytes, or a list of ints.
     ///
     /// Raises:
-    ///     Exception: If the hex string is invalid or has incorrect length.
+    ///     ValueError: If the hex string is invalid or the value is not 20 bytes.
     #[setter]
-    pub fn set_subnetwork_id(&mut self, value: &str) -> PyResult<()> {
-        let subnetwork_id = Vec::from_hex(value)
-            .map_err(|err| PyException::new_err(err.to_string()))?
-            .as_slice()
-            .try_into()
-            .map_err(|err| {
-                PyException::new_err(format!("subnetwork_id conversion error: {}", err))
-            })?;
+    pub fn set_subnetwork_id(&mut self, value: PyBinary) -> PyResult<()> {
+        let subnetwork_id = parse_subnetwork_id(&value.data)?;
         self.0.inner().subnetwork_id = subnetwork_id;
         Ok(())
     }
@@ -295,6 +434,18 @@ impl PyTransaction {
         self.0.inner().payload = value.into();
     }
 
+    /// The transaction payload data as raw bytes.
+    ///
+    /// Copies the payload straight into a `bytes` object, skipping the hex
+    /// encoding done by `payload`. Prefer this for large payloads.
+    ///
+    /// Returns:
+    ///     bytes: The payload bytes.
+    #[getter]
+    pub fn get_payload_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.inner().payload)
+    }
+
     /// The transaction mass used for fee calculation.
     ///
     /// Returns:
@@ -319,6 +470,7 @@ impl PyTransaction {
     /// Returns:
     ///     dict: the Transaction in dictionary form.
     fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.update_id();
         self.0.try_to_pydict(py)
     }
 
@@ -349,10 +501,7 @@ impl PyTransaction {
 
     // Cannot be derived via pyclass(eq) as wrapped Transaction type does not derive PartialEq/Eq
     fn __eq__(&self, other: &PyTransaction) -> bool {
-        match (bincode::serialize(&self.0), bincode::serialize(&other.0)) {
-            (Ok(a), Ok(b)) => a == b,
-            _ => false,
-        }
+        transactions_eq(&self.0, &other.0)
     }
 }
 
@@ -398,17 +547,12 @@ impl TryFrom<&Bound<'_, PyDict>> for PyTransaction {
             .extract()?;
 
         // Parse subnetworkId
-        let subnetwork_id_str: String = dict
-            .get_item("subnetworkId")?
-            .ok_or_else(|| PyKeyError::new_err("Key `subnetworkId` not present"))?
-            .extract()?;
-        let subnetwork_id: SubnetworkId = Vec::from_hex(&subnetwork_id_str)
-            .map_err(|err| PyException::new_err(err.to_string()))?
-            .as_slice()
-            .try_into()
-            .map_err(|err| {
-                PyException::new_err(format!("subnetwork_id conversion error: {}", err))
-            })?;
+        let subnetwork_id = PyBinary::try_from(
+            &dict
+                .get_item("subnetworkId")?
+                .ok_or_else(|| PyKeyError::new_err("Key `subnetworkId` not present"))?,
+        )?;
+        let subnetwork_id = parse_subnetwork_id(&subnetwork_id.data)?;
 
         // Parse gas
         let gas: u64 = dict
@@ -417,15 +561,12 @@ impl TryFrom<&Bound<'_, PyDict>> for PyTransaction {
             .extract()?;
 
         // Parse payload
-        let payload_str: String = dict
-            .get_item("payload")?
-            .ok_or_else(|| PyKeyError::new_err("Key `payload` not present"))?
-            .extract()?;
-        let payload: Vec<u8> = if payload_str.is_empty() {
-            Vec::new()
-        } else {
-            Vec::from_hex(&payload_str).map_err(|err| PyException::new_err(err.to_string()))?
-        };
+        let payload: Vec<u8> = PyBinary::try_from(
+            &dict
+                .get_item("payload")?
+                .ok_or_else(|| PyKeyError::new_err("Key `payload` not present"))?,
+        )?
+        .into();
 
         // Parse mass
         let mass: u64 = dict
@@ -469,6 +610,42 @@ impl TryFrom<&Bound<'_, PyDict>> for PyTransaction {
             mass,
         )
         .map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(Self(tx))
+        Ok(Self::from(tx))
+    }
+}
+
+// The stored id is derived from the other fields and may not be finalized
+// yet, so it is not compared.
+fn transactions_eq(a: &Transaction, b: &Transaction) -> bool {
+    shared_inner_eq(
+        || a.inner(),
+        || b.inner(),
+        |a, b| {
+            a.version == b.version
+                && a.lock_time == b.lock_time
+                && a.subnetwork_id == b.subnetwork_id
+                && a.gas == b.gas
+                && a.payload == b.payload
+                && a.mass == b.mass
+                && a.inputs.len() == b.inputs.len()
+                && a.outputs.len() == b.outputs.len()
+                && a.inputs.iter().zip(&b.inputs).all(|(a, b)| inputs_eq(a, b))
+                && a.outputs
+                    .iter()
+                    .zip(&b.outputs)
+                    .all(|(a, b)| outputs_eq(a, b))
+        },
+    )
+}
+
+// Consensus rejects non-zero gas on native and built-in subnetworks.
+fn check_gas(tx: &Transaction) -> PyResult<()> {
+    let inner = tx.inner();
+    if inner.gas != 0 && inner.subnetwork_id.is_builtin_or_native() {
+        return Err(PyValueError::new_err(format!(
+            "gas must be 0 on subnetwork {}, got {}",
+            inner.subnetwork_id, inner.gas
+        )));
     }
+    Ok(())
 }
@@ -1,26 +1,51 @@
 use crate::{
-    address::PyAddress,
-    consensus::core::{network::PyNetworkType, script_public_key::PyScriptPublicKey},
+    address::{PyAddress, check_address_network},
+    consensus::{
+        client::transaction::PyTransaction,
+        convert::TryToPyDict,
+        core::{network::PyNetworkType, script_public_key::PyScriptPublicKey},
+    },
     types::PyBinary,
 };
-use kaspa_consensus_core::network::NetworkType;
-use kaspa_txscript::{script_class::ScriptClass, standard};
-use pyo3::{exceptions::PyException, prelude::*};
+use kaspa_consensus_core::{network::NetworkType, tx::ScriptPublicKey};
+use kaspa_txscript::{
+    opcodes::codes::OpReturn, script_builder::ScriptBuilder, script_class::ScriptClass, standard,
+};
+use pyo3::{
+    exceptions::PyException,
+    prelude::*,
+    types::{PyDict, PyList},
+};
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use std::ops::Deref;
 use workflow_core::hex::ToHex;
 
 /// Create a pay-to-address locking script.
 ///
 /// Args:
 ///     address: The destination address.
+///     network: Optional network the address is expected to belong to.
 ///
 /// Returns:
 ///     ScriptPublicKey: The locking script for the address.
+///
+/// Raises:
+///     AddressNetworkMismatchError: If `network` is given and the address
+///         prefix belongs to a different network.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "pay_to_address_script")]
-pub fn py_pay_to_address_script(address: PyAddress) -> PyResult<PyScriptPublicKey> {
-    Ok(standard::pay_to_address_script(&address.into()).into())
+#[pyo3(signature = (address, network=None))]
+pub fn py_pay_to_address_script(
+    address: PyAddress,
+    #[gen_stub(override_type(type_repr = "str | NetworkType | None = None"))] network: Option<
+        PyNetworkType,
+    >,
+) -> PyResult<PyScriptPublicKey> {
+    if let Some(network) = network {
+        check_address_network(&address.0, network.into())?;
+    }
+    Ok(address.to_script_public_key())
 }
 
 /// Create a pay-to-script-hash (P2SH) locking script.
@@ -60,6 +85,31 @@ pub fn py_pay_to_script_hash_signature_script(
     Ok(script.to_hex())
 }
 
+/// Create a provably unspendable data carrier script.
+///
+/// The script is `OP_RETURN <data>`. Outputs locked to it can never be
+/// spent, so it is suited to timestamping and commitments.
+///
+/// Args:
+///     data: The data to embed (at most 520 bytes).
+///
+/// Returns:
+///     ScriptPublicKey: The data carrier locking script.
+///
+/// Raises:
+///     Exception: If the data exceeds the maximum script element size.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "create_data_script")]
+pub fn py_create_data_script(data: PyBinary) -> PyResult<PyScriptPublicKey> {
+    let mut builder = ScriptBuilder::new();
+    builder
+        .add_op(OpReturn)
+        .and_then(|builder| builder.add_data(data.data.as_slice()))
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    Ok(ScriptPublicKey::new(0, builder.drain().into()).into())
+}
+
 /// Extract the address from a script public key.
 ///
 /// Args:
@@ -128,3 +178,87 @@ pub fn py_is_script_pay_to_pubkey_ecdsa(script: PyBinary) -> PyResult<bool> {
 pub fn py_is_script_pay_to_script_hash(script: PyBinary) -> PyResult<bool> {
     Ok(ScriptClass::is_pay_to_script_hash(script.data.as_slice()))
 }
+
+/// Compare two transactions and report where they differ.
+///
+/// Transactions are compared in their dictionary form (see
+/// `Transaction.to_dict()`). Inputs and outputs are compared index by index;
+/// an index present in only one of the transactions is reported as differing.
+///
+/// Args:
+///     a: The first transaction.
+///     b: The second transaction.
+///
+/// Returns:
+///     dict: A dictionary with keys:
+///         - 'fields' (list[str]): Top-level fields that differ (e.g. 'payload', 'mass').
+///         - 'inputs' (list[int]): Indices of inputs that differ.
+///         - 'outputs' (list[int]): Indices of outputs that differ.
+///
+/// Raises:
+///     Exception: If a transaction cannot be converted to a dictionary.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "transaction_diff")]
+pub fn py_transaction_diff<'py>(
+    py: Python<'py>,
+    a: &PyTransaction,
+    b: &PyTransaction,
+) -> PyResult<Bound<'py, PyDict>> {
+    let a = a.inner().try_to_pydict(py)?;
+    let b = b.inner().try_to_pydict(py)?;
+
+    let mut fields = Vec::new();
+    for (key, value) in a.iter() {
+        let name: String = key.extract()?;
+        if name == "inputs" || name == "outputs" {
+            continue;
+        }
+        match b.get_item(&key)? {
+            Some(other) if value.eq(&other)? => {}
+            _ => fields.push(name),
+        }
+    }
+
+    let diff = PyDict::new(py);
+    diff.set_item("fields", fields)?;
+    diff.set_item("inputs", diff_indices(&a, &b, "inputs")?)?;
+    diff.set_item("outputs", diff_indices(&a, &b, "outputs")?)?;
+    Ok(diff)
+}
+
+fn diff_indices(a: &Bound<'_, PyDict>, b: &Bound<'_, PyDict>, key: &str) -> PyResult<Vec<usize>> {
+    let (a, b) = (dict_list(a, key)?, dict_list(b, key)?);
+
+    let mut indices = Vec::new();
+    for index in 0..a.len().max(b.len()) {
+        match (a.get(index), b.get(index)) {
+            (Some(x), Some(y)) if x.eq(y)? => {}
+            _ => indices.push(index),
+        }
+    }
+    Ok(indices)
+}
+
+fn dict_list<'py>(dict: &Bound<'py, PyDict>, key: &str) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    match dict.get_item(key)? {
+        Some(items) => Ok(items.cast_into::<PyList>()?.iter().collect()),
+        None => Ok(Vec::new()),
+    }
+}
+
+// Field-by-field equality of two client values backed by a shared inner value.
+// Clones share the same inner value and its lock isn't reentrant, so identity
+// is checked first rather than locking it twice.
+pub(crate) fn shared_inner_eq<G: Deref>(
+    a: impl Fn() -> G,
+    b: impl Fn() -> G,
+    fields_eq: impl FnOnce(&G, &G) -> bool,
+) -> bool {
+    // Each guard is dropped at the end of its statement
+    let a_ptr = &*a() as *const G::Target;
+    if std::ptr::eq(a_ptr, &*b()) {
+        return true;
+    }
+    fields_eq(&a(), &b())
+}
@@ -1,17 +1,29 @@
-use super::outpoint::PyTransactionOutpoint;
+use super::{outpoint::PyTransactionOutpoint, output::PyTransactionOutput};
 use crate::{
     address::PyAddress,
-    consensus::{convert::TryToPyDict, core::script_public_key::PyScriptPublicKey},
+    consensus::{
+        convert::TryToPyDict,
+        core::{network::PyNetworkId, script_public_key::PyScriptPublicKey},
+    },
+    crypto::hashes::PyHash,
     types::PyBinary,
 };
-use kaspa_consensus_client::{UtxoEntry, UtxoEntryReference};
+use kaspa_addresses::Address;
+use kaspa_consensus_client::{
+    TransactionOutpoint, TransactionOutput, UtxoEntry, UtxoEntryReference,
+};
+use kaspa_consensus_core::network::NetworkId;
+use kaspa_consensus_core::tx::ScriptPublicKey;
+use kaspa_hashes::Hash;
 use kaspa_utils::hex::FromHex;
+use kaspa_wallet_core::utxo::NetworkParams;
 use pyo3::{
-    exceptions::{PyKeyError, PyValueError},
+    exceptions::{PyException, PyKeyError, PyValueError},
     prelude::*,
-    types::{PyDict, PyList, PyType},
+    types::{PyBytes, PyDict, PyList, PyType},
 };
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use std::sync::Arc;
 
 /// An unspent transaction output (UTXO).
@@ -80,6 +92,42 @@ impl PyUtxoEntry {
         self.0.is_coinbase
     }
 
+    /// Check whether this UTXO is spendable at the given DAA score.
+    ///
+    /// Coinbase UTXOs use the network's coinbase maturity period and all
+    /// other UTXOs the user transaction maturity period, including any
+    /// overrides set through `UtxoProcessor`.
+    ///
+    /// Args:
+    ///     current_daa_score: The current virtual DAA score.
+    ///     network: The network the UTXO belongs to (e.g. "mainnet", "testnet-10").
+    ///
+    /// Returns:
+    ///     bool: True if the UTXO has reached maturity.
+    ///
+    /// Raises:
+    ///     ValueError: If the network is not a known network.
+    pub fn is_mature(
+        &self,
+        current_daa_score: u64,
+        #[gen_stub(override_type(type_repr = "str | NetworkId"))] network: PyNetworkId,
+    ) -> PyResult<bool> {
+        let network_id = NetworkId::from(network);
+        if !NetworkId::iter().any(|known| known == network_id) {
+            return Err(PyValueError::new_err(format!(
+                "Unsupported network `{network_id}`"
+            )));
+        }
+
+        let params = NetworkParams::from(network_id);
+        let maturity_period = if self.0.is_coinbase {
+            params.coinbase_transaction_maturity_period_daa()
+        } else {
+            params.user_transaction_maturity_period_daa()
+        };
+        Ok(current_daa_score >= self.0.block_daa_score.saturating_add(maturity_period))
+    }
+
     /// Get a dictionary representation of the UtxoEntry.
     /// Note that this creates a second separate object on the Python heap.
     ///
@@ -111,12 +159,44 @@ impl PyUtxoEntry {
         Self::try_from(dict)
     }
 
+    /// Serialize the UtxoEntry to a compact binary form.
+    ///
+    /// Suited to caching UTXOs on disk; read back with `UtxoEntry.from_bytes()`.
+    ///
+    /// Returns:
+    ///     bytes: The serialized UtxoEntry.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = encode_utxo_records(&UtxoEntryRecord::from(&self.0))?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Create a UtxoEntry from bytes produced by `to_bytes()`.
+    ///
+    /// Args:
+    ///     data: The serialized UtxoEntry as bytes or hex string.
+    ///
+    /// Returns:
+    ///     UtxoEntry: A new UtxoEntry instance.
+    ///
+    /// Raises:
+    ///     ValueError: If the data is not a valid serialized UtxoEntry.
+    #[staticmethod]
+    pub fn from_bytes(data: PyBinary) -> PyResult<Self> {
+        let record: UtxoEntryRecord = decode_utxo_records(&data.data)?;
+        Ok(Self(record.try_into()?))
+    }
+
     // Cannot be derived via pyclass(eq) as wrapped PyUtxoEntry type does not derive PartialEq/Eq
     fn __eq__(&self, other: &PyUtxoEntry) -> bool {
-        match (bincode::serialize(&self.0), bincode::serialize(&other.0)) {
-            (Ok(a), Ok(b)) => a == b,
-            _ => false,
-        }
+        utxo_entries_eq(&self.0, &other.0)
+    }
+
+    /// The amount in sompi, so entries can be used with `int()` and `sum()`.
+    ///
+    /// Returns:
+    ///     int: The UTXO value in sompi.
+    fn __int__(&self) -> u64 {
+        self.0.amount
     }
 }
 
@@ -162,13 +242,7 @@ impl TryFrom<&Bound<'_, PyDict>> for PyUtxoEntry {
         let script_public_key = if let Ok(spk) = spk_obj.extract::<PyScriptPublicKey>() {
             spk
         } else if let Ok(spk_dict) = spk_obj.cast::<PyDict>() {
-            PyScriptPublicKey::constructor(
-                spk_dict.as_any().get_item("version")?.extract::<u16>()?,
-                spk_dict
-                    .as_any()
-                    .get_item("script")?
-                    .extract::<PyBinary>()?,
-            )?
+            PyScriptPublicKey::try_from(spk_dict)?
         } else {
             return Err(PyValueError::new_err(
                 "Value for `scriptPublicKey` must be type ScriptPublicKey or dict",
@@ -209,6 +283,20 @@ pub struct PyUtxoEntries(Arc<Vec<UtxoEntryReference>>);
 #[gen_stub_pymethods]
 #[pymethods]
 impl PyUtxoEntries {
+    /// Create a new collection of UTXO entry references.
+    ///
+    /// Args:
+    ///     items: List of UtxoEntryReference objects.
+    ///
+    /// Returns:
+    ///     UtxoEntries: A new UtxoEntries instance.
+    #[new]
+    pub fn constructor(items: Vec<PyUtxoEntryReference>) -> Self {
+        Self(Arc::new(
+            items.iter().map(UtxoEntryReference::from).collect(),
+        ))
+    }
+
     /// The list of UTXO entry references.
     ///
     /// Returns:
@@ -267,6 +355,47 @@ impl PyUtxoEntries {
         Ok(dict)
     }
 
+    /// Serialize the UtxoEntries to a compact binary form.
+    ///
+    /// Suited to caching UTXO sets on disk; read back with
+    /// `UtxoEntries.from_bytes()`.
+    ///
+    /// Returns:
+    ///     bytes: The serialized UtxoEntries.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let records = self
+            .0
+            .iter()
+            .map(|reference| UtxoEntryRecord::from(reference.utxo.as_ref()))
+            .collect::<Vec<_>>();
+        let bytes = encode_utxo_records(&records)?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Create UtxoEntries from bytes produced by `to_bytes()`.
+    ///
+    /// Args:
+    ///     data: The serialized UtxoEntries as bytes or hex string.
+    ///
+    /// Returns:
+    ///     UtxoEntries: A new UtxoEntries instance.
+    ///
+    /// Raises:
+    ///     ValueError: If the data is not a valid serialized UtxoEntries.
+    #[staticmethod]
+    pub fn from_bytes(data: PyBinary) -> PyResult<Self> {
+        let records: Vec<UtxoEntryRecord> = decode_utxo_records(&data.data)?;
+        let entries = records
+            .into_iter()
+            .map(|record| {
+                Ok(UtxoEntryReference {
+                    utxo: Arc::new(record.try_into()?),
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Self(Arc::new(entries)))
+    }
+
     // Cannot be derived via pyclass(eq) as wrapped PyUtxoEntries type does not derive PartialEq/Eq
     fn __eq__(&self, other: &PyUtxoEntries) -> bool {
         match (bincode::serialize(&self.0), bincode::serialize(&other.0)) {
@@ -386,6 +515,53 @@ impl PyUtxoEntryReference {
     fn from_dict(_cls: &Bound<'_, PyType>, dict: &Bound<'_, PyDict>) -> PyResult<Self> {
         Self::try_from(dict)
     }
+
+    /// Create a UtxoEntryReference for an output of a transaction.
+    ///
+    /// Lets an output that was just created be spent by a follow-up
+    /// transaction. The entry's address is left unset.
+    ///
+    /// Args:
+    ///     tx_id: The ID of the transaction containing the output.
+    ///     index: The index of the output within the transaction.
+    ///     output: The transaction output.
+    ///     block_daa_score: The DAA score of the block accepting the transaction.
+    ///     is_coinbase: Whether the transaction is a coinbase transaction.
+    ///
+    /// Returns:
+    ///     UtxoEntryReference: A new UtxoEntryReference instance.
+    #[staticmethod]
+    pub fn from_output(
+        tx_id: PyHash,
+        index: u32,
+        output: PyTransactionOutput,
+        block_daa_score: u64,
+        is_coinbase: bool,
+    ) -> Self {
+        let output = TransactionOutput::from(output);
+        let output = output.inner();
+
+        let utxo = UtxoEntry {
+            address: None,
+            outpoint: TransactionOutpoint::new(tx_id.into(), index),
+            amount: output.value,
+            script_public_key: output.script_public_key.clone(),
+            block_daa_score,
+            is_coinbase,
+        };
+
+        Self(UtxoEntryReference {
+            utxo: Arc::new(utxo),
+        })
+    }
+
+    /// The amount in sompi, so entries can be used with `int()` and `sum()`.
+    ///
+    /// Returns:
+    ///     int: The UTXO value in sompi.
+    fn __int__(&self) -> u64 {
+        self.0.utxo.amount
+    }
 }
 
 impl From<PyUtxoEntryReference> for UtxoEntryReference {
@@ -448,13 +624,7 @@ impl TryFrom<&Bound<'_, PyDict>> for PyUtxoEntryReference {
         } else if let Ok(spk_str) = spk_obj.extract::<String>() {
             PyScriptPublicKey::from_hex(&spk_str)?
         } else if let Ok(spk_dict) = spk_obj.cast::<PyDict>() {
-            PyScriptPublicKey::constructor(
-                spk_dict.as_any().get_item("version")?.extract::<u16>()?,
-                spk_dict
-                    .as_any()
-                    .get_item("script")?
-                    .extract::<PyBinary>()?,
-            )?
+            PyScriptPublicKey::try_from(spk_dict)?
         } else {
             return Err(PyValueError::new_err(
                 "Value for `scriptPublicKey` must be type ScriptPublicKey, str, or dict",
@@ -487,3 +657,75 @@ impl TryFrom<&Bound<'_, PyDict>> for PyUtxoEntryReference {
         Ok(Self(inner))
     }
 }
+
+// Plain-typed mirror of `UtxoEntry` used by `to_bytes`/`from_bytes`. The upstream
+// serde impls are shaped for JSON and wasm and don't all round-trip through bincode.
+#[derive(Serialize, Deserialize)]
+struct UtxoEntryRecord {
+    address: Option<String>,
+    transaction_id: [u8; 32],
+    index: u32,
+    amount: u64,
+    script_version: u16,
+    script: Vec<u8>,
+    block_daa_score: u64,
+    is_coinbase: bool,
+}
+
+impl From<&UtxoEntry> for UtxoEntryRecord {
+    fn from(utxo: &UtxoEntry) -> Self {
+        let outpoint = utxo.outpoint.inner();
+        Self {
+            address: utxo.address.as_ref().map(Address::to_string),
+            transaction_id: outpoint.transaction_id.as_bytes(),
+            index: outpoint.index,
+            amount: utxo.amount,
+            script_version: utxo.script_public_key.version(),
+            script: utxo.script_public_key.script().to_vec(),
+            block_daa_score: utxo.block_daa_score,
+            is_coinbase: utxo.is_coinbase,
+        }
+    }
+}
+
+impl TryFrom<UtxoEntryRecord> for UtxoEntry {
+    type Error = PyErr;
+    fn try_from(record: UtxoEntryRecord) -> PyResult<Self> {
+        let address = record
+            .address
+            .map(|address| Address::try_from(address.as_str()))
+            .transpose()
+            .map_err(|err| PyValueError::new_err(format!("Invalid UTXO entry bytes: {err}")))?;
+
+        Ok(UtxoEntry {
+            address,
+            outpoint: TransactionOutpoint::new(
+                Hash::from_bytes(record.transaction_id),
+                record.index,
+            ),
+            amount: record.amount,
+            script_public_key: ScriptPublicKey::new(record.script_version, record.script.into()),
+            block_daa_score: record.block_daa_score,
+            is_coinbase: record.is_coinbase,
+        })
+    }
+}
+
+fn encode_utxo_records<T: Serialize + ?Sized>(records: &T) -> PyResult<Vec<u8>> {
+    bincode::serialize(records).map_err(|err| PyException::new_err(err.to_string()))
+}
+
+fn decode_utxo_records<T: DeserializeOwned>(data: &[u8]) -> PyResult<T> {
+    bincode::deserialize(data)
+        .map_err(|err| PyValueError::new_err(format!("Invalid UTXO entry bytes: {err}")))
+}
+
+// Field-by-field equality, matching the previous serialize-and-compare semantics.
+pub(crate) fn utxo_entries_eq(a: &UtxoEntry, b: &UtxoEntry) -> bool {
+    a.address == b.address
+        && a.outpoint == b.outpoint
+        && a.amount == b.amount
+        && a.script_public_key == b.script_public_key
+        && a.block_daa_score == b.block_daa_score
+        && a.is_coinbase == b.is_coinbase
+}
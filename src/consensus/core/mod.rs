@@ -1,4 +1,5 @@
 pub mod hashing;
 pub mod network;
 pub mod script_public_key;
+pub mod subnets;
 pub mod tx;
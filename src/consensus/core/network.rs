@@ -1,7 +1,9 @@
 use kaspa_addresses::Prefix;
 use kaspa_consensus_core::network::{NetworkId, NetworkType};
-use pyo3::{exceptions::PyException, prelude::*};
-use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyclass_enum, gen_stub_pymethods};
+use pyo3::{exceptions::PyException, prelude::*, types::PyBool};
+use pyo3_stub_gen::derive::{
+    gen_stub_pyclass, gen_stub_pyclass_enum, gen_stub_pyfunction, gen_stub_pymethods,
+};
 use std::str::FromStr;
 
 crate::wrap_unit_enum_for_py!(
@@ -64,7 +66,7 @@ impl From<&PyNetworkType> for NetworkType {
 /// Represents a specific Kaspa network, optionally with a numeric suffix
 /// for testnets (e.g., "testnet-10", "testnet-11").
 #[gen_stub_pyclass]
-#[pyclass(name = "NetworkId", skip_from_py_object, eq)]
+#[pyclass(name = "NetworkId", skip_from_py_object)]
 #[derive(Clone, PartialEq)]
 pub struct PyNetworkId(NetworkId);
 
@@ -179,6 +181,53 @@ impl PyNetworkId {
     pub fn __str__(&self) -> String {
         self.0.to_string()
     }
+
+    /// Compare with a NetworkId, a network string, or a NetworkType.
+    ///
+    /// Strings are parsed as a network id (e.g. "mainnet", "testnet-10").
+    /// A NetworkType matches any NetworkId of that type, regardless of suffix.
+    ///
+    /// Returns:
+    ///     bool: True if the values refer to the same network, or
+    ///         NotImplemented for other types.
+    pub fn __eq__<'py>(
+        &self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "builtins.object"))] other: &Bound<'py, PyAny>,
+    ) -> Py<PyAny> {
+        let equal = if let Ok(other) = other.cast::<PyNetworkId>() {
+            self.0 == other.borrow().0
+        } else if let Ok(network_type) = other.cast::<PyNetworkType>() {
+            self.0.network_type == NetworkType::from(&*network_type.borrow())
+        } else if let Ok(s) = other.extract::<String>() {
+            NetworkId::from_str(&s).is_ok_and(|network_id| self.0 == network_id)
+        } else {
+            return py.NotImplemented();
+        };
+        PyBool::new(py, equal).to_owned().into_any().unbind()
+    }
+}
+
+/// List the public Kaspa networks.
+///
+/// Includes mainnet followed by the active testnets (e.g. testnet-10).
+/// Devnet and simnet are local networks and are not listed.
+///
+/// Returns:
+///     list[NetworkId]: The known network ids.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "known_networks")]
+pub fn py_known_networks() -> Vec<PyNetworkId> {
+    NetworkId::iter()
+        .filter(|network_id| {
+            matches!(
+                network_id.network_type,
+                NetworkType::Mainnet | NetworkType::Testnet
+            )
+        })
+        .map(PyNetworkId::from)
+        .collect()
 }
 
 impl From<PyNetworkId> for NetworkId {
@@ -1,7 +1,11 @@
 use crate::types::PyBinary;
 use kaspa_consensus_core::tx::ScriptPublicKey;
 use kaspa_utils::hex::FromHex;
-use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+    types::{PyBytes, PyDict, PyInt},
+};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 use std::str::FromStr;
 
@@ -31,6 +35,31 @@ impl PyScriptPublicKey {
         Ok(Self(inner))
     }
 
+    /// Create a script public key from its combined form.
+    ///
+    /// The combined form is the 2-byte big-endian script version followed
+    /// by the script, as returned by RPC (e.g. "0000" + script hex).
+    ///
+    /// Args:
+    ///     data: The combined version and script as hex, bytes, or a list of ints.
+    ///
+    /// Returns:
+    ///     ScriptPublicKey: A new ScriptPublicKey instance.
+    ///
+    /// Raises:
+    ///     ValueError: If the data is shorter than the 2-byte version prefix.
+    #[staticmethod]
+    pub fn from_combined(data: PyBinary) -> PyResult<Self> {
+        let Some((version, script)) = data.data.split_first_chunk::<2>() else {
+            return Err(PyValueError::new_err(format!(
+                "Combined script public key must be at least 2 bytes, got {} bytes",
+                data.data.len()
+            )));
+        };
+        let inner = ScriptPublicKey::new(u16::from_be_bytes(*version), script.into());
+        Ok(Self(inner))
+    }
+
     /// The script bytes as a hex string.
     ///
     /// Returns:
@@ -66,6 +95,27 @@ impl From<ScriptPublicKey> for PyScriptPublicKey {
     }
 }
 
+impl TryFrom<&Bound<'_, PyDict>> for PyScriptPublicKey {
+    type Error = PyErr;
+    fn try_from(dict: &Bound<PyDict>) -> PyResult<Self> {
+        let version = dict.as_any().get_item("version")?;
+        // Name the field instead of surfacing a bare OverflowError
+        let version = version.extract::<u16>().map_err(|err| {
+            if version.is_instance_of::<PyInt>() {
+                PyValueError::new_err(format!(
+                    "`scriptPublicKey.version` must be between 0 and {}, got {version}",
+                    u16::MAX
+                ))
+            } else {
+                err
+            }
+        })?;
+        let script = dict.as_any().get_item("script")?.extract::<PyBinary>()?;
+
+        Self::constructor(version, script)
+    }
+}
+
 impl FromHex for PyScriptPublicKey {
     type Error = PyErr;
 
@@ -0,0 +1,98 @@
+use kaspa_consensus_core::subnets::{
+    SUBNETWORK_ID_COINBASE, SUBNETWORK_ID_NATIVE, SUBNETWORK_ID_SIZE, SubnetworkId,
+};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+/// A 20-byte subnetwork identifier.
+///
+/// Identifies the subnetwork a transaction belongs to. Regular transactions
+/// use the native subnetwork and mining rewards the coinbase subnetwork.
+#[gen_stub_pyclass]
+#[pyclass(name = "SubnetworkId", eq)]
+#[derive(Clone, PartialEq)]
+pub struct PySubnetworkId(SubnetworkId);
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PySubnetworkId {
+    /// The subnetwork of regular (non-coinbase) transactions.
+    ///
+    /// Returns:
+    ///     SubnetworkId: The native subnetwork ID.
+    #[staticmethod]
+    pub fn native() -> Self {
+        Self(SUBNETWORK_ID_NATIVE)
+    }
+
+    /// The subnetwork of coinbase (mining reward) transactions.
+    ///
+    /// Returns:
+    ///     SubnetworkId: The coinbase subnetwork ID.
+    #[staticmethod]
+    pub fn coinbase() -> Self {
+        Self(SUBNETWORK_ID_COINBASE)
+    }
+
+    /// Create a subnetwork ID from a hex string.
+    ///
+    /// Args:
+    ///     hex: A 40-character hex string.
+    ///
+    /// Returns:
+    ///     SubnetworkId: A new SubnetworkId instance.
+    ///
+    /// Raises:
+    ///     ValueError: If `hex` is not valid hex or does not decode to 20 bytes.
+    #[staticmethod]
+    pub fn from_hex(hex: &str) -> PyResult<Self> {
+        let mut data = vec![0u8; hex.len() / 2];
+        faster_hex::hex_decode(hex.as_bytes(), &mut data)
+            .map_err(|err| PyValueError::new_err(format!("Invalid subnetwork_id hex: {err}")))?;
+        Ok(Self(parse_subnetwork_id(&data)?))
+    }
+
+    /// Convert the subnetwork ID to a hex string.
+    ///
+    /// Returns:
+    ///     str: A 40-character hex string.
+    #[pyo3(name = "to_string")]
+    pub fn py_to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// The string representation.
+    ///
+    /// Returns:
+    ///     str: The SubnetworkId as a hex string
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// The byte representation
+    pub fn __bytes__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, self.0.as_ref())
+    }
+}
+
+impl From<SubnetworkId> for PySubnetworkId {
+    fn from(value: SubnetworkId) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PySubnetworkId> for SubnetworkId {
+    fn from(value: PySubnetworkId) -> Self {
+        value.0
+    }
+}
+
+pub(crate) fn parse_subnetwork_id(data: &[u8]) -> PyResult<SubnetworkId> {
+    data.try_into().map_err(|_| {
+        PyValueError::new_err(format!(
+            "subnetwork_id must be {} bytes, got {} bytes",
+            SUBNETWORK_ID_SIZE,
+            data.len()
+        ))
+    })
+}
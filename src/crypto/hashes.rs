@@ -1,5 +1,9 @@
 use kaspa_hashes::Hash;
-use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+    types::{PyBytes, PyInt},
+};
 use pyo3_stub_gen::derive::*;
 use std::str::FromStr;
 
@@ -40,6 +44,41 @@ impl PyHash {
         self.0.to_string()
     }
 
+    /// Create a Hash from an integer.
+    ///
+    /// The integer is read as big-endian, so the most significant byte comes
+    /// first, matching the order of the hex string.
+    ///
+    /// Args:
+    ///     value: An integer from 0 to 2**256 - 1.
+    ///
+    /// Returns:
+    ///     Hash: A new Hash instance.
+    ///
+    /// Raises:
+    ///     ValueError: If `value` is negative or does not fit in 32 bytes.
+    #[staticmethod]
+    pub fn from_int(value: &Bound<'_, PyInt>) -> PyResult<Self> {
+        let bytes = value
+            .call_method1("to_bytes", (32, "big"))
+            .map_err(|_| PyValueError::new_err("`value` must be between 0 and 2**256 - 1"))?;
+        Ok(Self(Hash::from_slice(bytes.cast::<PyBytes>()?.as_bytes())))
+    }
+
+    /// Convert the hash to an integer.
+    ///
+    /// The hash bytes are read as big-endian, so `Hash.from_int(h.to_int()) == h`
+    /// and `h.to_int() == int(h.to_string(), 16)`.
+    ///
+    /// Returns:
+    ///     int: The hash as a non-negative integer.
+    pub fn to_int<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyInt>> {
+        let int = py
+            .get_type::<PyInt>()
+            .call_method1("from_bytes", (self.__bytes__(py), "big"))?;
+        Ok(int.cast_into::<PyInt>()?)
+    }
+
     /// The string representation.
     ///
     /// Returns:
@@ -54,6 +93,41 @@ impl PyHash {
     }
 }
 
+/// Merge two hashes into their parent merkle tree node.
+///
+/// Uses Kaspa's `MerkleBranchHash` (keyed BLAKE2b) over `left` followed by `right`.
+///
+/// Args:
+///     left: The left child hash.
+///     right: The right child hash.
+///
+/// Returns:
+///     Hash: The parent node hash.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "merge_hashes")]
+pub fn py_merge_hashes(left: PyHash, right: PyHash) -> PyHash {
+    kaspa_merkle::merkle_hash(left.0, right.0).into()
+}
+
+/// Compute the merkle root of a list of hashes.
+///
+/// Follows the Kaspa consensus rules used for block transaction and accepted
+/// ID merkle roots: a missing right sibling is replaced by the zero hash, and
+/// an empty list yields the zero hash.
+///
+/// Args:
+///     hashes: The leaf hashes, in order.
+///
+/// Returns:
+///     Hash: The merkle root.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "merkle_root")]
+pub fn py_merkle_root(hashes: Vec<PyHash>) -> PyHash {
+    kaspa_merkle::calc_merkle_root(hashes.into_iter().map(Hash::from)).into()
+}
+
 impl From<PyHash> for Hash {
     fn from(value: PyHash) -> Self {
         value.0
@@ -2,8 +2,8 @@ use crate::{
     consensus::core::script_public_key::PyScriptPublicKey, crypto::txscript::opcodes::PyOpcodes,
     types::PyBinary,
 };
-use kaspa_txscript::{script_builder as native, standard};
-use pyo3::{exceptions::PyException, prelude::*};
+use kaspa_txscript::{MAX_SCRIPTS_SIZE, script_builder as native, standard};
+use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 use std::sync::{Arc, Mutex, MutexGuard};
 use workflow_core::hex::ToHex;
@@ -50,10 +50,20 @@ impl PyScriptBuilder {
     ///
     /// Returns:
     ///     ScriptBuilder: A new ScriptBuilder initialized with the script.
+    ///
+    /// Raises:
+    ///     Exception: If the script exceeds the maximum script size.
     #[staticmethod]
     pub fn from_script(script: PyBinary) -> PyResult<Self> {
         let builder = PyScriptBuilder::default();
         let script: Vec<u8> = script.into();
+        if script.len() > MAX_SCRIPTS_SIZE {
+            return Err(PyException::new_err(format!(
+                "Script size {} exceeds the maximum of {} bytes",
+                script.len(),
+                MAX_SCRIPTS_SIZE
+            )));
+        }
         builder.inner().script_mut().extend(&script);
 
         Ok(builder)
@@ -204,14 +214,22 @@ impl PyScriptBuilder {
             .collect()
     }
 
+    /// Get the script as bytes.
+    ///
+    /// Returns:
+    ///     bytes: The script bytes.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, self.inner().script())
+    }
+
     /// Drain and return the script, clearing the builder.
     ///
     /// Returns:
-    ///     str: The script as a string.
+    ///     str: The script bytes as a hex string.
     pub fn drain(&self) -> String {
         let mut inner = self.inner();
 
-        String::from_utf8(inner.drain()).unwrap()
+        inner.drain().to_hex()
     }
 
     /// Create a P2SH (pay-to-script-hash) locking script.
@@ -247,6 +265,14 @@ impl PyScriptBuilder {
         Ok(generated_script.to_hex())
     }
 
+    /// The current script length in bytes.
+    ///
+    /// Scripts are limited to 10,000 bytes; `add_*` methods raise once
+    /// the limit would be exceeded.
+    fn __len__(&self) -> usize {
+        self.inner().script().len()
+    }
+
     // Cannot be derived via pyclass(eq)
     fn __eq__(&self, other: &PyScriptBuilder) -> bool {
         match (
@@ -1,2 +1,4 @@
 pub mod builder;
 pub mod opcodes;
+pub mod script_hash;
+pub mod utils;
@@ -0,0 +1,109 @@
+use crate::{
+    address::PyAddress,
+    consensus::core::{network::PyNetworkType, script_public_key::PyScriptPublicKey},
+    types::PyBinary,
+};
+use kaspa_consensus_core::network::NetworkType;
+use kaspa_txscript::{script_builder::ScriptBuilder, standard};
+use pyo3::{exceptions::PyException, prelude::*};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use workflow_core::hex::ToHex;
+
+/// A pay-to-script-hash (P2SH) redeem script with its derived forms.
+///
+/// Bundles the locking script, the P2SH address, and the unlocking
+/// signature script for a single redeem script.
+#[gen_stub_pyclass]
+#[pyclass(name = "ScriptHash")]
+#[derive(Clone)]
+pub struct PyScriptHash {
+    redeem_script: Vec<u8>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyScriptHash {
+    /// Create a P2SH helper for a redeem script.
+    ///
+    /// Args:
+    ///     redeem_script: The redeem script as hex, bytes, or list.
+    ///
+    /// Returns:
+    ///     ScriptHash: A new ScriptHash instance.
+    #[new]
+    pub fn new(redeem_script: PyBinary) -> Self {
+        Self {
+            redeem_script: redeem_script.data,
+        }
+    }
+
+    /// The redeem script as a hex string.
+    ///
+    /// Returns:
+    ///     str: The redeem script.
+    #[getter]
+    pub fn get_redeem_script(&self) -> String {
+        self.redeem_script.to_hex()
+    }
+
+    /// The P2SH locking script for the redeem script.
+    ///
+    /// Returns:
+    ///     ScriptPublicKey: The P2SH locking script.
+    #[getter]
+    pub fn get_script_public_key(&self) -> PyScriptPublicKey {
+        standard::pay_to_script_hash_script(&self.redeem_script).into()
+    }
+
+    /// Get the P2SH address for the redeem script.
+    ///
+    /// Args:
+    ///     network: The network type for address encoding.
+    ///
+    /// Returns:
+    ///     Address: The P2SH address.
+    ///
+    /// Raises:
+    ///     Exception: If address extraction fails.
+    pub fn address(
+        &self,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network: PyNetworkType,
+    ) -> PyResult<PyAddress> {
+        let script_public_key = standard::pay_to_script_hash_script(&self.redeem_script);
+        standard::extract_script_pub_key_address(
+            &script_public_key,
+            NetworkType::from(network).into(),
+        )
+        .map(PyAddress::from)
+        .map_err(|err| PyException::new_err(err.to_string()))
+    }
+
+    /// Build the signature script that spends a P2SH output.
+    ///
+    /// Each signature is pushed as data, in the given order, followed by
+    /// the redeem script.
+    ///
+    /// Args:
+    ///     signatures: The signatures (including sighash type byte) as hex,
+    ///         bytes, or list, in the order the redeem script consumes them.
+    ///
+    /// Returns:
+    ///     str: The signature script as a hex string.
+    ///
+    /// Raises:
+    ///     Exception: If a signature or the redeem script cannot be pushed.
+    pub fn signature_script(&self, signatures: Vec<PyBinary>) -> PyResult<String> {
+        let mut builder = ScriptBuilder::new();
+        for signature in signatures.iter() {
+            builder
+                .add_data(signature.data.as_slice())
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+        }
+        let script = standard::pay_to_script_hash_signature_script(
+            self.redeem_script.clone(),
+            builder.drain(),
+        )
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(script.to_hex())
+    }
+}
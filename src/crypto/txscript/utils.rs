@@ -0,0 +1,151 @@
+use crate::{
+    consensus::{client::transaction::PyTransaction, core::script_public_key::PyScriptPublicKey},
+    types::PyBinary,
+};
+use kaspa_consensus_core::{
+    hashing::sighash::SigHashReusedValuesUnsync,
+    tx::{PopulatedTransaction, VerifiableTransaction},
+};
+use kaspa_txscript::{
+    MAX_SCRIPTS_SIZE, TxScriptEngine,
+    caches::Cache,
+    opcodes::codes::{OpCheckLockTimeVerify, OpCheckSequenceVerify},
+    script_builder::ScriptBuilder,
+};
+use pyo3::{
+    exceptions::{PyException, PyIndexError},
+    prelude::*,
+};
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use workflow_core::hex::ToHex;
+
+/// Create an absolute time-locked script (CLTV).
+///
+/// The script is `<lock_time> OP_CHECKLOCKTIMEVERIFY <redeem_script>`.
+/// The spending transaction's lock time must be at least `lock_time`.
+///
+/// Args:
+///     lock_time: DAA score or timestamp before which the script cannot be spent.
+///     redeem_script: The script to run once the time lock is satisfied.
+///
+/// Returns:
+///     str: The script as a hex string.
+///
+/// Raises:
+///     Exception: If the script cannot be built or exceeds the maximum script size.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "create_lock_time_script")]
+pub fn py_create_lock_time_script(lock_time: u64, redeem_script: PyBinary) -> PyResult<String> {
+    let mut builder = ScriptBuilder::new();
+    builder
+        .add_lock_time(lock_time)
+        .and_then(|builder| builder.add_op(OpCheckLockTimeVerify))
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    append_redeem_script(builder, redeem_script)
+}
+
+/// Create a relative time-locked script (CSV).
+///
+/// The script is `<sequence> OP_CHECKSEQUENCEVERIFY <redeem_script>`.
+/// The spending input's sequence must be at least `sequence`.
+///
+/// Args:
+///     sequence: Relative time lock value.
+///     redeem_script: The script to run once the time lock is satisfied.
+///
+/// Returns:
+///     str: The script as a hex string.
+///
+/// Raises:
+///     Exception: If the script cannot be built or exceeds the maximum script size.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "create_sequence_lock_script")]
+pub fn py_create_sequence_lock_script(sequence: u64, redeem_script: PyBinary) -> PyResult<String> {
+    let mut builder = ScriptBuilder::new();
+    builder
+        .add_sequence(sequence)
+        .and_then(|builder| builder.add_op(OpCheckSequenceVerify))
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    append_redeem_script(builder, redeem_script)
+}
+
+/// Run the script VM for a single transaction input.
+///
+/// The input's signature script is replaced by `signature_script` and the
+/// UTXO it spends is given `script_public_key` as its locking script; the
+/// rest of the transaction (including all UTXO entries, which signature
+/// hashing depends on) is taken from `tx`.
+///
+/// Args:
+///     signature_script: The unlocking script to execute.
+///     script_public_key: The locking script it must satisfy.
+///     tx: The spending transaction, with UTXO entries attached to every input.
+///     input_index: Index of the input being spent.
+///
+/// Returns:
+///     bool: True if the script executes successfully, False if execution fails.
+///
+/// Raises:
+///     IndexError: If `input_index` is out of range.
+///     Exception: If UTXO entries are missing.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "verify_script")]
+pub fn py_verify_script(
+    signature_script: PyBinary,
+    script_public_key: PyScriptPublicKey,
+    tx: &PyTransaction,
+    input_index: usize,
+) -> PyResult<bool> {
+    let (mut tx, mut utxos) = tx
+        .inner()
+        .tx_and_utxos()
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    if input_index >= tx.inputs.len() {
+        return Err(PyIndexError::new_err(format!(
+            "Input index {input_index} out of range for transaction with {} inputs",
+            tx.inputs.len()
+        )));
+    }
+    tx.inputs[input_index].signature_script = signature_script.data;
+    utxos[input_index].script_public_key = script_public_key.into();
+
+    let populated = PopulatedTransaction::new(&tx, utxos);
+    Ok(execute_input_script(&populated, input_index).is_ok())
+}
+
+/// Execute the signature script of input `input_index` against the
+/// script public key of the UTXO it spends.
+pub(crate) fn execute_input_script(
+    tx: &PopulatedTransaction,
+    input_index: usize,
+) -> Result<(), String> {
+    let (input, entry) = tx.populated_input(input_index);
+    let reused_values = SigHashReusedValuesUnsync::new();
+    let sig_cache = Cache::new(0);
+    TxScriptEngine::from_transaction_input(
+        tx,
+        input,
+        input_index,
+        entry,
+        &reused_values,
+        &sig_cache,
+    )
+    .execute()
+    .map_err(|err| err.to_string())
+}
+
+fn append_redeem_script(mut builder: ScriptBuilder, redeem_script: PyBinary) -> PyResult<String> {
+    builder.script_mut().extend(redeem_script.data.as_slice());
+    let script = builder.drain();
+    if script.len() > MAX_SCRIPTS_SIZE {
+        return Err(PyException::new_err(format!(
+            "Script size {} exceeds the maximum of {} bytes",
+            script.len(),
+            MAX_SCRIPTS_SIZE
+        )));
+    }
+    Ok(script.to_hex())
+}
@@ -17,6 +17,11 @@ fn kaspa(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_class::<address::PyAddress>()?;
     m.add_class::<address::PyAddressVersion>()?;
+    m.add_class::<address::PyAddressBook>()?;
+    m.add(
+        "AddressNetworkMismatchError",
+        m.py().get_type::<address::AddressNetworkMismatchError>(),
+    )?;
 
     m.add_class::<consensus::client::transaction::PyTransaction>()?;
     m.add_class::<consensus::client::input::PyTransactionInput>()?;
@@ -42,6 +47,10 @@ fn kaspa(m: &Bound<'_, PyModule>) -> PyResult<()> {
         consensus::client::utils::py_pay_to_script_hash_signature_script,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        consensus::client::utils::py_create_data_script,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(
         consensus::client::utils::py_is_script_pay_to_pubkey,
         m
@@ -54,11 +63,20 @@ fn kaspa(m: &Bound<'_, PyModule>) -> PyResult<()> {
         consensus::client::utils::py_is_script_pay_to_script_hash,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        consensus::client::utils::py_transaction_diff,
+        m
+    )?)?;
 
     m.add_class::<consensus::core::hashing::PySighashType>()?;
     m.add_class::<consensus::core::network::PyNetworkId>()?;
     m.add_class::<consensus::core::network::PyNetworkType>()?;
+    m.add_function(wrap_pyfunction!(
+        consensus::core::network::py_known_networks,
+        m
+    )?)?;
     m.add_class::<consensus::core::script_public_key::PyScriptPublicKey>()?;
+    m.add_class::<consensus::core::subnets::PySubnetworkId>()?;
     m.add_class::<consensus::core::tx::TransactionId>()?;
 
     m.add_class::<wallet::bip32::language::PyLanguage>()?;
@@ -68,10 +86,22 @@ fn kaspa(m: &Bound<'_, PyModule>) -> PyResult<()> {
         wallet::core::derivation::py_create_multisig_address,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::derivation::py_derive_account,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::derivation::py_address_indices_with_utxos,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(
         wallet::core::tx::signer::py_sign_transaction,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::signer::py_sign_transactions,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(
         wallet::core::tx::signer::py_create_input_signature,
         m
@@ -80,6 +110,14 @@ fn kaspa(m: &Bound<'_, PyModule>) -> PyResult<()> {
         wallet::core::tx::signer::py_sign_script_hash,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::signer::py_signature_to_compact,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::signer::py_signature_from_compact,
+        m
+    )?)?;
 
     m.add_function(wrap_pyfunction!(wallet::core::utils::py_kaspa_to_sompi, m)?)?;
     m.add_function(wrap_pyfunction!(wallet::core::utils::py_sompi_to_kaspa, m)?)?;
@@ -87,17 +125,47 @@ fn kaspa(m: &Bound<'_, PyModule>) -> PyResult<()> {
         wallet::core::utils::py_sompi_to_kaspa_string_with_suffix,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::utils::py_sompi_to_kaspa_string,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::utils::py_sompi_to_kaspa_string_with_decimals,
+        m
+    )?)?;
 
     m.add_class::<crypto::txscript::builder::PyScriptBuilder>()?;
     m.add_class::<crypto::txscript::opcodes::PyOpcodes>()?;
+    m.add_class::<crypto::txscript::script_hash::PyScriptHash>()?;
+    m.add_function(wrap_pyfunction!(
+        crypto::txscript::utils::py_create_lock_time_script,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        crypto::txscript::utils::py_create_sequence_lock_script,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        crypto::txscript::utils::py_verify_script,
+        m
+    )?)?;
     m.add_class::<crypto::hashes::PyHash>()?;
+    m.add_function(wrap_pyfunction!(crypto::hashes::py_merge_hashes, m)?)?;
+    m.add_function(wrap_pyfunction!(crypto::hashes::py_merkle_root, m)?)?;
 
+    m.add_class::<wallet::core::tx::generator::batch::PyPendingTransactionBatch>()?;
     m.add_class::<wallet::core::tx::generator::generator::PyGenerator>()?;
     m.add_class::<wallet::core::tx::generator::pending::PendingTransaction>()?;
     m.add_class::<wallet::core::tx::generator::summary::PyGeneratorSummary>()?;
+    m.add(
+        "InsufficientFundsError",
+        m.py()
+            .get_type::<wallet::core::tx::generator::generator::InsufficientFundsError>(),
+    )?;
     m.add_class::<wallet::core::utxo::balance::PyBalance>()?;
     m.add_class::<wallet::core::utxo::balance::PyBalanceStrings>()?;
     m.add_class::<wallet::core::utxo::context::PyUtxoContext>()?;
+    m.add_class::<wallet::core::utxo::context::PyUtxoPageIterator>()?;
     m.add_class::<wallet::core::utxo::processor::PyUtxoProcessor>()?;
 
     m.add_function(wrap_pyfunction!(
@@ -108,6 +176,10 @@ fn kaspa(m: &Bound<'_, PyModule>) -> PyResult<()> {
         wallet::core::tx::mass::py_calculate_unsigned_transaction_fee,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::mass::py_minimum_relay_fee,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(
         wallet::core::tx::mass::py_calculate_unsigned_transaction_mass,
         m
@@ -120,6 +192,23 @@ fn kaspa(m: &Bound<'_, PyModule>) -> PyResult<()> {
         wallet::core::tx::mass::py_update_unsigned_transaction_mass,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::mass::py_fee_for_mass,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::mass::py_fee_rate_for_fee,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::standard::py_check_transaction_standard,
+        m
+    )?)?;
+    m.add(
+        "NonStandardTransactionError",
+        m.py()
+            .get_type::<wallet::core::tx::standard::NonStandardTransactionError>(),
+    )?;
 
     m.add_class::<wallet::core::tx::payment::PyPaymentOutput>()?;
 
@@ -135,17 +224,43 @@ fn kaspa(m: &Bound<'_, PyModule>) -> PyResult<()> {
         wallet::core::tx::utils::py_estimate_transactions,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(wallet::core::tx::utils::py_send, m)?)?;
+    m.add_function(wrap_pyfunction!(wallet::core::tx::utils::py_bump_fee, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::utils::py_attach_utxos,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::utils::py_wait_for_acceptance,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::tx::utils::py_select_coins,
+        m
+    )?)?;
 
     m.add_class::<rpc::encoding::PyEncoding>()?;
     m.add_class::<rpc::wrpc::resolver::PyResolver>()?;
     m.add_class::<rpc::wrpc::client::PyNotificationEvent>()?;
     m.add_class::<rpc::wrpc::client::PyRpcClient>()?;
+    m.add(
+        "RpcTimeoutError",
+        m.py().get_type::<rpc::wrpc::client::RpcTimeoutError>(),
+    )?;
 
     m.add_function(wrap_pyfunction!(wallet::core::message::py_sign_message, m)?)?;
     m.add_function(wrap_pyfunction!(
         wallet::core::message::py_verify_message,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::message::py_verify_message_by_address,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        wallet::core::message::py_verify_messages,
+        m
+    )?)?;
 
     m.add_class::<wallet::keys::derivation::PyDerivationPath>()?;
     m.add_class::<wallet::keys::keypair::PyKeypair>()?;
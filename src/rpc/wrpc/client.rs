@@ -4,8 +4,10 @@ use crate::rpc::encoding::PyEncoding;
 use crate::rpc::model::*;
 use crate::rpc::notification::PyNotification;
 use crate::rpc::wrpc::resolver::PyResolver;
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
+use borsh::{BorshDeserialize, BorshSerialize};
 use futures::*;
+use kaspa_addresses::Address;
 use kaspa_notify::listener::ListenerId;
 use kaspa_notify::notification::Notification;
 use kaspa_notify::scope::{
@@ -14,6 +16,7 @@ use kaspa_notify::scope::{
     VirtualChainChangedScope, VirtualDaaScoreChangedScope,
 };
 use kaspa_notify::{connection::ChannelType, events::EventType};
+use kaspa_rpc_core::api::ops::RpcApiOps;
 use kaspa_rpc_core::api::rpc::RpcApi;
 use kaspa_rpc_core::model::*;
 use kaspa_rpc_core::notify::connection::ChannelConnection;
@@ -22,7 +25,7 @@ use kaspa_wrpc_client::{
 };
 use paste::paste;
 use pyo3::{
-    exceptions::PyException,
+    exceptions::{PyException, PyTimeoutError, PyValueError},
     prelude::*,
     types::{PyDict, PyModule, PyTuple},
 };
@@ -37,6 +40,7 @@ use std::{
     time::Duration,
 };
 use workflow_core::channel::{Channel, DuplexChannel};
+use workflow_core::task::sleep;
 use workflow_log::*;
 use workflow_rpc::{client::Ctl, encoding::Encoding};
 
@@ -98,6 +102,118 @@ impl<'py> FromPyObject<'_, 'py> for PyNotificationEvent {
     }
 }
 
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyNotificationEvent {
+    /// The event name, matching the `type` key of the event dicts passed to callbacks.
+    ///
+    /// Returns:
+    ///     str: The event name (e.g. "UtxosChanged").
+    #[getter]
+    pub fn get_kind(&self) -> String {
+        match NotificationEvent::from(self.clone()) {
+            NotificationEvent::All => "All".to_string(),
+            NotificationEvent::Notification(event_type) => event_type.to_string(),
+            NotificationEvent::RpcCtl(ctl) => ctl.to_string(),
+        }
+    }
+
+    /// Get the payload of a `UtxosChanged` event.
+    ///
+    /// A `NotificationEvent` only names an event type, so the payload is read
+    /// from the event dict a callback receives.
+    ///
+    /// Args:
+    ///     event: The event dict passed to a callback.
+    ///
+    /// Returns:
+    ///     dict | None: A dict with "added" and "removed" keys, or None if
+    ///         `event` is not a `UtxosChanged` event.
+    #[staticmethod]
+    pub fn as_utxos_changed<'py>(
+        event: &Bound<'py, PyDict>,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        if !is_event(event, PyNotificationEvent::UtxosChanged)? {
+            return Ok(None);
+        }
+        let payload = PyDict::new(event.py());
+        payload.set_item("added", event.get_item("added")?)?;
+        payload.set_item("removed", event.get_item("removed")?)?;
+        Ok(Some(payload))
+    }
+
+    /// Get the payload of a `BlockAdded` event.
+    ///
+    /// A `NotificationEvent` only names an event type, so the payload is read
+    /// from the event dict a callback receives.
+    ///
+    /// Args:
+    ///     event: The event dict passed to a callback.
+    ///
+    /// Returns:
+    ///     dict | None: The notification data, holding the added block under
+    ///         "block", or None if `event` is not a `BlockAdded` event.
+    #[staticmethod]
+    pub fn as_block_added<'py>(event: &Bound<'py, PyDict>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        if !is_event(event, PyNotificationEvent::BlockAdded)? {
+            return Ok(None);
+        }
+        match event.get_item("data")? {
+            Some(data) => Ok(Some(data.cast_into::<PyDict>()?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Whether this is an RPC connection event (`Connect` or `Disconnect`).
+    ///
+    /// Returns:
+    ///     bool: True for connection events, False for node notifications.
+    #[getter]
+    pub fn get_is_control(&self) -> bool {
+        matches!(
+            self,
+            PyNotificationEvent::Connect | PyNotificationEvent::Disconnect
+        )
+    }
+}
+
+// Whether a callback event dict was raised for `kind`.
+fn is_event(event: &Bound<'_, PyDict>, kind: PyNotificationEvent) -> PyResult<bool> {
+    Ok(match event.get_item("event")? {
+        Some(value) => value
+            .extract::<PyNotificationEvent>()
+            .is_ok_and(|event| event == kind),
+        None => false,
+    })
+}
+
+impl From<EventType> for PyNotificationEvent {
+    fn from(value: EventType) -> Self {
+        match value {
+            EventType::BlockAdded => PyNotificationEvent::BlockAdded,
+            EventType::VirtualChainChanged => PyNotificationEvent::VirtualChainChanged,
+            EventType::FinalityConflict => PyNotificationEvent::FinalityConflict,
+            EventType::FinalityConflictResolved => PyNotificationEvent::FinalityConflictResolved,
+            EventType::UtxosChanged => PyNotificationEvent::UtxosChanged,
+            EventType::SinkBlueScoreChanged => PyNotificationEvent::SinkBlueScoreChanged,
+            EventType::VirtualDaaScoreChanged => PyNotificationEvent::VirtualDaaScoreChanged,
+            EventType::PruningPointUtxoSetOverride => {
+                PyNotificationEvent::PruningPointUtxoSetOverride
+            }
+            EventType::NewBlockTemplate => PyNotificationEvent::NewBlockTemplate,
+        }
+    }
+}
+
+impl From<Ctl> for PyNotificationEvent {
+    fn from(value: Ctl) -> Self {
+        match value {
+            Ctl::Connect => PyNotificationEvent::Connect,
+            Ctl::Disconnect => PyNotificationEvent::Disconnect,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 enum NotificationEvent {
     All,
@@ -164,14 +280,140 @@ impl From<PyNotificationEvent> for NotificationEvent {
     }
 }
 
+pyo3_stub_gen::create_exception!(
+    kaspa,
+    RpcTimeoutError,
+    PyTimeoutError,
+    "Raised when an RPC call does not complete within its `timeout`."
+);
+
+// Convert a snake_case method name (`get_server_info`) to its camelCase wRPC
+// name. Names already in camelCase are returned unchanged.
+fn to_camel_case(name: &str) -> String {
+    let mut camel = String::with_capacity(name.len());
+    let mut upper = false;
+    for ch in name.chars() {
+        if ch == '_' {
+            upper = true;
+        } else if upper {
+            camel.push(ch.to_ascii_uppercase());
+            upper = false;
+        } else {
+            camel.push(ch);
+        }
+    }
+    camel
+}
+
+// Resolve an RPC method name, in camelCase or snake_case, to its wRPC op.
+fn parse_rpc_op(method: &str) -> Option<RpcApiOps> {
+    let camel = to_camel_case(method);
+    let mut chars = camel.chars();
+    let pascal = chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default();
+    [camel, pascal]
+        .into_iter()
+        .find_map(|name| serde_json::from_value(serde_json::Value::String(name)).ok())
+}
+
+// An untyped wRPC message, sent and received as-is by `RpcClient.call`.
+//
+// Only JSON-encoded clients send these; the Borsh impls just satisfy the
+// workflow-rpc message bounds.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct RawMessage(serde_json::Value);
+
+impl BorshSerialize for RawMessage {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.0.to_string(), writer)
+    }
+}
+
+impl BorshDeserialize for RawMessage {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let json = String::deserialize_reader(reader)?;
+        serde_json::from_str(&json)
+            .map(Self)
+            .map_err(std::io::Error::other)
+    }
+}
+
+// Await an RPC call, failing with `RpcTimeoutError` if `timeout` (in milliseconds)
+// elapses before the node responds.
+async fn call_with_timeout<T, E: std::fmt::Display>(
+    timeout: Option<u64>,
+    call: impl Future<Output = std::result::Result<T, E>>,
+) -> PyResult<T> {
+    let result = match timeout {
+        Some(ms) => {
+            match future::select(Box::pin(call), Box::pin(sleep(Duration::from_millis(ms)))).await {
+                future::Either::Left((result, _)) => result,
+                future::Either::Right(_) => {
+                    return Err(RpcTimeoutError::new_err(format!(
+                        "RPC call timed out after {ms} ms"
+                    )));
+                }
+            }
+        }
+        None => call.await,
+    };
+    result.map_err(|err| PyException::new_err(err.to_string()))
+}
+
 #[derive(Clone)]
 struct PyCallback {
     callback: Arc<Py<PyAny>>,
     args: Option<Arc<Py<PyTuple>>>,
     kwargs: Option<Arc<Py<PyDict>>>,
+    // When set, UTXO change events are narrowed to these addresses.
+    addresses: Option<Arc<AHashSet<Address>>>,
 }
 
 impl PyCallback {
+    fn new(
+        py: Python,
+        callback: Py<PyAny>,
+        args: &Bound<'_, PyTuple>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+        addresses: Option<AHashSet<Address>>,
+    ) -> PyResult<Self> {
+        let args = args.into_pyobject(py)?.extract::<Py<PyTuple>>()?;
+
+        let kwargs = match kwargs {
+            Some(kw) => kw.into_pyobject(py)?.extract::<Py<PyDict>>()?,
+            None => PyDict::new(py).into(),
+        };
+
+        Ok(PyCallback {
+            callback: Arc::new(callback),
+            args: Some(Arc::new(args)),
+            kwargs: Some(Arc::new(kwargs)),
+            addresses: addresses.map(Arc::new),
+        })
+    }
+
+    // Entries this callback should see, or all of them if it isn't scoped to addresses.
+    fn scope_utxos<'a>(
+        &self,
+        entries: &'a [RpcUtxosByAddressesEntry],
+    ) -> Vec<&'a RpcUtxosByAddressesEntry> {
+        match &self.addresses {
+            Some(addresses) => entries
+                .iter()
+                .filter(|entry| {
+                    entry
+                        .address
+                        .as_ref()
+                        .is_some_and(|address| addresses.contains(address))
+                })
+                .collect(),
+            None => entries.iter().collect(),
+        }
+    }
+
     fn add_event_to_args(&self, py: Python, event: Bound<PyDict>) -> PyResult<Py<PyTuple>> {
         match &self.args {
             Some(existing_args) => {
@@ -245,6 +487,15 @@ impl Inner {
             (None, None) => None,
         }
     }
+
+    fn register_callback(&self, event: NotificationEvent, callback: PyCallback) {
+        self.callbacks
+            .lock()
+            .unwrap()
+            .entry(event)
+            .or_default()
+            .push(callback);
+    }
 }
 
 /// WebSocket RPC client for communicating with Kaspa nodes.
@@ -550,27 +801,39 @@ impl PyRpcClient {
         kwargs: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
         let event: NotificationEvent = event.into();
+        let py_callback = PyCallback::new(py, callback, args, kwargs, None)?;
+        self.0.register_callback(event, py_callback);
+        Ok(())
+    }
 
-        let args = args.into_pyobject(py)?.extract::<Py<PyTuple>>()?;
-
-        let kwargs = match kwargs {
-            Some(kw) => kw.into_pyobject(py)?.extract::<Py<PyDict>>()?,
-            None => PyDict::new(py).into(),
-        };
-
-        let py_callback = PyCallback {
-            callback: Arc::new(callback),
-            args: Some(Arc::new(args)),
-            kwargs: Some(Arc::new(kwargs)),
-        };
-
-        self.0
-            .callbacks
-            .lock()
-            .unwrap()
-            .entry(event)
-            .or_default()
-            .push(py_callback);
+    /// Register a callback for UTXO changes of specific addresses.
+    ///
+    /// The callback receives the same event as a `utxos-changed` listener,
+    /// but `added` and `removed` only hold entries for `addresses`. Events
+    /// touching none of them are dropped before Python is invoked. The
+    /// addresses must still be subscribed via `subscribe_utxos_changed()`.
+    /// Remove the callback with `remove_event_listener("utxos-changed", callback)`.
+    ///
+    /// Args:
+    ///     addresses: The addresses the callback is interested in.
+    ///     callback: Function to call when UTXOs of the addresses change.
+    ///     *args: Additional arguments to pass to callback.
+    ///     **kwargs: Additional keyword arguments to pass to callback.
+    #[pyo3(signature = (addresses, callback, *args, **kwargs))]
+    fn add_utxos_changed_listener(
+        &self,
+        py: Python,
+        addresses: Vec<PyAddress>,
+        callback: Py<PyAny>,
+        args: &Bound<'_, PyTuple>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<()> {
+        let addresses = addresses.into_iter().map(Address::from).collect();
+        let py_callback = PyCallback::new(py, callback, args, kwargs, Some(addresses))?;
+        self.0.register_callback(
+            NotificationEvent::Notification(EventType::UtxosChanged),
+            py_callback,
+        );
         Ok(())
     }
 
@@ -641,6 +904,62 @@ impl PyRpcClient {
         *self.0.callbacks.lock().unwrap() = Default::default();
         Ok(())
     }
+
+    /// Call an RPC method by name with raw parameters (async).
+    ///
+    /// An escape hatch for RPCs this client has no dedicated method for.
+    /// `params` is sent to the node as-is under the wRPC method `method`,
+    /// which may be given in camelCase (`"getServerInfo"`) or snake_case
+    /// (`"get_server_info"`). Neither the request nor the response is
+    /// validated: the response is the node's raw reply, and its shape may
+    /// change between node versions. Only available on JSON-encoded clients.
+    ///
+    /// Args:
+    ///     method: The wRPC method name, in camelCase or snake_case.
+    ///     params: The request parameters (default: empty).
+    ///     timeout: Optional call timeout in milliseconds.
+    ///
+    /// Returns:
+    ///     dict: The raw response.
+    ///
+    /// Raises:
+    ///     ValueError: If `method` is not a wRPC method, or the client does not
+    ///         use JSON encoding.
+    ///     RpcTimeoutError: If the call exceeds `timeout`.
+    ///     Exception: If the node rejects the request or the call fails.
+    #[pyo3(signature = (method, params=None, timeout=None))]
+    fn call<'py>(
+        &self,
+        py: Python<'py>,
+        method: &str,
+        params: Option<Bound<'py, PyDict>>,
+        timeout: Option<u64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if !matches!(self.0.client.encoding(), Encoding::SerdeJson) {
+            return Err(PyValueError::new_err(
+                "RpcClient.call requires a client using JSON encoding",
+            ));
+        }
+        let op = parse_rpc_op(method)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown RPC method: `{method}`")))?;
+        let params = match params {
+            Some(params) => serde_pyobject::from_pyobject(params)?,
+            None => serde_json::Value::Object(Default::default()),
+        };
+        let client = self.0.client.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let response: RawMessage = call_with_timeout(
+                timeout,
+                client
+                    .rpc_client()
+                    .call::<RawMessage, RawMessage>(op, RawMessage(params)),
+            )
+            .await?;
+
+            Python::attach(|py| Ok(serde_pyobject::to_pyobject(py, &response.0)?.unbind()))
+        })
+    }
 }
 
 impl PyRpcClient {
@@ -722,6 +1041,7 @@ impl PyRpcClient {
                                     Python::attach(|py| {
                                         let event = PyDict::new(py);
                                         event.set_item("type", ctl.to_string()).unwrap();
+                                        event.set_item("event", PyNotificationEvent::from(ctl)).unwrap();
                                         event.set_item("rpc", this.get_url()).unwrap();
 
                                         handler.execute(py, event).unwrap_or_else(|err| panic!("{}", err));
@@ -740,12 +1060,19 @@ impl PyRpcClient {
                                         let UtxosChangedNotification { added, removed } = utxos_changed_notification;
 
                                         for handler in handlers.into_iter() {
+                                            let added = handler.scope_utxos(added);
+                                            let removed = handler.scope_utxos(removed);
+                                            if added.is_empty() && removed.is_empty() && handler.addresses.is_some() {
+                                                continue;
+                                            }
+
                                             Python::attach(|py| {
-                                                let added = serde_pyobject::to_pyobject(py, added).unwrap();
-                                                let removed = serde_pyobject::to_pyobject(py, removed).unwrap();
+                                                let added = serde_pyobject::to_pyobject(py, &added).unwrap();
+                                                let removed = serde_pyobject::to_pyobject(py, &removed).unwrap();
 
                                                 let event = PyDict::new(py);
                                                 event.set_item("type", event_type.to_string()).unwrap();
+                                                event.set_item("event", PyNotificationEvent::from(event_type)).unwrap();
                                                 event.set_item("added", &added).unwrap();
                                                 event.set_item("removed", &removed).unwrap();
 
@@ -762,6 +1089,7 @@ impl PyRpcClient {
                                             Python::attach(|py| {
                                                 let event = PyDict::new(py);
                                                 event.set_item("type", event_type.to_string()).unwrap();
+                                                event.set_item("event", PyNotificationEvent::from(event_type)).unwrap();
                                                 event.set_item("data", PyNotification::from(notification.clone()).to_pyobject(py).unwrap()).unwrap();
 
                                                 handler.execute(py, event).unwrap_or_else(|err| panic!("{}", err));
@@ -983,6 +1311,7 @@ build_wrpc_python_subscriptions!([
 // For each type name (e.g., `GetBlockCount`), this generates:
 // - A Python-callable async method `get_block_count`
 // - That accepts an optional `PyDict` as request parameters
+// - That accepts an optional `timeout` in milliseconds, raising `RpcTimeoutError` when exceeded
 // - Calls the corresponding `get_block_count_call` method on the RPC client
 // - Returns the response as a Python object
 macro_rules! build_wrpc_python_interface {
@@ -992,11 +1321,12 @@ macro_rules! build_wrpc_python_interface {
             #[pymethods]
             impl PyRpcClient {
                 $(
-                    #[pyo3(signature = (request=None))]
+                    #[pyo3(signature = (request=None, timeout=None))]
                     fn [<$name:snake>]<'py>(
                         &self,
                         py: Python<'py>,
-                        request: Option<Bound<'_, PyDict>>
+                        request: Option<Bound<'_, PyDict>>,
+                        timeout: Option<u64>,
                     ) -> PyResult<Bound<'py, PyAny>> {
                         let client = self.0.client.clone();
 
@@ -1005,10 +1335,11 @@ macro_rules! build_wrpc_python_interface {
                             .try_into()?;
 
                         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-                            let response: [<$name Response>] = client
-                                .[<$name:snake _call>](None, request.0)
-                                .await
-                                .map_err(|err| PyException::new_err(err.to_string()))?;
+                            let response: [<$name Response>] = call_with_timeout(
+                                timeout,
+                                client.[<$name:snake _call>](None, request.0),
+                            )
+                            .await?;
 
                             Python::attach(|py| {
                                 Ok(serde_pyobject::to_pyobject(py, &response)?.unbind())
@@ -1052,20 +1383,23 @@ macro_rules! build_wrpc_python_interface_with_args {
             #[pymethods]
             impl PyRpcClient {
                 $(
+                    #[pyo3(signature = (request, timeout=None))]
                     fn [<$name:snake>]<'py>(
                         &self,
                         py: Python<'py>,
-                        request: Bound<'_, PyDict>
+                        request: Bound<'_, PyDict>,
+                        timeout: Option<u64>,
                     ) -> PyResult<Bound<'py, PyAny>> {
                         let client = self.0.client.clone();
 
                         let request: [<Py $name Request>] = request.try_into()?;
 
                         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-                            let response: [<$name Response>] = client
-                                .[<$name:snake _call>](None, request.0)
-                                .await
-                                .map_err(|err| PyException::new_err(err.to_string()))?;
+                            let response: [<$name Response>] = call_with_timeout(
+                                timeout,
+                                client.[<$name:snake _call>](None, request.0),
+                            )
+                            .await?;
 
                             Python::attach(|py| {
                                 Ok(serde_pyobject::to_pyobject(py, &response)?.unbind())
@@ -1,6 +1,6 @@
-use pyo3::exceptions::PyException;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyList};
+use pyo3::types::{PyByteArray, PyBytes, PyList};
 use pyo3_stub_gen::derive::gen_stub_pyclass;
 
 /// Binary data type for flexible input handling.
@@ -10,9 +10,13 @@ use pyo3_stub_gen::derive::gen_stub_pyclass;
 /// data in multiple convenient forms from Python.
 ///
 /// Accepts:
-///     - str: A hexadecimal string (e.g., "deadbeef").
+///     - str: An even-length hexadecimal string, optionally prefixed with
+///       `0x` (e.g., "deadbeef" or "0xdeadbeef").
 ///     - bytes: Python bytes object.
+///     - bytearray: Python bytearray object.
 ///     - list[int]: A list of byte values (0-255).
+///
+/// Any other value raises `ValueError`.
 #[gen_stub_pyclass]
 #[pyclass(name = "Binary")]
 pub struct PyBinary {
@@ -23,30 +27,7 @@ impl<'py> FromPyObject<'_, 'py> for PyBinary {
     type Error = PyErr;
 
     fn extract(value: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
-        if let Ok(str) = value.extract::<String>() {
-            // Python `str` (of valid hex)
-            let mut data = vec![0u8; str.len() / 2];
-            match faster_hex::hex_decode(str.as_bytes(), &mut data) {
-                Ok(()) => Ok(PyBinary { data }),
-                Err(_) => Err(PyException::new_err("Invalid hex string")),
-            }
-        } else if let Ok(py_bytes) = value.cast::<PyBytes>() {
-            // Python `bytes` type
-            Ok(PyBinary {
-                data: py_bytes.as_bytes().to_vec(),
-            })
-        } else if let Ok(op_list) = value.cast::<PyList>() {
-            // Python `[int]` (list of bytes)
-            let data = op_list
-                .iter()
-                .map(|item| item.extract::<u8>())
-                .collect::<PyResult<Vec<u8>>>()?;
-            Ok(PyBinary { data })
-        } else {
-            Err(PyException::new_err(
-                "Expected `str` (of valid hex), `bytes`, or `[int]`",
-            ))
-        }
+        PyBinary::try_from(&*value)
     }
 }
 
@@ -54,27 +35,45 @@ impl TryFrom<&Bound<'_, PyAny>> for PyBinary {
     type Error = PyErr;
     fn try_from(value: &Bound<PyAny>) -> Result<Self, Self::Error> {
         if let Ok(str) = value.extract::<String>() {
-            // Python `str` (of valid hex)
-            let mut data = vec![0u8; str.len() / 2];
-            match faster_hex::hex_decode(str.as_bytes(), &mut data) {
-                Ok(()) => Ok(PyBinary { data }), // Hex string
-                Err(_) => Err(PyException::new_err("Invalid hex string")),
+            // Python `str` (of valid hex, optional `0x` prefix)
+            let hex = str
+                .strip_prefix("0x")
+                .or_else(|| str.strip_prefix("0X"))
+                .unwrap_or(&str);
+            if hex.len() % 2 != 0 {
+                return Err(PyValueError::new_err(
+                    "Invalid hex string: expected an even number of digits",
+                ));
+            }
+            let mut data = vec![0u8; hex.len() / 2];
+            match faster_hex::hex_decode(hex.as_bytes(), &mut data) {
+                Ok(()) => Ok(PyBinary { data }),
+                Err(_) => Err(PyValueError::new_err("Invalid hex string")),
             }
         } else if let Ok(py_bytes) = value.cast::<PyBytes>() {
             // Python `bytes` type
             Ok(PyBinary {
                 data: py_bytes.as_bytes().to_vec(),
             })
+        } else if let Ok(py_bytearray) = value.cast::<PyByteArray>() {
+            // Python `bytearray` type
+            Ok(PyBinary {
+                data: py_bytearray.to_vec(),
+            })
         } else if let Ok(op_list) = value.cast::<PyList>() {
             // Python `[int]` (list of bytes)
             let data = op_list
                 .iter()
-                .map(|item| item.extract::<u8>().unwrap())
-                .collect();
+                .map(|item| {
+                    item.extract::<u8>().map_err(|_| {
+                        PyValueError::new_err("List items must be integers in range 0-255")
+                    })
+                })
+                .collect::<PyResult<Vec<u8>>>()?;
             Ok(PyBinary { data })
         } else {
-            Err(PyException::new_err(
-                "Expected `str` (of valid hex), `bytes`, or `[int]`",
+            Err(PyValueError::new_err(
+                "Expected `str` (of valid hex), `bytes`, `bytearray`, or `[int]`",
             ))
         }
     }
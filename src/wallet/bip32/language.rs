@@ -1,7 +1,11 @@
-use kaspa_bip32::Language;
+use kaspa_bip32::{Language, Mnemonic};
 use pyo3::{exceptions::PyException, prelude::*};
-use pyo3_stub_gen::derive::gen_stub_pyclass_enum;
+use pyo3_stub_gen::derive::{gen_stub_pyclass_enum, gen_stub_pymethods};
 use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Number of words in a BIP-39 word list.
+const WORD_LIST_SIZE: u16 = 2048;
 
 crate::wrap_unit_enum_for_py!(
     /// BIP-39 mnemonic word list language.
@@ -34,3 +38,54 @@ impl<'py> FromPyObject<'_, 'py> for PyLanguage {
         }
     }
 }
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyLanguage {
+    /// The full BIP-39 word list for this language.
+    ///
+    /// Returns:
+    ///     list[str]: The 2048 words, ordered by index.
+    fn word_list(&self) -> Vec<String> {
+        words(self.clone().into()).to_vec()
+    }
+
+    /// Get the word at a given index of the word list.
+    ///
+    /// Args:
+    ///     index: The word index (0-2047).
+    ///
+    /// Returns:
+    ///     str | None: The word, or None if the index is out of range.
+    fn word_at(&self, index: u16) -> Option<String> {
+        words(self.clone().into()).get(index as usize).cloned()
+    }
+}
+
+// Word lists are not exposed by kaspa-bip32, so they are recovered (once per
+// language) from mnemonics whose first word encodes the desired index.
+fn words(language: Language) -> &'static [String] {
+    static ENGLISH: OnceLock<Vec<String>> = OnceLock::new();
+    match language {
+        Language::English => ENGLISH.get_or_init(|| build_word_list(Language::English)),
+    }
+}
+
+fn build_word_list(language: Language) -> Vec<String> {
+    (0..WORD_LIST_SIZE)
+        .map(|index| {
+            // The first word carries the top 11 bits of the entropy
+            let mut entropy = vec![0u8; 16];
+            entropy[0] = (index >> 3) as u8;
+            entropy[1] = ((index & 0x7) << 5) as u8;
+            let mnemonic = Mnemonic::from_entropy(entropy, language)
+                .expect("16 bytes is a valid entropy length");
+            mnemonic
+                .phrase()
+                .split_whitespace()
+                .next()
+                .map(String::from)
+                .unwrap_or_default()
+        })
+        .collect()
+}
@@ -1,6 +1,10 @@
+use crate::types::PyBinary;
 use crate::wallet::bip32::language::PyLanguage;
 use kaspa_bip32::{Error, Language, Mnemonic};
-use pyo3::{exceptions::PyException, prelude::*};
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+};
 use pyo3_stub_gen::derive::*;
 use workflow_core::hex::ToHex;
 
@@ -13,6 +17,12 @@ use workflow_core::hex::ToHex;
 #[pyclass(name = "Mnemonic")]
 pub struct PyMnemonic(Mnemonic);
 
+impl PyMnemonic {
+    pub fn inner(&self) -> &Mnemonic {
+        &self.0
+    }
+}
+
 #[gen_stub_pymethods]
 #[pymethods]
 impl PyMnemonic {
@@ -45,6 +55,9 @@ impl PyMnemonic {
 
     /// Validate a mnemonic phrase.
     ///
+    /// Checks the word count, that every word is in the language word list,
+    /// and the BIP-39 checksum. Returns False rather than raising for invalid phrases.
+    ///
     /// Args:
     ///     phrase: The mnemonic phrase to validate.
     ///     language: Optional language for validation (default: English).
@@ -77,17 +90,20 @@ impl PyMnemonic {
     /// Set the entropy directly.
     ///
     /// Args:
-    ///     value: The entropy as a hex string (16 or 32 bytes).
+    ///     value: The entropy as hex, bytes, or a list of ints (16 or 32 bytes).
+    ///
+    /// Raises:
+    ///     ValueError: If the value is not valid binary data or not 16 or 32 bytes.
     #[setter]
-    pub fn set_entropy(&mut self, value: &str) {
-        // let vec = Vec::<u8>::from_hex(entropy)
-        //     .unwrap_or_else(|err| panic!("invalid entropy `{entropy}`: {err}"));
-        // let len = vec.len();
-        // if len != 16 && len != 32 {
-        //     panic!("Invalid entropy: `{entropy}`")
-        // }
-        self.0.set_entropy(value.to_string());
-        // self.entropy = vec;
+    pub fn set_entropy(&mut self, value: PyBinary) -> PyResult<()> {
+        let len = value.data.len();
+        if len != 16 && len != 32 {
+            return Err(PyValueError::new_err(format!(
+                "Invalid entropy length: expected 16 or 32 bytes, got {len}"
+            )));
+        }
+        self.0.set_entropy(value.data.to_hex());
+        Ok(())
     }
 
     /// Generate a random mnemonic phrase.
@@ -1,13 +1,35 @@
+use kaspa_addresses::Address;
+use kaspa_bip32::{ChildNumber, ExtendedPrivateKey};
+use kaspa_consensus_client::UtxoEntryReference;
 use kaspa_consensus_core::network::NetworkType;
+use kaspa_rpc_core::api::rpc::RpcApi;
+use kaspa_wallet_core::derivation::WalletDerivationManagerTrait;
+use kaspa_wallet_core::rpc::DynRpcApi;
 use kaspa_wallet_core::{derivation::create_address, prelude::AccountKind};
-use kaspa_wallet_keys::publickey::PublicKey;
-use pyo3::{exceptions::PyException, prelude::*};
+use kaspa_wallet_keys::derivation::gen1::WalletDerivationManager;
+use kaspa_wallet_keys::{publickey::PublicKey, xpub::XPub};
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+    types::{PyDict, PyList},
+};
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use secp256k1::SecretKey;
+use std::collections::HashMap;
+use std::sync::Arc;
 
 use crate::{
     address::PyAddress,
+    consensus::client::utxo::PyUtxoEntryReference,
     consensus::core::network::PyNetworkType,
-    wallet::{core::account::kind::PyAccountKind, keys::publickey::PyPublicKey},
+    rpc::wrpc::client::PyRpcClient,
+    wallet::{
+        bip32::phrase::PyMnemonic,
+        core::account::kind::PyAccountKind,
+        keys::{
+            pubkeygen::PyPublicKeyGenerator, publickey::PyPublicKey, xprv::PyXPrv, xpub::PyXPub,
+        },
+    },
 };
 
 /// Create a multisig address from multiple public keys.
@@ -50,3 +72,207 @@ pub fn py_create_multisig_address(
     .map_err(|err| PyException::new_err(err.to_string()))?
     .into())
 }
+
+/// Derive a standard (BIP-44) account from a mnemonic in one call.
+///
+/// Uses the Kaspa derivation path `m/44'/111111'/<account_index>'`, with
+/// receive addresses under `/0/<index>` and change addresses under `/1/<index>`.
+///
+/// Args:
+///     mnemonic: The mnemonic phrase.
+///     passphrase: Optional BIP-39 passphrase (default: none).
+///     network_type: The network type for address encoding.
+///     account_index: The account index (default: 0).
+///
+/// Returns:
+///     dict: A dictionary with keys:
+///         - 'derivation_path' (str): The account derivation path.
+///         - 'xprv' (XPrv): The account extended private key.
+///         - 'xpub' (XPub): The account extended public key.
+///         - 'receive_address' (Address): The first receive address.
+///         - 'change_address' (Address): The first change address.
+///
+/// Raises:
+///     Exception: If derivation fails.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "derive_account")]
+#[pyo3(signature = (mnemonic, network_type, passphrase=None, account_index=0))]
+pub fn py_derive_account<'py>(
+    py: Python<'py>,
+    mnemonic: &PyMnemonic,
+    #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
+    passphrase: Option<&str>,
+    account_index: u32,
+) -> PyResult<Bound<'py, PyDict>> {
+    let seed = mnemonic.inner().to_seed(passphrase.unwrap_or_default());
+    let master = ExtendedPrivateKey::<SecretKey>::new(seed.as_bytes())
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    let path =
+        WalletDerivationManager::build_derivate_path(false, account_index as u64, None, None)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+    let xprv = master
+        .derive_path(&path)
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    let xpub = xprv.public_key();
+
+    let hd_wallet = WalletDerivationManager::from_extended_public_key(xpub.clone(), None)
+        .map_err(|err| PyException::new_err(err.to_string()))?;
+    let network_type = NetworkType::from(network_type);
+    let receive_address = PublicKey::from(
+        hd_wallet
+            .receive_pubkey_manager()
+            .derive_pubkey(0)
+            .map_err(|err| PyException::new_err(err.to_string()))?,
+    )
+    .to_address(network_type)
+    .map_err(|err| PyException::new_err(err.to_string()))?;
+    let change_address = PublicKey::from(
+        hd_wallet
+            .change_pubkey_manager()
+            .derive_pubkey(0)
+            .map_err(|err| PyException::new_err(err.to_string()))?,
+    )
+    .to_address(network_type)
+    .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("derivation_path", path.to_string())?;
+    dict.set_item("xprv", PyXPrv::from(xprv))?;
+    dict.set_item("xpub", PyXPub::new(XPub::from(xpub)))?;
+    dict.set_item("receive_address", PyAddress::from(receive_address))?;
+    dict.set_item("change_address", PyAddress::from(change_address))?;
+    Ok(dict)
+}
+
+/// Find the used receive and change addresses of an account (async).
+///
+/// Scans the receive and change branches of `pubkey_generator` from index 0,
+/// fetching UTXOs for `gap_limit` addresses at a time, and stops each branch
+/// once `gap_limit` consecutive addresses after the last used one are empty.
+/// An address counts as used if it currently holds UTXOs, so addresses that
+/// were spent down to zero are treated as empty.
+///
+/// Args:
+///     rpc: A connected RPC client.
+///     pubkey_generator: Generator for the account's public keys.
+///     network: The network type for address encoding.
+///     gap_limit: Number of consecutive empty addresses that ends the scan of a
+///         branch (default: 20).
+///
+/// Returns:
+///     dict: A dictionary with "receive" and "change" keys, each a list of dicts
+///         in index order with keys:
+///         - 'index' (int): The address index within the branch.
+///         - 'address' (Address): The derived address.
+///         - 'utxos' (list[UtxoEntryReference]): The UTXOs held by the address.
+///
+/// Raises:
+///     ValueError: If `gap_limit` is 0.
+///     Exception: If derivation or an RPC call fails.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "address_indices_with_utxos")]
+#[pyo3(signature = (rpc, pubkey_generator, network, gap_limit=20))]
+pub fn py_address_indices_with_utxos<'py>(
+    py: Python<'py>,
+    rpc: &PyRpcClient,
+    pubkey_generator: &PyPublicKeyGenerator,
+    #[gen_stub(override_type(type_repr = "str | NetworkType"))] network: PyNetworkType,
+    gap_limit: u32,
+) -> PyResult<Bound<'py, PyAny>> {
+    if gap_limit == 0 {
+        return Err(PyValueError::new_err("`gap_limit` must be greater than 0"));
+    }
+    let network_type = NetworkType::from(network);
+    let hd_wallet = pubkey_generator.hd_wallet().clone();
+    let rpc: Arc<DynRpcApi> = rpc.client().clone();
+
+    pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let receive = scan_branch(&rpc, &hd_wallet, false, network_type, gap_limit).await?;
+        let change = scan_branch(&rpc, &hd_wallet, true, network_type, gap_limit).await?;
+
+        Python::attach(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item("receive", used_addresses_to_list(py, receive)?)?;
+            dict.set_item("change", used_addresses_to_list(py, change)?)?;
+            Ok(dict.into_any().unbind())
+        })
+    })
+}
+
+type UsedAddress = (u32, Address, Vec<UtxoEntryReference>);
+
+// Scan one branch in batches, extending the scan window past every used
+// address until `gap_limit` consecutive addresses hold no UTXOs.
+async fn scan_branch(
+    rpc: &Arc<DynRpcApi>,
+    hd_wallet: &WalletDerivationManager,
+    change: bool,
+    network_type: NetworkType,
+    gap_limit: u32,
+) -> PyResult<Vec<UsedAddress>> {
+    let manager = if change {
+        hd_wallet.change_pubkey_manager()
+    } else {
+        hd_wallet.receive_pubkey_manager()
+    };
+
+    let mut used = Vec::new();
+    let mut start = 0;
+    let mut end = gap_limit.min(ChildNumber::HARDENED_FLAG);
+    while start < end {
+        let addresses = manager
+            .derive_pubkey_range(start..end)
+            .map_err(|err| PyException::new_err(err.to_string()))?
+            .into_iter()
+            .map(|pk| PublicKey::from(pk).to_address(network_type))
+            .collect::<Result<Vec<_>, kaspa_wallet_keys::error::Error>>()
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+
+        let mut utxos: HashMap<Address, Vec<UtxoEntryReference>> = HashMap::new();
+        for entry in rpc
+            .get_utxos_by_addresses(addresses.clone())
+            .await
+            .map_err(|err| PyException::new_err(err.to_string()))?
+        {
+            if let Some(address) = entry.address.clone() {
+                utxos
+                    .entry(address)
+                    .or_default()
+                    .push(UtxoEntryReference::from(entry));
+            }
+        }
+
+        let batch_end = end;
+        for (index, address) in (start..batch_end).zip(addresses) {
+            if let Some(entries) = utxos.remove(&address) {
+                end = (index + 1)
+                    .saturating_add(gap_limit)
+                    .min(ChildNumber::HARDENED_FLAG);
+                used.push((index, address, entries));
+            }
+        }
+        start = batch_end;
+    }
+    Ok(used)
+}
+
+fn used_addresses_to_list(py: Python<'_>, used: Vec<UsedAddress>) -> PyResult<Bound<'_, PyList>> {
+    let list = PyList::empty(py);
+    for (index, address, entries) in used {
+        let dict = PyDict::new(py);
+        dict.set_item("index", index)?;
+        dict.set_item("address", PyAddress::from(address))?;
+        dict.set_item(
+            "utxos",
+            entries
+                .into_iter()
+                .map(PyUtxoEntryReference::from)
+                .collect::<Vec<_>>(),
+        )?;
+        list.append(dict)?;
+    }
+    Ok(list)
+}
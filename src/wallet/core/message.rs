@@ -1,4 +1,7 @@
+use crate::address::PyAddress;
+use crate::types::PyBinary;
 use crate::wallet::keys::{privatekey::PyPrivateKey, publickey::PyPublicKey};
+use kaspa_addresses::Version;
 // use kaspa_wallet_core::imports::*;
 use kaspa_wallet_core::message::*;
 use pyo3::{exceptions::PyException, prelude::*};
@@ -68,3 +71,87 @@ pub fn py_verify_message(
     )
     .is_ok())
 }
+
+/// Verify a message signature against the signer's address.
+///
+/// The public key is taken from the address payload, so only `PubKey`
+/// (Schnorr) and `PubKeyECDSA` addresses can be verified.
+///
+/// Args:
+///     message: The original message.
+///     signature: The 64-byte signature as hex, bytes, or a list of ints.
+///     address: The claimed signer's address, as an Address or string.
+///
+/// Returns:
+///     bool: True if the signature is valid for the address, False otherwise.
+///
+/// Raises:
+///     Exception: If the signature or address is malformed, or the address
+///         is a `ScriptHash` address.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "verify_message_by_address")]
+pub fn py_verify_message_by_address(
+    message: String,
+    signature: PyBinary,
+    #[gen_stub(override_type(type_repr = "Address | str"))] address: Bound<'_, PyAny>,
+) -> PyResult<bool> {
+    let address = if let Ok(address) = address.extract::<PyAddress>() {
+        address
+    } else if let Ok(s) = address.extract::<String>() {
+        PyAddress::try_from(s)?
+    } else {
+        return Err(PyException::new_err(
+            "`address` must be either an Address instance or a string",
+        ));
+    };
+
+    if signature.data.len() != 64 {
+        return Err(PyException::new_err(format!(
+            "Invalid signature length: expected 64 bytes, got {}",
+            signature.data.len()
+        )));
+    }
+
+    let xonly_public_key = match address.0.version {
+        Version::PubKey => secp256k1::XOnlyPublicKey::from_slice(address.0.payload.as_slice())
+            .map_err(|err| PyException::new_err(err.to_string()))?,
+        Version::PubKeyECDSA => {
+            secp256k1::PublicKey::from_slice(address.0.payload.as_slice())
+                .map_err(|err| PyException::new_err(err.to_string()))?
+                .x_only_public_key()
+                .0
+        }
+        Version::ScriptHash => {
+            return Err(PyException::new_err(
+                "Cannot verify a message against a ScriptHash address",
+            ));
+        }
+    };
+
+    let pm = PersonalMessage(&message);
+    Ok(verify_message(&pm, &signature.data, &xonly_public_key).is_ok())
+}
+
+/// Verify a batch of message signatures in a single call.
+///
+/// Args:
+///     items: A list of `(message, signature, public_key)` tuples. Signatures
+///         may be hex, bytes, or a list of ints.
+///
+/// Returns:
+///     list[bool]: One result per item, in input order. Malformed signatures
+///         yield False.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "verify_messages")]
+pub fn py_verify_messages(items: Vec<(String, PyBinary, PyPublicKey)>) -> Vec<bool> {
+    items
+        .into_iter()
+        .map(|(message, signature, public_key)| {
+            let pm = PersonalMessage(&message);
+            signature.data.len() == 64
+                && verify_message(&pm, &signature.data, &public_key.0.xonly_public_key).is_ok()
+        })
+        .collect()
+}
@@ -0,0 +1,99 @@
+use super::super::super::imports::*;
+use super::pending::PendingTransaction;
+use crate::rpc::wrpc::client::PyRpcClient;
+use kaspa_wallet_core::tx::generator as native;
+use pyo3::exceptions::PyIndexError;
+use pyo3::types::PyIterator;
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+/// An ordered batch of pending transactions produced by `create_transactions`.
+///
+/// Transactions are kept in the order the Generator produced them. When
+/// UTXOs need compounding, intermediate (batch) transactions come first and
+/// the final transaction spends their outputs, so the batch must be
+/// submitted in order.
+///
+/// Supports `len()`, indexing, and iteration like a list.
+#[gen_stub_pyclass]
+#[pyclass(name = "PendingTransactionBatch")]
+pub struct PyPendingTransactionBatch(Vec<native::PendingTransaction>);
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyPendingTransactionBatch {
+    /// The pending transactions in submission order.
+    ///
+    /// Returns:
+    ///     list[PendingTransaction]: The pending transactions.
+    #[getter]
+    fn get_transactions(&self) -> Vec<PendingTransaction> {
+        self.0
+            .iter()
+            .cloned()
+            .map(PendingTransaction::from)
+            .collect()
+    }
+
+    /// Submit all transactions in order (async).
+    ///
+    /// Each transaction is submitted only after the previous one was accepted
+    /// by the node. Submission stops at the first failure.
+    ///
+    /// Args:
+    ///     rpc_client: The RPC client for submission.
+    ///
+    /// Returns:
+    ///     list[str]: The submitted transaction IDs, in submission order.
+    ///
+    /// Raises:
+    ///     Exception: If any submission fails. Transactions before the failing
+    ///         one remain submitted.
+    fn submit_all<'py>(
+        &self,
+        py: Python<'py>,
+        rpc_client: &PyRpcClient,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let transactions = self.0.clone();
+        let rpc: Arc<DynRpcApi> = rpc_client.client().clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut ids = Vec::with_capacity(transactions.len());
+            for (index, transaction) in transactions.iter().enumerate() {
+                let txid = transaction.try_submit(&rpc).await.map_err(|err| {
+                    PyException::new_err(format!(
+                        "Failed to submit transaction {} of {} ({} submitted): {}",
+                        index + 1,
+                        transactions.len(),
+                        ids.len(),
+                        err
+                    ))
+                })?;
+                ids.push(txid.to_string());
+            }
+            Ok(ids)
+        })
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __getitem__(&self, index: isize) -> PyResult<PendingTransaction> {
+        let len = self.0.len() as isize;
+        let index = if index < 0 { index + len } else { index };
+        if index < 0 || index >= len {
+            return Err(PyIndexError::new_err("batch index out of range"));
+        }
+        Ok(self.0[index as usize].clone().into())
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, self.get_transactions())?.try_iter()
+    }
+}
+
+impl From<Vec<native::PendingTransaction>> for PyPendingTransactionBatch {
+    fn from(transactions: Vec<native::PendingTransaction>) -> Self {
+        Self(transactions)
+    }
+}
@@ -2,16 +2,21 @@ use super::super::super::imports::*;
 use super::pending::PendingTransaction;
 use super::summary::PyGeneratorSummary;
 use crate::consensus::core::network::PyNetworkId;
+use crate::consensus::core::script_public_key::PyScriptPublicKey;
 use crate::{
     consensus::client::utxo::PyUtxoEntryReference, wallet::core::tx::payment::PyPaymentOutput,
 };
 use kaspa_consensus_client::UtxoEntryReference;
+use kaspa_txscript::extract_script_pub_key_address;
+use kaspa_wallet_core::error::Error;
 use kaspa_wallet_core::result::Result;
 use kaspa_wallet_core::tx::{
     Fees, PaymentDestination, PaymentOutput, PaymentOutputs, generator as native,
 };
-use kaspa_wallet_core::utxo::UtxoContext;
+use kaspa_wallet_core::utxo::{NetworkParams, UtxoContext};
+use pyo3::exceptions::PyValueError;
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use std::sync::atomic::{AtomicU64, Ordering};
 use workflow_core::prelude::Abortable;
 
 /// UTXO entries collection for flexible input handling.
@@ -56,6 +61,21 @@ impl<'py> FromPyObject<'_, 'py> for PyUtxoEntries {
     }
 }
 
+impl PyUtxoEntries {
+    /// Drop coinbase entries that haven't reached maturity at `current_daa_score`.
+    pub(crate) fn retain_mature_coinbase(&mut self, current_daa_score: u64, network_id: NetworkId) {
+        let maturity_period =
+            NetworkParams::from(network_id).coinbase_transaction_maturity_period_daa();
+        self.entries.retain(|reference| {
+            let matures_at = reference
+                .utxo
+                .block_daa_score
+                .saturating_add(maturity_period);
+            !reference.utxo.is_coinbase || current_daa_score >= matures_at
+        });
+    }
+}
+
 /// Payment outputs collection for flexible input handling.
 ///
 /// This type is not intended to be instantiated directly from Python.
@@ -100,13 +120,42 @@ impl<'py> FromPyObject<'_, 'py> for PyOutputs {
     }
 }
 
+pyo3_stub_gen::create_exception!(
+    kaspa,
+    InsufficientFundsError,
+    PyException,
+    "Raised when UTXOs cannot cover the outputs plus fees. The `available`, `required` and `deficit` attributes hold the amounts in sompi."
+);
+
+// Build an `InsufficientFundsError` carrying the amounts (in sompi) as attributes.
+pub(crate) fn insufficient_funds_error(available: u64, required: u64) -> PyErr {
+    let deficit = required.saturating_sub(available);
+    let err = InsufficientFundsError::new_err(format!(
+        "Insufficient funds: {available} sompi available, {required} sompi required (deficit {deficit} sompi)"
+    ));
+    Python::attach(|py| {
+        // Setting attributes on a fresh exception instance doesn't fail
+        let value = err.value(py);
+        let _ = value.setattr("available", available);
+        let _ = value.setattr("required", required);
+        let _ = value.setattr("deficit", deficit);
+    });
+    err
+}
+
 /// Transaction generator for building and signing transactions.
 ///
 /// Handles UTXO selection, fee calculation, change outputs, and transaction
 /// splitting for large transfers.
 #[gen_stub_pyclass]
 #[pyclass(name = "Generator")]
-pub struct PyGenerator(Arc<native::Generator>);
+pub struct PyGenerator {
+    inner: Arc<native::Generator>,
+    // Fees paid by the intermediate (compound) transactions yielded so far
+    compound_fees: Arc<AtomicU64>,
+    // Total amount of the entries the generator may spend
+    available: u64,
+}
 
 #[gen_stub_pymethods]
 #[pymethods]
@@ -116,37 +165,92 @@ impl PyGenerator {
     /// Args:
     ///     network_id: The network to build transactions for.
     ///     entries: List of UTXO entries to spend from.
-    ///     change_address: Address to send change to.
+    ///     change_address: Address to send change to. Required unless `change_script`
+    ///         is given.
     ///     outputs: Optional list of payment outputs.
-    ///     payload: Optional transaction payload (OP_RETURN data).
+    ///     payload: Optional payload attached to the final transaction. Its size is
+    ///         included in the transaction mass (and fee).
     ///     fee_rate: Optional fee rate multiplier.
-    ///     priority_fee: Additional fee in sompi.
+    ///     priority_fee: Additional fee in sompi added to the final transaction on top
+    ///         of the network fee. Paid by the sender (deducted from change).
     ///     priority_entries: UTXOs to use first.
     ///     sig_op_count: Signature operations per input (default: 1).
     ///     minimum_signatures: For multisig fee estimation.
+    ///     no_change: Send all remaining funds (minus fees) to the single payment
+    ///         output instead of creating a change output (default: False).
+    ///     current_daa_score: If set, coinbase UTXOs that are not yet mature at this
+    ///         DAA score are skipped.
+    ///     change_script: Locking script to send change to, used instead of
+    ///         `change_address`. Must be a standard pay-to-pubkey, pay-to-pubkey-ECDSA
+    ///         or pay-to-script-hash script.
     ///
     /// Returns:
     ///     Generator: A new Generator instance.
     ///
     /// Raises:
-    ///     Exception: If generator creation fails.
+    ///     ValueError: If neither `change_address` nor `change_script` is given, or
+    ///         `change_script` is not a standard script.
+    ///     Exception: If generator creation fails, or `no_change` is set with more than one output.
+    ///
+    /// Iterating the generator, or calling `estimate()`, raises `InsufficientFundsError`
+    /// if the entries cannot cover the outputs plus fees.
     #[new]
-    #[pyo3(signature = (network_id, entries, change_address, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None))]
+    #[pyo3(signature = (network_id, entries, change_address=None, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None, no_change=None, current_daa_score=None, change_script=None))]
+    // Each option is a Python keyword argument, so they aren't grouped into a struct
+    #[allow(clippy::too_many_arguments)]
     pub fn ctor(
         network_id: PyNetworkId,
-        entries: PyUtxoEntries,
-        change_address: PyAddress,
+        mut entries: PyUtxoEntries,
+        change_address: Option<PyAddress>,
         outputs: Option<PyOutputs>,
         payload: Option<PyBinary>,
         fee_rate: Option<f64>,
         priority_fee: Option<u64>,
-        priority_entries: Option<PyUtxoEntries>,
+        mut priority_entries: Option<PyUtxoEntries>,
         sig_op_count: Option<u8>,
         minimum_signatures: Option<u16>,
+        no_change: Option<bool>,
+        current_daa_score: Option<u64>,
+        change_script: Option<PyScriptPublicKey>,
     ) -> PyResult<Self> {
-        let settings = GeneratorSettings::new(
+        // The native generator pays change to an address, so a change script is
+        // mapped to the address it encodes
+        let change_address: Address = match (change_script, change_address) {
+            (Some(script), _) => extract_script_pub_key_address(
+                &script.into(),
+                NetworkId::from(network_id.clone()).into(),
+            )
+            .map_err(|_| {
+                PyValueError::new_err(
+                    "`change_script` must be a standard pay-to-pubkey or pay-to-script-hash script",
+                )
+            })?,
+            (None, Some(address)) => address.into(),
+            (None, None) => {
+                return Err(PyValueError::new_err(
+                    "Either `change_address` or `change_script` is required",
+                ));
+            }
+        };
+
+        if let Some(current_daa_score) = current_daa_score {
+            let network_id = NetworkId::from(network_id.clone());
+            entries.retain_mature_coinbase(current_daa_score, network_id);
+            if let Some(priority_entries) = priority_entries.as_mut() {
+                priority_entries.retain_mature_coinbase(current_daa_score, network_id);
+            }
+        }
+
+        let available = entries
+            .entries
+            .iter()
+            .chain(priority_entries.iter().flat_map(|p| p.entries.iter()))
+            .map(|reference| reference.amount())
+            .fold(0u64, u64::saturating_add);
+
+        let settings = GeneratorSettings::try_new(
             outputs,
-            change_address.into(),
+            change_address,
             fee_rate,
             priority_fee,
             entries.entries,
@@ -155,7 +259,8 @@ impl PyGenerator {
             minimum_signatures,
             payload.map(Into::into),
             &network_id.to_string(),
-        );
+            no_change.unwrap_or(false),
+        )?;
 
         let settings = match settings.source {
             GeneratorSource::UtxoEntries(utxo_entries) => {
@@ -193,7 +298,11 @@ impl PyGenerator {
         let generator = native::Generator::try_new(settings, None, Some(&abortable))
             .map_err(|err| PyException::new_err(err.to_string()))?;
 
-        Ok(Self(Arc::new(generator)))
+        Ok(Self {
+            inner: Arc::new(generator),
+            compound_fees: Arc::new(AtomicU64::new(0)),
+            available,
+        })
     }
 
     /// Estimate the transaction without generating.
@@ -202,13 +311,11 @@ impl PyGenerator {
     ///     GeneratorSummary: A summary with fee, transaction count, and other details.
     ///
     /// Raises:
+    ///     InsufficientFundsError: If the entries cannot cover the outputs plus fees.
     ///     Exception: If estimation fails.
     pub fn estimate(&self) -> PyResult<PyGeneratorSummary> {
-        self.0
-            .iter()
-            .collect::<Result<Vec<_>>>()
-            .map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(self.0.summary().into())
+        self.try_collect()?;
+        Ok(self.summary())
     }
 
     /// Get the summary after generation.
@@ -216,18 +323,47 @@ impl PyGenerator {
     /// Returns:
     ///     GeneratorSummary: The generation summary with fees and transaction details.
     pub fn summary(&self) -> PyGeneratorSummary {
-        self.0.summary().into()
+        PyGeneratorSummary::new(
+            self.inner.summary(),
+            self.compound_fees.load(Ordering::Relaxed),
+        )
     }
 }
 
 impl PyGenerator {
     pub fn iter(&self) -> impl Iterator<Item = Result<native::PendingTransaction>> {
-        self.0.iter()
+        let compound_fees = self.compound_fees.clone();
+        self.inner.iter().inspect(move |result| {
+            if let Ok(transaction) = result
+                && transaction.is_batch()
+            {
+                compound_fees.fetch_add(transaction.fees(), Ordering::Relaxed);
+            }
+        })
+    }
+
+    /// Generate all remaining transactions.
+    pub fn try_collect(&self) -> PyResult<Vec<native::PendingTransaction>> {
+        self.iter()
+            .collect::<Result<Vec<_>>>()
+            .map_err(|err| self.map_error(err))
+    }
+
+    fn map_error(&self, err: Error) -> PyErr {
+        match err {
+            Error::InsufficientFunds {
+                additional_needed, ..
+            } => insufficient_funds_error(
+                self.available,
+                self.available.saturating_add(additional_needed),
+            ),
+            err => PyException::new_err(err.to_string()),
+        }
     }
 
     #[allow(dead_code)]
     pub fn stream(&self) -> impl Stream<Item = Result<native::PendingTransaction>> {
-        self.0.stream()
+        self.inner.stream()
     }
 }
 
@@ -245,15 +381,13 @@ impl PyGenerator {
     ///     PendingTransaction | None: The next transaction to sign and submit.
     ///
     /// Raises:
+    ///     InsufficientFundsError: If the entries cannot cover the outputs plus fees.
     ///     Exception: If transaction generation fails.
     fn __next__(slf: PyRefMut<Self>) -> PyResult<Option<PendingTransaction>> {
-        match slf.0.iter().next() {
+        match slf.iter().next() {
             Some(result) => match result {
                 Ok(transaction) => Ok(Some(transaction.into())),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyException, _>(format!(
-                    "{}",
-                    e
-                ))),
+                Err(e) => Err(slf.map_error(e)),
             },
             None => Ok(None),
         }
@@ -283,9 +417,9 @@ struct GeneratorSettings {
 }
 
 impl GeneratorSettings {
-    pub fn new(
+    pub fn try_new(
         outputs: Option<PyOutputs>,
-        change_address: Address,
+        mut change_address: Address,
         fee_rate: Option<f64>,
         priority_fee: Option<u64>,
         entries: Vec<UtxoEntryReference>,
@@ -294,10 +428,21 @@ impl GeneratorSettings {
         minimum_signatures: Option<u16>,
         payload: Option<Vec<u8>>,
         network_id: &str,
-    ) -> GeneratorSettings {
+        no_change: bool,
+    ) -> PyResult<GeneratorSettings> {
         let network_id = NetworkId::from_str(network_id).unwrap();
 
         let final_transaction_destination = match outputs {
+            // Sweep into the single output by treating its address as the change address
+            Some(py_outputs) if no_change => {
+                let [output] = py_outputs.outputs.as_slice() else {
+                    return Err(PyException::new_err(
+                        "no_change requires exactly one payment output",
+                    ));
+                };
+                change_address = output.address.clone();
+                PaymentDestination::Change
+            }
             Some(py_outputs) => PaymentOutputs {
                 outputs: py_outputs.outputs,
             }
@@ -308,7 +453,9 @@ impl GeneratorSettings {
         let fee_rate =
             fee_rate.and_then(|v| (v.is_finite() && !v.is_nan() && v >= 1e-8).then_some(v));
 
+        // With no change output, the priority fee is taken from the swept amount
         let final_priority_fee = match priority_fee {
+            Some(fee) if no_change => Fees::ReceiverPays(fee),
             Some(fee) => fee.into(),
             None => Fees::None,
         };
@@ -320,7 +467,7 @@ impl GeneratorSettings {
 
         let minimum_signatures = minimum_signatures.unwrap_or(1);
 
-        GeneratorSettings {
+        Ok(GeneratorSettings {
             network_id: Some(network_id),
             source: generator_source,
             priority_utxo_entries: priority_entries,
@@ -332,6 +479,6 @@ impl GeneratorSettings {
             sig_op_count,
             minimum_signatures,
             payload,
-        }
+        })
     }
 }
@@ -1,8 +1,10 @@
+pub mod batch;
 #[allow(clippy::module_inception)]
 pub mod generator;
 pub mod pending;
 pub mod summary;
 
+pub use batch::*;
 pub use generator::*;
 pub use pending::*;
 pub use summary::*;
@@ -124,6 +124,29 @@ impl PendingTransaction {
             .collect()
     }
 
+    /// Get the address of the UTXO spent by each input, in input order.
+    ///
+    /// Use this to tell which key has to sign each input when the transaction
+    /// spends UTXOs from several addresses.
+    ///
+    /// Returns:
+    ///     list[Address | None]: The address per input, or None if the UTXO has no address.
+    fn input_addresses(&self) -> Vec<Option<PyAddress>> {
+        let transaction =
+            Transaction::from_cctx_transaction(&self.0.transaction(), self.0.utxo_entries());
+        transaction
+            .inner()
+            .inputs
+            .iter()
+            .map(|input| {
+                input
+                    .get_utxo()
+                    .and_then(|utxo| utxo.utxo.address.clone())
+                    .map(PyAddress::from)
+            })
+            .collect()
+    }
+
     /// Get the UTXO entries being spent by this transaction.
     ///
     /// Returns:
@@ -1,4 +1,5 @@
 // use crate::imports::*;
+use crate::wallet::core::utils::sompi_to_kaspa_decimal_string;
 use kaspa_wallet_core::tx::generator as core;
 use pyo3::prelude::*;
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
@@ -13,7 +14,19 @@ use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 ///     create_transactions, Generator
 #[gen_stub_pyclass]
 #[pyclass(name = "GeneratorSummary")]
-pub struct PyGeneratorSummary(core::GeneratorSummary);
+pub struct PyGeneratorSummary {
+    inner: core::GeneratorSummary,
+    compound_fees: u64,
+}
+
+impl PyGeneratorSummary {
+    pub fn new(inner: core::GeneratorSummary, compound_fees: u64) -> Self {
+        Self {
+            inner,
+            compound_fees,
+        }
+    }
+}
 
 #[gen_stub_pymethods]
 #[pymethods]
@@ -24,7 +37,7 @@ impl PyGeneratorSummary {
     ///     str: The network type string.
     #[getter]
     pub fn get_network_type(&self) -> String {
-        self.0.network_type().to_string()
+        self.inner.network_type().to_string()
     }
 
     /// The total number of UTXOs consumed.
@@ -33,7 +46,7 @@ impl PyGeneratorSummary {
     ///     int: The UTXO count.
     #[getter]
     pub fn get_utxos(&self) -> usize {
-        self.0.aggregated_utxos()
+        self.inner.aggregated_utxos()
     }
 
     /// The total fees across all generated transactions in sompi.
@@ -42,7 +55,16 @@ impl PyGeneratorSummary {
     ///     int: The aggregate fee amount.
     #[getter]
     pub fn get_fees(&self) -> u64 {
-        self.0.aggregate_fees()
+        self.inner.aggregate_fees()
+    }
+
+    /// The total fees across all generated transactions in KAS.
+    ///
+    /// Returns:
+    ///     str: The aggregate fee amount as an exact decimal string (e.g. "0.00002036").
+    #[getter]
+    pub fn get_total_fees_kas(&self) -> String {
+        sompi_to_kaspa_decimal_string(self.inner.aggregate_fees())
     }
 
     /// The number of transactions generated.
@@ -51,7 +73,34 @@ impl PyGeneratorSummary {
     ///     int: The transaction count.
     #[getter]
     pub fn get_transactions(&self) -> usize {
-        self.0.number_of_generated_transactions()
+        self.inner.number_of_generated_transactions()
+    }
+
+    /// The number of intermediate (compound) transactions generated.
+    ///
+    /// Compound transactions consolidate UTXOs when the inputs needed for a
+    /// payment exceed the mass limit of a single transaction. Their outputs
+    /// feed the final payment transaction.
+    ///
+    /// Returns:
+    ///     int: The compound transaction count.
+    #[getter]
+    pub fn get_compound_transactions(&self) -> usize {
+        let generated = self.inner.number_of_generated_transactions();
+        if self.inner.final_transaction_id().is_some() {
+            generated.saturating_sub(1)
+        } else {
+            generated
+        }
+    }
+
+    /// The total fees paid by compound transactions in sompi.
+    ///
+    /// Returns:
+    ///     int: The aggregate compound transaction fee amount.
+    #[getter]
+    pub fn get_compound_fees(&self) -> u64 {
+        self.compound_fees
     }
 
     /// The final transaction amount in sompi.
@@ -60,7 +109,7 @@ impl PyGeneratorSummary {
     ///     int | None: The final amount, or None if not applicable.
     #[getter]
     pub fn get_final_amount(&self) -> Option<u64> {
-        self.0.final_transaction_amount()
+        self.inner.final_transaction_amount()
     }
 
     /// The ID of the final transaction.
@@ -69,20 +118,17 @@ impl PyGeneratorSummary {
     ///     str | None: The transaction ID, or None if not yet generated.
     #[getter]
     pub fn get_final_transaction_id(&self) -> Option<String> {
-        self.0.final_transaction_id().map(|id| id.to_string())
+        self.inner.final_transaction_id().map(|id| id.to_string())
     }
 
     // Cannot be derived via pyclass(eq)
     fn __eq__(&self, other: &PyGeneratorSummary) -> bool {
-        match (bincode::serialize(&self.0), bincode::serialize(&other.0)) {
-            (Ok(a), Ok(b)) => a == b,
+        match (
+            bincode::serialize(&self.inner),
+            bincode::serialize(&other.inner),
+        ) {
+            (Ok(a), Ok(b)) => a == b && self.compound_fees == other.compound_fees,
             _ => false,
         }
     }
 }
-
-impl From<core::GeneratorSummary> for PyGeneratorSummary {
-    fn from(inner: core::GeneratorSummary) -> Self {
-        Self(inner)
-    }
-}
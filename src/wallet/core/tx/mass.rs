@@ -5,6 +5,7 @@ use super::super::imports::*;
 use kaspa_consensus_core::config::params::Params;
 use kaspa_consensus_core::mass::{UtxoCell, calc_storage_mass};
 use kaspa_wallet_core::tx::{MAXIMUM_STANDARD_TRANSACTION_MASS, mass};
+use pyo3::exceptions::PyValueError;
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
 // use pyo3::prelude::*;
 
@@ -30,24 +31,19 @@ pub fn py_maximum_standard_transaction_mass() -> u64 {
 ///     int: The calculated transaction mass.
 ///
 /// Raises:
+///     ValueError: If the transaction has no inputs or no outputs.
 ///     Exception: If mass calculation fails.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "calculate_transaction_mass")]
 #[pyo3(signature = (network_id, tx, minimum_signatures=None))]
 pub fn py_calculate_unsigned_transaction_mass(
+    py: Python<'_>,
     network_id: PyNetworkId,
     tx: PyTransaction,
     minimum_signatures: Option<u16>,
 ) -> PyResult<u64> {
-    let network_id: NetworkId = network_id.into();
-    let consensus_params = Params::from(network_id);
-    let mc = mass::MassCalculator::new(&consensus_params);
-    mc.calc_overall_mass_for_unsigned_client_transaction(
-        &tx.into(),
-        minimum_signatures.unwrap_or(1),
-    )
-    .map_err(|err| PyException::new_err(err.to_string()))
+    unsigned_transaction_mass(py, network_id, &tx.into(), minimum_signatures).map(|(mass, _)| mass)
 }
 
 /// Calculate and update the mass field of an unsigned transaction.
@@ -61,23 +57,20 @@ pub fn py_calculate_unsigned_transaction_mass(
 ///     bool: True if mass is within limits and was updated, False if too large.
 ///
 /// Raises:
+///     ValueError: If the transaction has no inputs or no outputs.
 ///     Exception: If mass calculation fails.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "update_transaction_mass")]
 #[pyo3(signature = (network_id, tx, minimum_signatures=None))]
 pub fn py_update_unsigned_transaction_mass(
+    py: Python<'_>,
     network_id: PyNetworkId,
     tx: PyTransaction,
     minimum_signatures: Option<u16>,
 ) -> PyResult<bool> {
-    let network_id: NetworkId = network_id.into();
-    let consensus_params = Params::from(network_id);
-    let mc = mass::MassCalculator::new(&consensus_params);
     let tx: kaspa_consensus_client::Transaction = tx.into();
-    let mass = mc
-        .calc_overall_mass_for_unsigned_client_transaction(&tx, minimum_signatures.unwrap_or(1))
-        .map_err(|err| PyException::new_err(err.to_string()))?;
+    let (mass, _) = unsigned_transaction_mass(py, network_id, &tx, minimum_signatures)?;
     if mass > MAXIMUM_STANDARD_TRANSACTION_MASS {
         Ok(false)
     } else {
@@ -97,25 +90,19 @@ pub fn py_update_unsigned_transaction_mass(
 ///     int | None: The fee in sompi, or None if mass exceeds limits.
 ///
 /// Raises:
+///     ValueError: If the transaction has no inputs or no outputs.
 ///     Exception: If mass calculation fails.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "calculate_transaction_fee")]
 #[pyo3(signature = (network_id, tx, minimum_signatures=None))]
 pub fn py_calculate_unsigned_transaction_fee(
+    py: Python<'_>,
     network_id: PyNetworkId,
     tx: PyTransaction,
     minimum_signatures: Option<u16>,
 ) -> PyResult<Option<u64>> {
-    let network_id: NetworkId = network_id.into();
-    let consensus_params = Params::from(network_id);
-    let mc = mass::MassCalculator::new(&consensus_params);
-    let mass = mc
-        .calc_overall_mass_for_unsigned_client_transaction(
-            &tx.into(),
-            minimum_signatures.unwrap_or(1),
-        )
-        .map_err(|err| PyException::new_err(err.to_string()))?;
+    let (mass, mc) = unsigned_transaction_mass(py, network_id, &tx.into(), minimum_signatures)?;
     if mass > MAXIMUM_STANDARD_TRANSACTION_MASS {
         Ok(None)
     } else {
@@ -123,6 +110,41 @@ pub fn py_calculate_unsigned_transaction_fee(
     }
 }
 
+/// Calculate the minimum fee a node will relay for a transaction.
+///
+/// The fee is derived from the transaction's overall mass (compute and
+/// storage) at the minimum relay fee rate. Transactions paying less are
+/// rejected from the mempool.
+///
+/// Args:
+///     network_id: The network identifier.
+///     tx: The transaction to calculate the minimum fee for.
+///     minimum_signatures: Minimum signatures per input (default: 1).
+///
+/// Returns:
+///     int: The minimum relay fee in sompi.
+///
+/// Raises:
+///     ValueError: If the transaction has no inputs or no outputs.
+///     Exception: If mass calculation fails or the mass exceeds the maximum
+///         standard transaction mass.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "minimum_relay_fee")]
+#[pyo3(signature = (network_id, tx, minimum_signatures=None))]
+pub fn py_minimum_relay_fee(
+    py: Python<'_>,
+    network_id: PyNetworkId,
+    tx: PyTransaction,
+    minimum_signatures: Option<u16>,
+) -> PyResult<u64> {
+    py_calculate_unsigned_transaction_fee(py, network_id, tx, minimum_signatures)?.ok_or_else(|| {
+        PyException::new_err(format!(
+            "Transaction mass exceeds the maximum standard transaction mass {MAXIMUM_STANDARD_TRANSACTION_MASS}"
+        ))
+    })
+}
+
 /// Calculate the storage mass for a transaction.
 ///
 /// Storage mass penalizes transactions that increase the UTXO set size
@@ -135,6 +157,9 @@ pub fn py_calculate_unsigned_transaction_fee(
 ///
 /// Returns:
 ///     int | None: The storage mass, or None if not applicable.
+///
+/// Raises:
+///     ValueError: If `input_values` or `output_values` is empty.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "calculate_storage_mass")]
@@ -143,6 +168,16 @@ pub fn py_calculate_storage_mass(
     input_values: Vec<u64>,
     output_values: Vec<u64>,
 ) -> PyResult<Option<u64>> {
+    if input_values.is_empty() {
+        return Err(PyValueError::new_err(
+            "Cannot calculate storage mass with no input values",
+        ));
+    }
+    if output_values.is_empty() {
+        return Err(PyValueError::new_err(
+            "Cannot calculate storage mass with no output values",
+        ));
+    }
     let network_id: NetworkId = network_id.into();
     let consensus_params = Params::from(network_id);
 
@@ -164,3 +199,89 @@ pub fn py_calculate_storage_mass(
 
     Ok(storage_mass)
 }
+
+/// Calculate the absolute fee for a transaction mass at a given fee rate.
+///
+/// The fee is `mass * fee_rate` rounded up to the next whole sompi, so the
+/// resulting fee rate is never below `fee_rate`.
+///
+/// Args:
+///     mass: The transaction mass in grams.
+///     fee_rate: The fee rate in sompi per gram.
+///
+/// Returns:
+///     int: The fee in sompi.
+///
+/// Raises:
+///     ValueError: If `fee_rate` is negative or not finite, or the fee overflows.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "fee_for_mass")]
+pub fn py_fee_for_mass(mass: u64, fee_rate: f64) -> PyResult<u64> {
+    if !fee_rate.is_finite() || fee_rate < 0.0 {
+        return Err(PyValueError::new_err(format!(
+            "fee_rate must be a finite, non-negative number, got {fee_rate}"
+        )));
+    }
+    let fee = (mass as f64 * fee_rate).ceil();
+    if fee >= u64::MAX as f64 {
+        return Err(PyValueError::new_err("fee overflows u64"));
+    }
+    Ok(fee as u64)
+}
+
+/// Calculate the fee rate paid by a fee for a transaction mass.
+///
+/// The inverse of `fee_for_mass`: returns `fee / mass` without rounding.
+///
+/// Args:
+///     fee: The fee in sompi.
+///     mass: The transaction mass in grams.
+///
+/// Returns:
+///     float: The fee rate in sompi per gram.
+///
+/// Raises:
+///     ValueError: If `mass` is zero.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "fee_rate_for_fee")]
+pub fn py_fee_rate_for_fee(fee: u64, mass: u64) -> PyResult<f64> {
+    if mass == 0 {
+        return Err(PyValueError::new_err("mass must be greater than zero"));
+    }
+    Ok(fee as f64 / mass as f64)
+}
+
+// Computes the overall mass of an unsigned transaction with the GIL released,
+// returning the calculator so callers can derive fees from the same params.
+pub(crate) fn unsigned_transaction_mass(
+    py: Python<'_>,
+    network_id: PyNetworkId,
+    tx: &kaspa_consensus_client::Transaction,
+    minimum_signatures: Option<u16>,
+) -> PyResult<(u64, mass::MassCalculator)> {
+    if tx.inner().inputs.is_empty() {
+        return Err(PyValueError::new_err(
+            "Cannot calculate the mass of a transaction with no inputs",
+        ));
+    }
+    if tx.inner().outputs.is_empty() {
+        return Err(PyValueError::new_err(
+            "Cannot calculate the mass of a transaction with no outputs",
+        ));
+    }
+    let network_id: NetworkId = network_id.into();
+    let consensus_params = Params::from(network_id);
+    let mc = mass::MassCalculator::new(&consensus_params);
+    let mass = py
+        .detach(|| {
+            mc.calc_overall_mass_for_unsigned_client_transaction(
+                tx,
+                minimum_signatures.unwrap_or(1),
+            )
+            .map_err(|err| err.to_string())
+        })
+        .map_err(PyException::new_err)?;
+    Ok((mass, mc))
+}
@@ -2,4 +2,5 @@ pub mod generator;
 pub mod mass;
 pub mod payment;
 pub mod signer;
+pub mod standard;
 pub mod utils;
@@ -1,12 +1,10 @@
+use kaspa_consensus_client::TransactionOutput;
 use kaspa_wallet_core::tx::payment::PaymentOutput;
-use pyo3::{
-    exceptions::{PyException, PyKeyError},
-    prelude::*,
-    types::PyDict,
-};
+use pyo3::{exceptions::PyKeyError, prelude::*, types::PyDict};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 
-use crate::address::PyAddress;
+use crate::address::{PyAddress, extract_address};
+use crate::consensus::client::output::PyTransactionOutput;
 
 /// A payment destination with address and amount.
 ///
@@ -20,6 +18,60 @@ pub struct PyPaymentOutput(PaymentOutput);
 #[gen_stub_pymethods]
 #[pymethods]
 impl PyPaymentOutput {
+    /// Create a new payment output.
+    ///
+    /// Args:
+    ///     address: The destination address, as an Address or string.
+    ///     amount: The amount to send in sompi.
+    ///
+    /// Returns:
+    ///     PaymentOutput: A new PaymentOutput instance.
+    ///
+    /// Raises:
+    ///     Exception: If the address is invalid.
+    #[new]
+    pub fn constructor(
+        #[gen_stub(override_type(type_repr = "Address | str"))] address: Bound<'_, PyAny>,
+        amount: u64,
+    ) -> PyResult<Self> {
+        let address = extract_address(&address)?;
+        Ok(Self(PaymentOutput::new(address.into(), amount)))
+    }
+
+    /// The destination address.
+    ///
+    /// Returns:
+    ///     Address: The address receiving the payment.
+    #[getter]
+    pub fn get_address(&self) -> PyAddress {
+        self.0.address.clone().into()
+    }
+
+    /// The amount in sompi.
+    ///
+    /// Returns:
+    ///     int: The payment amount in sompi.
+    #[getter]
+    pub fn get_amount(&self) -> u64 {
+        self.0.amount
+    }
+
+    /// Convert to a transaction output paying `amount` to `address`.
+    ///
+    /// Returns:
+    ///     TransactionOutput: An output with the address's pay-to-address locking script.
+    pub fn to_transaction_output(&self) -> PyResult<PyTransactionOutput> {
+        Ok(TransactionOutput::from(self.0.clone()).into())
+    }
+
+    /// The amount in sompi, so outputs can be used with `int()` and `sum()`.
+    ///
+    /// Returns:
+    ///     int: The payment amount in sompi.
+    fn __int__(&self) -> u64 {
+        self.0.amount
+    }
+
     // Cannot be derived via pyclass(eq)
     fn __eq__(&self, other: &PyPaymentOutput) -> bool {
         match (bincode::serialize(&self.0), bincode::serialize(&other.0)) {
@@ -42,15 +94,7 @@ impl TryFrom<&Bound<'_, PyDict>> for PyPaymentOutput {
             .get_item("address")?
             .ok_or_else(|| PyKeyError::new_err("Key `address` not present"))?;
 
-        let address = if let Ok(address) = address_value.extract::<PyAddress>() {
-            address
-        } else if let Ok(s) = address_value.extract::<String>() {
-            PyAddress::try_from(s).map_err(|err| PyException::new_err(format!("{}", err)))?
-        } else {
-            return Err(PyException::new_err(
-                "Addresses must be either an Address instance or a string",
-            ));
-        };
+        let address = extract_address(&address_value)?;
 
         let amount: u64 = value
             .get_item("amount")?
@@ -1,17 +1,25 @@
 use crate::{
     consensus::{client::transaction::PyTransaction, core::hashing::PySighashType},
     crypto::hashes::PyHash,
+    types::PyBinary,
     wallet::keys::privatekey::PyPrivateKey,
 };
 use kaspa_consensus_client::{Transaction, sign_with_multiple_v3};
 use kaspa_consensus_core::{
-    hashing::{sighash_type::SIG_HASH_ALL, wasm::SighashType},
+    hashing::{
+        sighash_type::{SIG_HASH_ALL, SigHashType},
+        wasm::SighashType,
+    },
     sign::{sign_input, verify},
     tx::PopulatedTransaction,
 };
 use kaspa_hashes::Hash;
 use kaspa_wallet_core::result::Result;
-use pyo3::{exceptions::PyException, prelude::*, types::PyList};
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+    types::PyList,
+};
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
 use workflow_core::hex::ToHex;
 use zeroize::Zeroize;
@@ -35,17 +43,64 @@ pub fn py_sign_transaction<'py>(
     signer: Bound<'py, PyList>,
     verify_sig: bool,
 ) -> PyResult<PyTransaction> {
-    let mut private_keys: Vec<[u8; 32]> = Vec::with_capacity(signer.len());
-    for item in signer.iter() {
-        let key: PyRef<'_, PyPrivateKey> = item.extract()?;
-        private_keys.push(key.secret_bytes());
-    }
+    let mut private_keys = extract_private_keys(&signer)?;
 
     let transaction: Transaction = tx.into();
-    let tx = sign_transaction(&transaction, &private_keys, verify_sig)
-        .map_err(|err| PyException::new_err(format!("Unable to sign: {err:?}")))?;
-    private_keys.zeroize();
-    Ok(tx.clone().into())
+    // Signing is pure Rust, so release the GIL while it runs
+    let result = signer.py().detach(|| {
+        let result = sign_transaction(&transaction, &private_keys, verify_sig)
+            .map(|tx| tx.clone())
+            .map_err(|err| format!("Unable to sign: {err:?}"));
+        private_keys.zeroize();
+        result
+    });
+    Ok(result.map_err(PyException::new_err)?.into())
+}
+
+/// Sign a batch of transactions with one or more private keys.
+///
+/// Equivalent to calling `sign_transaction` for each transaction, but done
+/// in a single call with the GIL released for the whole batch.
+///
+/// Args:
+///     txs: The transactions to sign.
+///     signer: List of PrivateKey objects for signing.
+///     verify_sig: Whether to verify signatures after signing (default: False).
+///
+/// Returns:
+///     list[Transaction]: The signed transactions, in the same order.
+///
+/// Raises:
+///     Exception: If signing or verification of any transaction fails.
+#[gen_stub_pyfunction]
+#[pyfunction(name = "sign_transactions")]
+#[pyo3(signature = (txs, signer, verify_sig=false))]
+pub fn py_sign_transactions<'py>(
+    txs: Vec<PyTransaction>,
+    signer: Bound<'py, PyList>,
+    verify_sig: bool,
+) -> PyResult<Vec<PyTransaction>> {
+    let mut private_keys = extract_private_keys(&signer)?;
+
+    let transactions: Vec<Transaction> = txs.into_iter().map(Transaction::from).collect();
+    let result = signer.py().detach(|| {
+        let result = transactions
+            .iter()
+            .enumerate()
+            .map(|(index, transaction)| {
+                sign_transaction(transaction, &private_keys, verify_sig)
+                    .map(|tx| tx.clone())
+                    .map_err(|err| format!("Unable to sign transaction {index}: {err:?}"))
+            })
+            .collect::<std::result::Result<Vec<Transaction>, String>>();
+        private_keys.zeroize();
+        result
+    });
+    Ok(result
+        .map_err(PyException::new_err)?
+        .into_iter()
+        .map(PyTransaction::from)
+        .collect())
 }
 
 /// Create a signature for a specific transaction input.
@@ -115,6 +170,111 @@ pub fn py_sign_script_hash(script_hash: String, privkey: &PyPrivateKey) -> PyRes
     Ok(result.to_hex())
 }
 
+/// Convert a signature into its compact 64-byte Schnorr form.
+///
+/// Accepts either the script-encoded signature produced by
+/// `create_input_signature` / `sign_script_hash` (66 bytes: a data push
+/// opcode, the 64-byte signature and the sighash type byte) or a 65-byte
+/// signature with a trailing sighash type byte. Kaspa uses Schnorr
+/// signatures, which have no DER encoding; the compact form is the raw
+/// 64-byte signature accepted by most secp256k1 libraries.
+///
+/// Args:
+///     signature: The signature as hex string, bytes, or list of ints.
+///
+/// Returns:
+///     str: The 64-byte compact signature as a hex string.
+///
+/// Raises:
+///     ValueError: If the signature is malformed.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "signature_to_compact")]
+pub fn py_signature_to_compact(signature: PyBinary) -> PyResult<String> {
+    let bytes = signature.data.as_slice();
+    let (compact, sighash) = match bytes.len() {
+        66 if bytes[0] == SCHNORR_SIGNATURE_PUSH => (&bytes[1..65], bytes[65]),
+        66 => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid signature push opcode: 0x{:02x}",
+                bytes[0]
+            )));
+        }
+        65 => (&bytes[..64], bytes[64]),
+        len => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid signature length: {len} (expected 65 or 66 bytes)"
+            )));
+        }
+    };
+    check_sighash_type(sighash)?;
+    check_compact_signature(compact)?;
+    Ok(compact.to_hex())
+}
+
+/// Encode a compact 64-byte Schnorr signature for use in a signature script.
+///
+/// The signature is validated, appended with the sighash type byte, and
+/// prefixed with its data push opcode, producing the same encoding as
+/// `create_input_signature`.
+///
+/// Args:
+///     signature: The 64-byte compact signature as hex string, bytes, or list of ints.
+///     sighash_type: The signature hash type (default: All).
+///
+/// Returns:
+///     str: The script-encoded signature as a hex string.
+///
+/// Raises:
+///     ValueError: If the signature is malformed.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "signature_from_compact")]
+#[pyo3(signature = (signature, sighash_type=None))]
+pub fn py_signature_from_compact(
+    signature: PyBinary,
+    #[gen_stub(override_type(type_repr = "str | SighashType | None = SighashType.All"))]
+    sighash_type: Option<PySighashType>,
+) -> PyResult<String> {
+    check_compact_signature(&signature.data)?;
+    let sighash_type: SighashType = sighash_type.unwrap_or(PySighashType::All).into();
+    let sighash_type: SigHashType = sighash_type.into();
+    let encoded: Vec<u8> = std::iter::once(SCHNORR_SIGNATURE_PUSH)
+        .chain(signature.data)
+        .chain([sighash_type.to_u8()])
+        .collect();
+    Ok(encoded.to_hex())
+}
+
+// OP_DATA_65: pushes a 64-byte Schnorr signature plus its sighash type byte.
+const SCHNORR_SIGNATURE_PUSH: u8 = 65;
+
+fn check_compact_signature(signature: &[u8]) -> PyResult<()> {
+    secp256k1::schnorr::Signature::from_slice(signature)
+        .map(|_| ())
+        .map_err(|_| {
+            PyValueError::new_err(format!(
+                "Invalid compact signature length: {} (expected 64 bytes)",
+                signature.len()
+            ))
+        })
+}
+
+fn check_sighash_type(sighash: u8) -> PyResult<()> {
+    SigHashType::from_u8(sighash)
+        .map(|_| ())
+        .map_err(|_| PyValueError::new_err(format!("Invalid sighash type: 0x{sighash:02x}")))
+}
+
+fn extract_private_keys(signer: &Bound<'_, PyList>) -> PyResult<Vec<[u8; 32]>> {
+    let mut private_keys: Vec<[u8; 32]> = Vec::with_capacity(signer.len());
+    for item in signer.iter() {
+        let key: PyRef<'_, PyPrivateKey> = item.extract()?;
+        private_keys.push(key.secret_bytes());
+    }
+    Ok(private_keys)
+}
+
 fn sign_transaction<'a>(
     tx: &'a Transaction,
     private_keys: &[[u8; 32]],
@@ -0,0 +1,146 @@
+use crate::consensus::client::input::input_standard_violation;
+use crate::consensus::client::transaction::PyTransaction;
+use crate::consensus::core::network::PyNetworkId;
+
+use super::super::imports::*;
+use super::mass::unsigned_transaction_mass;
+use kaspa_consensus_core::constants::{MAX_SCRIPT_PUBLIC_KEY_VERSION, TX_VERSION};
+use kaspa_consensus_core::tx::ScriptPublicKey;
+use kaspa_txscript::script_class::ScriptClass;
+use kaspa_wallet_core::tx::MAXIMUM_STANDARD_TRANSACTION_MASS;
+use kaspa_wallet_core::tx::mass::MINIMUM_RELAY_TRANSACTION_FEE;
+use pyo3::exceptions::PyValueError;
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+
+pyo3_stub_gen::create_exception!(
+    kaspa,
+    NonStandardTransactionError,
+    PyValueError,
+    "Raised when a transaction violates a mempool standardness rule. The `rule` attribute names the violated rule."
+);
+
+// Build a `NonStandardTransactionError` carrying the violated rule as an attribute.
+fn non_standard_error(py: Python<'_>, rule: &str, message: String) -> PyErr {
+    let err = NonStandardTransactionError::new_err(format!(
+        "Non-standard transaction ({rule}): {message}"
+    ));
+    // Setting an attribute on a fresh exception instance doesn't fail
+    let _ = err.value(py).setattr("rule", rule);
+    err
+}
+
+/// Check a transaction against the mempool standardness rules.
+///
+/// A preflight for "will a node accept this?". The rules are checked in the
+/// order below and the first violation is raised:
+///
+/// - 'version': the transaction version is above the standard version.
+/// - 'signature_script_size': an input signature script is too large.
+/// - 'sig_op_count': an input spending a P2SH output declares more signature
+///   operations than the standard P2SH limit.
+/// - 'script_version': an output script version is above the standard version.
+/// - 'script_class': an output script is not a standard script.
+/// - 'dust': an output is too small to be worth spending at the minimum relay fee.
+/// - 'mass': the mass, estimated with one signature per input, exceeds the
+///   maximum standard transaction mass.
+///
+/// Fees and the validity of the spent UTXOs are not checked.
+///
+/// Args:
+///     tx: The transaction to check. All inputs must have their UTXO entries attached.
+///     network: Network whose consensus parameters are used to compute the mass.
+///
+/// Raises:
+///     NonStandardTransactionError: If the transaction violates a rule. The
+///         `rule` attribute holds the rule name listed above.
+///     ValueError: If the transaction has no inputs or no outputs.
+///     Exception: If mass calculation fails.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "check_transaction_standard")]
+pub fn py_check_transaction_standard(
+    py: Python<'_>,
+    tx: PyTransaction,
+    network: PyNetworkId,
+) -> PyResult<()> {
+    let tx: kaspa_consensus_client::Transaction = tx.into();
+
+    {
+        let inner = tx.inner();
+
+        if inner.version > TX_VERSION {
+            return Err(non_standard_error(
+                py,
+                "version",
+                format!(
+                    "Version {} is above the standard version {TX_VERSION}",
+                    inner.version
+                ),
+            ));
+        }
+
+        for (index, input) in inner.inputs.iter().enumerate() {
+            if let Some((rule, message)) = input_standard_violation(input) {
+                return Err(non_standard_error(
+                    py,
+                    rule,
+                    format!("Input {index}: {message}"),
+                ));
+            }
+        }
+
+        for (index, output) in inner.outputs.iter().enumerate() {
+            let output = output.inner();
+            let script_public_key = &output.script_public_key;
+            if script_public_key.version() > MAX_SCRIPT_PUBLIC_KEY_VERSION {
+                return Err(non_standard_error(
+                    py,
+                    "script_version",
+                    format!(
+                        "Output {index}: script version {} is above the standard version {MAX_SCRIPT_PUBLIC_KEY_VERSION}",
+                        script_public_key.version()
+                    ),
+                ));
+            }
+            if matches!(
+                ScriptClass::from_script(script_public_key),
+                ScriptClass::NonStandard
+            ) {
+                return Err(non_standard_error(
+                    py,
+                    "script_class",
+                    format!("Output {index}: script is not a standard script"),
+                ));
+            }
+            if is_dust(output.value, script_public_key) {
+                return Err(non_standard_error(
+                    py,
+                    "dust",
+                    format!("Output {index}: value {} sompi is dust", output.value),
+                ));
+            }
+        }
+    }
+
+    let (mass, _) = unsigned_transaction_mass(py, network, &tx, None)?;
+    if mass > MAXIMUM_STANDARD_TRANSACTION_MASS {
+        return Err(non_standard_error(
+            py,
+            "mass",
+            format!(
+                "Mass {mass} exceeds the maximum standard transaction mass {MAXIMUM_STANDARD_TRANSACTION_MASS}"
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+// Mirrors the mempool dust rule: an output is dust if spending it would cost
+// more than a third of its value at the minimum relay fee.
+fn is_dust(value: u64, script_public_key: &ScriptPublicKey) -> bool {
+    // Value (8), script version (2), script length (8) and script, plus the
+    // 148 bytes of a typical input spending the output
+    let serialized_size = 8 + 2 + 8 + script_public_key.script().len() as u64 + 148;
+    value.saturating_mul(1000) / (3 * serialized_size) < MINIMUM_RELAY_TRANSACTION_FEE
+}
@@ -1,16 +1,36 @@
 use crate::consensus::client::transaction::PyTransaction;
+use crate::consensus::client::utxo::PyUtxoEntryReference;
 use crate::consensus::core::network::PyNetworkId;
+use crate::consensus::core::script_public_key::PyScriptPublicKey;
+use crate::rpc::wrpc::client::PyRpcClient;
+use crate::wallet::keys::privatekey::PyPrivateKey;
 
 use super::super::imports::*;
 use super::generator::{
-    PendingTransaction, PyGenerator, PyGeneratorSummary, PyOutputs, PyUtxoEntries,
+    PyGenerator, PyGeneratorSummary, PyOutputs, PyPendingTransactionBatch, PyUtxoEntries,
+    insufficient_funds_error,
 };
+use super::mass::py_fee_for_mass;
+use kaspa_addresses::Version;
 use kaspa_consensus_client::*;
+use kaspa_consensus_core::config::params::Params;
+use kaspa_consensus_core::network::NetworkType;
 use kaspa_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
-use kaspa_wallet_core::result::Result;
+use kaspa_consensus_core::tx::ScriptPublicKey;
+use kaspa_rpc_core::api::rpc::RpcApi;
+use kaspa_rpc_core::model::{GetVirtualChainFromBlockRequest, RpcHash};
+use kaspa_wallet_core::tx::generator as native;
+use kaspa_wallet_core::tx::mass::MassCalculator;
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use std::time::{Duration, Instant};
+use workflow_core::task::sleep;
+use zeroize::Zeroizing;
 // use pyo3::{exceptions::PyException, prelude::*};
 
+// How often `wait_for_acceptance` polls the virtual chain.
+const ACCEPTANCE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Create a single transaction from UTXOs.
 ///
 /// Args:
@@ -19,22 +39,29 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 ///     priority_fee: Priority fee in sompi.
 ///     payload: Optional transaction payload data.
 ///     sig_op_count: Signature operations per input (default: 1).
+///     network_id: Network whose consensus parameters are used to compute
+///         the mass. The mass is left unset when omitted.
+///     auto_mass: Compute and set the transaction mass when `network_id` is
+///         given, assuming `sig_op_count` signatures per input (default: True).
 ///
 /// Returns:
 ///     Transaction: The created transaction (unsigned).
 ///
 /// Raises:
-///     Exception: If transaction creation fails or fee exceeds input amount.
+///     Exception: If transaction creation fails, fee exceeds input amount,
+///         or mass calculation fails.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "create_transaction")]
-#[pyo3(signature = (utxo_entry_source, outputs, priority_fee, payload=None, sig_op_count=None))]
+#[pyo3(signature = (utxo_entry_source, outputs, priority_fee, payload=None, sig_op_count=None, network_id=None, auto_mass=true))]
 pub fn py_create_transaction(
     utxo_entry_source: PyUtxoEntries,
     outputs: PyOutputs,
     priority_fee: u64,
     payload: Option<PyBinary>,
     sig_op_count: Option<u8>,
+    network_id: Option<PyNetworkId>,
+    auto_mass: bool,
 ) -> PyResult<PyTransaction> {
     let payload: Vec<u8> = payload.map(Into::into).unwrap_or_default();
     let sig_op_count = sig_op_count.unwrap_or(1);
@@ -85,12 +112,23 @@ pub fn py_create_transaction(
     )
     .map_err(|err| PyException::new_err(err.to_string()))?;
 
+    if let Some(network_id) = network_id.filter(|_| auto_mass) {
+        let network_id: NetworkId = network_id.into();
+        let consensus_params = Params::from(network_id);
+        let mass = MassCalculator::new(&consensus_params)
+            .calc_overall_mass_for_unsigned_client_transaction(&transaction, sig_op_count as u16)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        transaction.set_mass(mass);
+    }
+
     Ok(transaction.into())
 }
 
 /// Create one or more transactions with automatic UTXO selection and change handling.
 ///
 /// Handles large transfers that may require multiple transactions due to mass limits.
+/// Transactions are returned in submission order: intermediate (batch)
+/// transactions precede the final transaction that spends their outputs.
 ///
 /// Args:
 ///     network_id: The network to build transactions for.
@@ -99,20 +137,28 @@ pub fn py_create_transaction(
 ///     outputs: Optional list of payment outputs.
 ///     payload: Optional transaction payload data.
 ///     fee_rate: Optional fee rate multiplier.
-///     priority_fee: Additional fee in sompi.
+///     priority_fee: Additional fee in sompi added to the final transaction on top
+///         of the network fee. Paid by the sender (deducted from change).
 ///     priority_entries: UTXOs to use first.
 ///     sig_op_count: Signature operations per input (default: 1).
 ///     minimum_signatures: For multisig fee estimation.
+///     no_change: Send all remaining funds (minus fees) to the single payment
+///         output instead of creating a change output (default: False).
+///     current_daa_score: If set, coinbase UTXOs that are not yet mature at this
+///         DAA score are skipped.
 ///
 /// Returns:
-///     dict: Dictionary with "transactions" (list) and "summary" keys.
+///     dict: Dictionary with "transactions" (PendingTransactionBatch) and "summary" keys.
 ///
 /// Raises:
+///     InsufficientFundsError: If the UTXOs cannot cover the outputs plus fees.
 ///     Exception: If transaction creation fails.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "create_transactions")]
-#[pyo3(signature = (network_id, entries, change_address, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None))]
+#[pyo3(signature = (network_id, entries, change_address, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None, no_change=None, current_daa_score=None))]
+// Mirrors the Generator's keyword arguments, which Python callers pass by name
+#[allow(clippy::too_many_arguments)]
 pub fn py_create_transactions<'a>(
     py: Python<'a>,
     network_id: PyNetworkId,
@@ -125,11 +171,13 @@ pub fn py_create_transactions<'a>(
     priority_entries: Option<PyUtxoEntries>,
     sig_op_count: Option<u8>,
     minimum_signatures: Option<u16>,
+    no_change: Option<bool>,
+    current_daa_score: Option<u64>,
 ) -> PyResult<Bound<'a, PyDict>> {
     let generator = PyGenerator::ctor(
         network_id,
         entries,
-        change_address,
+        Some(change_address),
         outputs,
         payload,
         fee_rate,
@@ -137,13 +185,12 @@ pub fn py_create_transactions<'a>(
         priority_entries,
         sig_op_count,
         minimum_signatures,
+        no_change,
+        current_daa_score,
+        None,
     )?;
 
-    let transactions = generator
-        .iter()
-        .map(|r| r.map(PendingTransaction::from))
-        .collect::<Result<Vec<_>>>()
-        .map_err(|err| PyException::new_err(err.to_string()))?;
+    let transactions: PyPendingTransactionBatch = generator.try_collect()?.into();
     let summary = generator.summary();
     let dict = PyDict::new(py);
     dict.set_item("transactions", transactions)?;
@@ -160,20 +207,26 @@ pub fn py_create_transactions<'a>(
 ///     outputs: Optional list of payment outputs.
 ///     payload: Optional transaction payload data.
 ///     fee_rate: Optional fee rate multiplier.
-///     priority_fee: Additional fee in sompi.
+///     priority_fee: Additional fee in sompi added to the final transaction on top
+///         of the network fee. Paid by the sender (deducted from change).
 ///     priority_entries: UTXOs to use first.
 ///     sig_op_count: Signature operations per input (default: 1).
 ///     minimum_signatures: For multisig fee estimation.
+///     no_change: Send all remaining funds (minus fees) to the single payment
+///         output instead of creating a change output (default: False).
+///     current_daa_score: If set, coinbase UTXOs that are not yet mature at this
+///         DAA score are skipped.
 ///
 /// Returns:
 ///     GeneratorSummary: Summary with fee, transaction count, and other details.
 ///
 /// Raises:
+///     InsufficientFundsError: If the UTXOs cannot cover the outputs plus fees.
 ///     Exception: If estimation fails.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "estimate_transactions")]
-#[pyo3(signature = (network_id, entries, change_address, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None))]
+#[pyo3(signature = (network_id, entries, change_address, outputs=None, payload=None, fee_rate=None, priority_fee=None, priority_entries=None, sig_op_count=None, minimum_signatures=None, no_change=None, current_daa_score=None))]
 pub fn py_estimate_transactions(
     network_id: PyNetworkId,
     entries: PyUtxoEntries,
@@ -185,11 +238,13 @@ pub fn py_estimate_transactions(
     priority_entries: Option<PyUtxoEntries>,
     sig_op_count: Option<u8>,
     minimum_signatures: Option<u16>,
+    no_change: Option<bool>,
+    current_daa_score: Option<u64>,
 ) -> PyResult<PyGeneratorSummary> {
     let generator = PyGenerator::ctor(
         network_id,
         entries,
-        change_address,
+        Some(change_address),
         outputs,
         payload,
         fee_rate,
@@ -197,11 +252,544 @@ pub fn py_estimate_transactions(
         priority_entries,
         sig_op_count,
         minimum_signatures,
+        no_change,
+        current_daa_score,
+        None,
+    )?;
+
+    generator.try_collect()?;
+    Ok(generator.summary())
+}
+
+/// Send funds from the addresses of one or more private keys (async).
+///
+/// Fetches the UTXOs of the Schnorr addresses derived from `from_private_keys`,
+/// skipping immature coinbase outputs, builds the transactions with a
+/// Generator, signs them with the same keys and submits them in order. Pass
+/// `dry_run=True` to review the signed transactions before submitting them.
+///
+/// Args:
+///     rpc: A connected RPC client.
+///     from_private_keys: Private keys whose addresses fund the transfer.
+///     outputs: List of payment outputs.
+///     change_address: Address to send change to.
+///     network: The network to build transactions for.
+///     fee_rate: Optional fee rate multiplier.
+///     priority_fee: Additional fee in sompi added to the final transaction on top
+///         of the network fee (default: 0).
+///     dry_run: Build and sign the transactions without submitting them
+///         (default: False).
+///
+/// Returns:
+///     list[str]: The submitted transaction IDs, in submission order.
+///     dict: With `dry_run=True`, a dictionary with "transactions"
+///         (PendingTransactionBatch, signed) and "summary" (GeneratorSummary)
+///         keys. Submit the batch with `PendingTransactionBatch.submit_all()`.
+///
+/// Raises:
+///     InsufficientFundsError: If the UTXOs cannot cover the outputs plus fees.
+///     Exception: If fetching UTXOs, building, signing or submitting a
+///         transaction fails. Transactions before the failing one remain submitted.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "send")]
+#[pyo3(signature = (rpc, from_private_keys, outputs, change_address, network, fee_rate=None, priority_fee=0, dry_run=false))]
+pub fn py_send<'py>(
+    py: Python<'py>,
+    rpc: &PyRpcClient,
+    from_private_keys: Vec<PyRef<'py, PyPrivateKey>>,
+    outputs: PyOutputs,
+    change_address: PyAddress,
+    network: PyNetworkId,
+    fee_rate: Option<f64>,
+    priority_fee: u64,
+    dry_run: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let (addresses, keys) = send_addresses_and_keys(&from_private_keys, &network)?;
+    let rpc: Arc<DynRpcApi> = rpc.client().clone();
+
+    pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let (transactions, summary) = build_signed_transactions(
+            &rpc,
+            addresses,
+            keys,
+            outputs,
+            change_address,
+            network,
+            fee_rate,
+            priority_fee,
+        )
+        .await?;
+
+        if dry_run {
+            return Python::attach(|py| {
+                let dict = PyDict::new(py);
+                dict.set_item(
+                    "transactions",
+                    PyPendingTransactionBatch::from(transactions),
+                )?;
+                dict.set_item("summary", summary)?;
+                Ok(dict.into_any().unbind())
+            });
+        }
+
+        let mut ids = Vec::with_capacity(transactions.len());
+        for transaction in transactions.iter() {
+            let txid = transaction
+                .try_submit(&rpc)
+                .await
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+            ids.push(txid.to_string());
+        }
+        Python::attach(|py| Ok(ids.into_pyobject(py)?.into_any().unbind()))
+    })
+}
+
+// The funding addresses of `send()` and the keys that sign for them. The keys
+// are cleared on drop, including when an error returns early.
+fn send_addresses_and_keys(
+    from_private_keys: &[PyRef<'_, PyPrivateKey>],
+    network: &PyNetworkId,
+) -> PyResult<(Vec<Address>, Zeroizing<Vec<[u8; 32]>>)> {
+    let network_id: NetworkId = network.clone().into();
+
+    let mut addresses: Vec<Address> = Vec::with_capacity(from_private_keys.len());
+    let mut keys = Zeroizing::new(Vec::<[u8; 32]>::with_capacity(from_private_keys.len()));
+    for key in from_private_keys.iter() {
+        let public_key = key
+            .inner()
+            .to_public_key()
+            .map_err(|_| PyException::new_err("Failed to derive public key"))?;
+        let (x_only_public_key, _) = public_key
+            .public_key
+            .ok_or_else(|| PyException::new_err("Failed to derive public key"))?
+            .x_only_public_key();
+        let address = Address::new(
+            network_id.into(),
+            Version::PubKey,
+            &x_only_public_key.serialize(),
+        );
+        if !addresses.contains(&address) {
+            addresses.push(address);
+        }
+        keys.push(key.secret_bytes());
+    }
+    Ok((addresses, keys))
+}
+
+// Fetch the UTXOs of `addresses`, then generate and sign the transactions.
+// The keys are dropped, and so cleared, as soon as signing is done.
+async fn build_signed_transactions(
+    rpc: &Arc<DynRpcApi>,
+    addresses: Vec<Address>,
+    keys: Zeroizing<Vec<[u8; 32]>>,
+    outputs: PyOutputs,
+    change_address: PyAddress,
+    network: PyNetworkId,
+    fee_rate: Option<f64>,
+    priority_fee: u64,
+) -> PyResult<(Vec<native::PendingTransaction>, PyGeneratorSummary)> {
+    let entries = rpc
+        .get_utxos_by_addresses(addresses)
+        .await
+        .map_err(|err| PyException::new_err(err.to_string()))?
+        .into_iter()
+        .map(UtxoEntryReference::from)
+        .collect();
+    let current_daa_score = rpc
+        .get_block_dag_info()
+        .await
+        .map_err(|err| PyException::new_err(err.to_string()))?
+        .virtual_daa_score;
+
+    let generator = PyGenerator::ctor(
+        network,
+        PyUtxoEntries { entries },
+        Some(change_address),
+        Some(outputs),
+        None,
+        fee_rate,
+        Some(priority_fee),
+        None,
+        None,
+        None,
+        None,
+        Some(current_daa_score),
+        None,
     )?;
 
-    generator
+    let transactions = generator.try_collect()?;
+
+    let signed = transactions
+        .iter()
+        .try_for_each(|transaction| transaction.try_sign_with_keys(&keys, Some(true)));
+    drop(keys);
+    signed.map_err(|err| PyException::new_err(err.to_string()))?;
+
+    Ok((transactions, generator.summary()))
+}
+
+/// Wait until a submitted transaction is accepted by the virtual chain (async).
+///
+/// Polls the virtual chain from `start_hash` until a chain block accepts the
+/// transaction or `timeout` elapses. Pass the sink captured before submitting
+/// (`(await rpc.get_sink())["sink"]`) so an acceptance that happens before this
+/// call is still seen. Without it, polling starts from the current sink and an
+/// earlier acceptance is missed. Only blocks on the current selected chain
+/// count, so an acceptance by a block removed in a reorg is not reported.
+///
+/// Args:
+///     rpc: A connected RPC client.
+///     transaction_id: The ID of the submitted transaction.
+///     timeout: Maximum time to wait in milliseconds.
+///     start_hash: Chain block to start polling from (default: the current sink).
+///
+/// Returns:
+///     bool: True if the transaction was accepted, False if `timeout` elapsed first.
+///
+/// Raises:
+///     ValueError: If `transaction_id` or `start_hash` is not a valid hash.
+///     Exception: If an RPC call fails.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "wait_for_acceptance")]
+#[pyo3(signature = (rpc, transaction_id, timeout, start_hash=None))]
+pub fn py_wait_for_acceptance<'py>(
+    py: Python<'py>,
+    rpc: &PyRpcClient,
+    transaction_id: &str,
+    timeout: u64,
+    start_hash: Option<&str>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let transaction_id = RpcHash::from_str(transaction_id)
+        .map_err(|_| PyValueError::new_err(format!("Invalid transaction id `{transaction_id}`")))?;
+    let start_hash = start_hash
+        .map(|hash| {
+            RpcHash::from_str(hash)
+                .map_err(|_| PyValueError::new_err(format!("Invalid start hash `{hash}`")))
+        })
+        .transpose()?;
+    let rpc: Arc<DynRpcApi> = rpc.client().clone();
+
+    pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let deadline = Instant::now() + Duration::from_millis(timeout);
+        let mut start_hash = match start_hash {
+            Some(hash) => hash,
+            None => {
+                rpc.get_sink()
+                    .await
+                    .map_err(|err| PyException::new_err(err.to_string()))?
+                    .sink
+            }
+        };
+
+        loop {
+            let request = GetVirtualChainFromBlockRequest {
+                start_hash,
+                include_accepted_transaction_ids: true,
+                min_confirmation_count: None,
+            };
+            let response = rpc
+                .get_virtual_chain_from_block_call(None, request)
+                .await
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+
+            // Accepted ids are only reported for blocks added to the selected
+            // chain. If a reorg removes blocks scanned by an earlier poll, the
+            // node reports them as removed and re-adds the chain from the common
+            // ancestor, so acceptance is only ever judged against the current chain.
+            let accepted = response
+                .accepted_transaction_ids
+                .iter()
+                .any(|block| block.accepted_transaction_ids.contains(&transaction_id));
+            if accepted {
+                return Ok(true);
+            }
+
+            // Only fetch the new part of the chain on the next poll
+            if let Some(last) = response.added_chain_block_hashes.last() {
+                start_hash = *last;
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(false);
+            }
+            sleep(ACCEPTANCE_POLL_INTERVAL.min(deadline - now)).await;
+        }
+    })
+}
+
+/// Raise the fee of a transaction by taking it out of its change output.
+///
+/// Returns a copy of `tx` with the output at `change_output_index` reduced by
+/// `additional_fee` and all signature scripts cleared, ready to be signed again
+/// and submitted with `RpcClient.submit_transaction_replacement()`. The
+/// original transaction is left unchanged.
+///
+/// Args:
+///     tx: The transaction to replace.
+///     additional_fee: Amount in sompi to add to the fee.
+///     change_output_index: Index of the change output that pays for the bump.
+///
+/// Returns:
+///     Transaction: The unsigned replacement transaction.
+///
+/// Raises:
+///     IndexError: If `change_output_index` is out of range.
+///     ValueError: If the change output cannot cover `additional_fee`.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "bump_fee")]
+pub fn py_bump_fee(
+    tx: PyTransaction,
+    additional_fee: u64,
+    change_output_index: usize,
+) -> PyResult<PyTransaction> {
+    let inner = tx.inner().inner();
+
+    let change = inner
+        .outputs
+        .get(change_output_index)
+        .ok_or_else(|| PyIndexError::new_err("change output index out of range"))?
+        .inner()
+        .value;
+    if additional_fee >= change {
+        return Err(PyValueError::new_err(format!(
+            "additional fee ({additional_fee}) must be less than the change output value ({change})"
+        )));
+    }
+
+    let inputs = inner
+        .inputs
+        .iter()
+        .map(|input| {
+            let input = input.inner();
+            TransactionInput::new(
+                input.previous_outpoint.clone(),
+                None,
+                input.sequence,
+                input.sig_op_count,
+                input.utxo.clone(),
+            )
+        })
+        .collect();
+
+    let outputs = inner
+        .outputs
         .iter()
-        .collect::<Result<Vec<_>>>()
+        .enumerate()
+        .map(|(index, output)| {
+            let output = output.inner();
+            let value = if index == change_output_index {
+                output.value - additional_fee
+            } else {
+                output.value
+            };
+            TransactionOutput::new(value, output.script_public_key.clone())
+        })
+        .collect();
+
+    let transaction = Transaction::new(
+        None,
+        inner.version,
+        inputs,
+        outputs,
+        inner.lock_time,
+        inner.subnetwork_id.clone(),
+        inner.gas,
+        inner.payload.clone(),
+        inner.mass,
+    )
+    .map_err(|err| PyException::new_err(err.to_string()))?;
+
+    Ok(transaction.into())
+}
+
+/// Attach UTXO entries to the inputs of a transaction by outpoint.
+///
+/// Each input receives the entry whose outpoint matches its previous outpoint,
+/// replacing any UTXO already attached. Use this before signing a transaction
+/// reconstructed from storage without UTXO data. Entries that no input spends
+/// are ignored.
+///
+/// Args:
+///     tx: The transaction to update in place.
+///     utxos: UTXO entries spent by the transaction inputs.
+///
+/// Raises:
+///     ValueError: If no entry matches the previous outpoint of an input. The
+///         transaction is left unchanged in that case.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "attach_utxos")]
+pub fn py_attach_utxos(tx: PyTransaction, utxos: Vec<PyUtxoEntryReference>) -> PyResult<()> {
+    let utxos: Vec<UtxoEntryReference> = utxos.into_iter().map(Into::into).collect();
+    let inner = tx.inner().inner();
+
+    // Match every input before attaching, so a failure leaves `tx` untouched
+    let mut matched = Vec::with_capacity(inner.inputs.len());
+    for (index, input) in inner.inputs.iter().enumerate() {
+        let input = input.inner();
+        let utxo = utxos
+            .iter()
+            .find(|utxo| utxo.utxo.outpoint == input.previous_outpoint)
+            .ok_or_else(|| {
+                let outpoint = input.previous_outpoint.inner();
+                PyValueError::new_err(format!(
+                    "No UTXO supplied for input {index} spending {}:{}",
+                    outpoint.transaction_id, outpoint.index
+                ))
+            })?;
+        matched.push(utxo.clone());
+    }
+
+    for (input, utxo) in inner.inputs.iter().zip(matched) {
+        input.inner().utxo = Some(utxo);
+    }
+    Ok(())
+}
+
+/// Select UTXOs covering a target amount and compute the fee and change.
+///
+/// UTXOs are selected largest first until they cover `target` plus the fee
+/// for the resulting transaction: one input per selected UTXO, one output
+/// for `target` and, if worthwhile, one change output paying to
+/// `change_script`. If a change output would cost more in fees (including
+/// storage mass) than it returns, it is omitted and the remainder is added
+/// to the fee. The target output is assumed to use a script of the same size
+/// as `change_script`, and each input to carry one signature.
+///
+/// Args:
+///     utxos: List of UTXO entries to select from.
+///     target: Amount to pay in sompi, excluding fees.
+///     fee_rate: Fee rate in sompi per gram.
+///     change_script: Locking script for the change output.
+///     network_id: Network whose consensus parameters are used to compute
+///         the mass (default: mainnet).
+///     current_daa_score: If set, coinbase UTXOs that are not yet mature at this
+///         DAA score are never selected.
+///
+/// Returns:
+///     dict: A dictionary with keys:
+///         - 'inputs' (list[UtxoEntryReference]): The selected UTXOs.
+///         - 'fee' (int): The fee in sompi.
+///         - 'change' (int): The change amount in sompi, 0 if there is no change output.
+///
+/// Raises:
+///     ValueError: If `fee_rate` is negative or not finite.
+///     InsufficientFundsError: If the UTXOs cannot cover the target amount and fee.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "select_coins")]
+#[pyo3(signature = (utxos, target, fee_rate, change_script, network_id=None, current_daa_score=None))]
+pub fn py_select_coins<'py>(
+    py: Python<'py>,
+    mut utxos: PyUtxoEntries,
+    target: u64,
+    fee_rate: f64,
+    change_script: PyScriptPublicKey,
+    network_id: Option<PyNetworkId>,
+    current_daa_score: Option<u64>,
+) -> PyResult<Bound<'py, PyDict>> {
+    // Validate the fee rate up front, even if no UTXO is ever selected
+    py_fee_for_mass(0, fee_rate)?;
+
+    let network_id: NetworkId = network_id
+        .map(Into::into)
+        .unwrap_or_else(|| NetworkId::new(NetworkType::Mainnet));
+    if let Some(current_daa_score) = current_daa_score {
+        utxos.retain_mature_coinbase(current_daa_score, network_id);
+    }
+    let consensus_params = Params::from(network_id);
+    let calculator = MassCalculator::new(&consensus_params);
+    let change_script: ScriptPublicKey = change_script.into();
+
+    let fee_for = |selected: &[UtxoEntryReference], change: Option<u64>| -> PyResult<u64> {
+        let inputs = selected
+            .iter()
+            .map(|reference| {
+                TransactionInput::new(
+                    reference.utxo.outpoint.clone(),
+                    None,
+                    0,
+                    1,
+                    Some(reference.clone()),
+                )
+            })
+            .collect::<Vec<_>>();
+        let outputs = std::iter::once(target)
+            .chain(change)
+            .map(|value| TransactionOutput::new(value, change_script.clone()))
+            .collect::<Vec<_>>();
+        let transaction = Transaction::new(
+            None,
+            0,
+            inputs,
+            outputs,
+            0,
+            SUBNETWORK_ID_NATIVE,
+            0,
+            vec![],
+            0,
+        )
         .map_err(|err| PyException::new_err(err.to_string()))?;
-    Ok(generator.summary())
+        let mass = calculator
+            .calc_overall_mass_for_unsigned_client_transaction(&transaction, 1)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        py_fee_for_mass(mass, fee_rate)
+    };
+
+    let mut entries = utxos.entries;
+    entries.sort_by_key(|reference| std::cmp::Reverse(reference.amount()));
+
+    let mut selected = Vec::new();
+    let mut total: u64 = 0;
+    for reference in entries {
+        total += reference.amount();
+        selected.push(reference);
+
+        if total < target.saturating_add(fee_for(&selected, None)?) {
+            continue;
+        }
+
+        let surplus = total - target;
+        let (fee, change) = select_change(surplus, |change| fee_for(&selected, Some(change)))
+            .unwrap_or((surplus, 0));
+
+        let inputs = selected
+            .into_iter()
+            .map(PyUtxoEntryReference::from)
+            .collect::<Vec<_>>();
+        let dict = PyDict::new(py);
+        dict.set_item("inputs", inputs)?;
+        dict.set_item("fee", fee)?;
+        dict.set_item("change", change)?;
+        return Ok(dict);
+    }
+
+    // Nothing left to select, so the requirement is priced with every UTXO as input
+    let required = target.saturating_add(fee_for(&selected, None)?);
+    Err(insufficient_funds_error(total, required))
+}
+
+// Returns the fee and change amount if a change output can pay for itself out
+// of `surplus`. A smaller change output has a higher storage mass, so the change
+// estimate is lowered until the fee it leaves behind covers its own mass.
+fn select_change(
+    surplus: u64,
+    fee_with_change: impl Fn(u64) -> PyResult<u64>,
+) -> Option<(u64, u64)> {
+    let mut change = surplus;
+    for _ in 0..4 {
+        change = surplus.checked_sub(fee_with_change(change).ok()?)?;
+        if change == 0 {
+            return None;
+        }
+        let fee = surplus - change;
+        if fee >= fee_with_change(change).ok()? {
+            return Some((fee, change));
+        }
+    }
+    None
 }
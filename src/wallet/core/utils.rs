@@ -1,8 +1,35 @@
-use pyo3::prelude::*;
+use kaspa_consensus_core::constants::{MAX_SOMPI, SOMPI_PER_KASPA};
+use pyo3::{exceptions::PyValueError, prelude::*};
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
 
 use crate::consensus::core::network::PyNetworkType;
 
+/// Number of decimal places in a KAS amount (1 KAS = 10^8 sompi).
+const KASPA_DECIMALS: usize = 8;
+
+/// Format a sompi amount as an exact KAS decimal string, without going through `f64`.
+///
+/// Trailing zeros of the fractional part are trimmed (`150000000` -> `"1.5"`,
+/// `1` -> `"0.00000001"`, `100000000` -> `"1"`).
+pub fn sompi_to_kaspa_decimal_string(sompi: u64) -> String {
+    format_sompi(sompi, 0, KASPA_DECIMALS)
+}
+
+// Format sompi as KAS keeping between `min_decimals` and `max_decimals` fractional
+// digits. Digits beyond `max_decimals` are truncated. Callers ensure
+// `min_decimals <= max_decimals <= KASPA_DECIMALS`.
+fn format_sompi(sompi: u64, min_decimals: usize, max_decimals: usize) -> String {
+    let whole = sompi / SOMPI_PER_KASPA;
+    let fraction = format!("{:08}", sompi % SOMPI_PER_KASPA);
+    let fraction = fraction[..max_decimals].trim_end_matches('0');
+    let fraction = format!("{fraction:0<min_decimals$}");
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{fraction}")
+    }
+}
+
 /// Convert KAS to sompi (1 KAS = 100,000,000 sompi).
 ///
 /// Args:
@@ -10,11 +37,30 @@ use crate::consensus::core::network::PyNetworkType;
 ///
 /// Returns:
 ///     int: The amount in sompi.
+///
+/// Raises:
+///     ValueError: If the amount is NaN, infinite, negative, or exceeds the maximum supply.
 #[gen_stub_pyfunction]
 #[pyfunction]
 #[pyo3(name = "kaspa_to_sompi")]
-pub fn py_kaspa_to_sompi(kaspa: f64) -> u64 {
-    kaspa_wallet_core::utils::kaspa_to_sompi(kaspa)
+pub fn py_kaspa_to_sompi(kaspa: f64) -> PyResult<u64> {
+    if !kaspa.is_finite() {
+        return Err(PyValueError::new_err(format!(
+            "Invalid KAS amount `{kaspa}`: must be a finite number"
+        )));
+    }
+    if kaspa < 0.0 {
+        return Err(PyValueError::new_err(format!(
+            "Invalid KAS amount `{kaspa}`: must not be negative"
+        )));
+    }
+    if kaspa > (MAX_SOMPI / SOMPI_PER_KASPA) as f64 {
+        return Err(PyValueError::new_err(format!(
+            "Invalid KAS amount `{kaspa}`: exceeds the maximum supply of {} KAS",
+            MAX_SOMPI / SOMPI_PER_KASPA
+        )));
+    }
+    Ok(kaspa_wallet_core::utils::kaspa_to_sompi(kaspa))
 }
 
 /// Convert sompi to KAS (1 KAS = 100,000,000 sompi).
@@ -51,3 +97,56 @@ pub fn py_sompi_to_kaspa_string_with_suffix(
         &network.into(),
     ))
 }
+
+/// Convert sompi to a KAS string without a network suffix.
+///
+/// The conversion is exact (no floating point). Trailing zeros are trimmed.
+///
+/// Args:
+///     sompi: The amount in sompi.
+///
+/// Returns:
+///     str: Formatted string like "1.5".
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "sompi_to_kaspa_string")]
+pub fn py_sompi_to_kaspa_string(sompi: u64) -> String {
+    sompi_to_kaspa_decimal_string(sompi)
+}
+
+/// Convert sompi to a KAS string with controlled number of decimal places.
+///
+/// The conversion is exact (no floating point). Trailing zeros are trimmed
+/// down to `min_decimals`, and digits beyond `max_decimals` are truncated.
+///
+/// Args:
+///     sompi: The amount in sompi.
+///     min_decimals: Minimum number of decimal places (default: 0).
+///     max_decimals: Maximum number of decimal places (default: 8).
+///
+/// Returns:
+///     str: Formatted string like "1.50".
+///
+/// Raises:
+///     ValueError: If `min_decimals` > `max_decimals` or `max_decimals` > 8.
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(name = "sompi_to_kaspa_string_with_decimals")]
+#[pyo3(signature = (sompi, min_decimals=0, max_decimals=8))]
+pub fn py_sompi_to_kaspa_string_with_decimals(
+    sompi: u64,
+    min_decimals: usize,
+    max_decimals: usize,
+) -> PyResult<String> {
+    if max_decimals > KASPA_DECIMALS {
+        return Err(PyValueError::new_err(format!(
+            "max_decimals must be <= {KASPA_DECIMALS}"
+        )));
+    }
+    if min_decimals > max_decimals {
+        return Err(PyValueError::new_err(
+            "min_decimals must be <= max_decimals",
+        ));
+    }
+    Ok(format_sompi(sompi, min_decimals, max_decimals))
+}
@@ -1,3 +1,4 @@
+use crate::wallet::core::utils::sompi_to_kaspa_decimal_string;
 use kaspa_wallet_core::utxo::Balance;
 use kaspa_wallet_core::utxo::balance::BalanceStrings;
 use pyo3::prelude::*;
@@ -30,6 +31,24 @@ impl PyBalance {
         self.0.outgoing
     }
 
+    /// Total mature balance in KAS as an exact decimal string (e.g. "0.00000001").
+    #[getter]
+    pub fn get_mature_kas(&self) -> String {
+        sompi_to_kaspa_decimal_string(self.0.mature)
+    }
+
+    /// Total pending balance in KAS as an exact decimal string.
+    #[getter]
+    pub fn get_pending_kas(&self) -> String {
+        sompi_to_kaspa_decimal_string(self.0.pending)
+    }
+
+    /// Total outgoing balance in KAS as an exact decimal string.
+    #[getter]
+    pub fn get_outgoing_kas(&self) -> String {
+        sompi_to_kaspa_decimal_string(self.0.outgoing)
+    }
+
     /// Number of mature UTXOs.
     #[getter]
     pub fn get_mature_utxo_count(&self) -> usize {
@@ -4,12 +4,16 @@ use crate::crypto::hashes::PyHash;
 use crate::wallet::core::utxo::balance::{PyBalance, PyBalanceStrings};
 use crate::wallet::core::utxo::processor::PyUtxoProcessor;
 use futures::stream::StreamExt;
-use kaspa_addresses::Address;
+use kaspa_addresses::{Address, Prefix};
 use kaspa_hashes::Hash;
 use kaspa_wallet_core::utxo::balance::BalanceStrings;
 use kaspa_wallet_core::utxo::{UtxoContext, UtxoContextBinding, UtxoContextId, UtxoStream};
-use pyo3::{exceptions::PyException, prelude::*};
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use std::collections::HashMap;
 use std::str::FromStr;
 
 /// UTXO context for tracking addresses and balances.
@@ -22,6 +26,30 @@ impl PyUtxoContext {
     pub fn inner(&self) -> &UtxoContext {
         &self.0
     }
+
+    // Ensure every address matches the prefix of the processor's network.
+    fn validate_network(&self, addresses: &[Address]) -> PyResult<()> {
+        let Ok(network_id) = self.0.processor().network_id() else {
+            return Ok(());
+        };
+        let expected = Prefix::from(network_id.network_type);
+
+        let mismatched = addresses
+            .iter()
+            .filter(|address| address.prefix != expected)
+            .map(|address| address.to_string())
+            .collect::<Vec<_>>();
+
+        if mismatched.is_empty() {
+            Ok(())
+        } else {
+            Err(PyException::new_err(format!(
+                "Address network mismatch: processor network is `{}`, offending addresses: {}",
+                network_id,
+                mismatched.join(", ")
+            )))
+        }
+    }
 }
 
 #[gen_stub_pymethods]
@@ -60,6 +88,9 @@ impl PyUtxoContext {
     /// Args:
     ///     addresses: List of Address objects or address strings.
     ///     current_daa_score: Optional current DAA score for scan context.
+    ///
+    /// Raises:
+    ///     Exception: If any address does not belong to the processor's network.
     #[pyo3(signature = (addresses, current_daa_score=None))]
     fn track_addresses<'py>(
         &self,
@@ -69,6 +100,7 @@ impl PyUtxoContext {
         current_daa_score: Option<u64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let addresses = parse_addresses(addresses)?;
+        self.validate_network(&addresses)?;
         let context = self.0.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
@@ -156,6 +188,51 @@ impl PyUtxoContext {
             .collect())
     }
 
+    /// Iterate over the mature UTXO entries in pages.
+    ///
+    /// Each page is read from the context on demand, so at most `page_size`
+    /// entries are held at a time. Entries added or removed while iterating
+    /// may shift page boundaries.
+    ///
+    /// Args:
+    ///     page_size: Maximum number of entries per page.
+    ///
+    /// Returns:
+    ///     UtxoPageIterator: An iterator yielding lists of UtxoEntryReference.
+    ///
+    /// Raises:
+    ///     ValueError: If `page_size` is 0.
+    fn iter_mature(&self, page_size: usize) -> PyResult<PyUtxoPageIterator> {
+        if page_size == 0 {
+            return Err(PyValueError::new_err("`page_size` must be greater than 0"));
+        }
+        Ok(PyUtxoPageIterator {
+            stream: UtxoStream::new(&self.0),
+            page_size,
+        })
+    }
+
+    /// Group the mature UTXO entries by address.
+    ///
+    /// Entries without an associated address are omitted.
+    ///
+    /// Returns:
+    ///     dict[str, list[UtxoEntryReference]]: Mature entries keyed by address string.
+    fn utxos_by_address(&self) -> HashMap<String, Vec<PyUtxoEntryReference>> {
+        let entries = futures::executor::block_on(UtxoStream::new(&self.0).collect::<Vec<_>>());
+
+        let mut grouped: HashMap<String, Vec<PyUtxoEntryReference>> = HashMap::new();
+        for entry in entries {
+            if let Some(address) = entry.utxo.address.as_ref() {
+                grouped
+                    .entry(address.to_string())
+                    .or_default()
+                    .push(PyUtxoEntryReference::from(entry));
+            }
+        }
+        grouped
+    }
+
     /// Current balance for this context (if available).
     #[getter]
     fn get_balance(&self) -> Option<PyBalance> {
@@ -177,6 +254,49 @@ impl PyUtxoContext {
     }
 }
 
+/// Iterator over pages of mature UTXO entries, created by `UtxoContext.iter_mature()`.
+#[gen_stub_pyclass]
+#[pyclass(name = "UtxoPageIterator")]
+pub struct PyUtxoPageIterator {
+    // Keeps its cursor between pages, so each page resumes where the last ended.
+    stream: UtxoStream,
+    page_size: usize,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyUtxoPageIterator {
+    /// Return self as an iterator.
+    fn __iter__(slf: PyRefMut<Self>) -> PyResult<Py<Self>> {
+        Ok(slf.into())
+    }
+
+    /// Get the next page of mature UTXO entries, or None when exhausted.
+    ///
+    /// Returns:
+    ///     list[UtxoEntryReference] | None: Up to `page_size` entries.
+    fn __next__(mut slf: PyRefMut<Self>, py: Python<'_>) -> Option<Vec<PyUtxoEntryReference>> {
+        let this = &mut *slf;
+        let entries = py.detach(|| {
+            futures::executor::block_on(
+                this.stream
+                    .by_ref()
+                    .take(this.page_size)
+                    .collect::<Vec<_>>(),
+            )
+        });
+        if entries.is_empty() {
+            return None;
+        }
+        Some(
+            entries
+                .into_iter()
+                .map(PyUtxoEntryReference::from)
+                .collect(),
+        )
+    }
+}
+
 impl From<PyUtxoContext> for UtxoContext {
     fn from(value: PyUtxoContext) -> Self {
         value.0
@@ -5,9 +5,17 @@ use kaspa_wallet_core::utxo::{
     UtxoProcessor, set_coinbase_transaction_maturity_period_daa,
     set_user_transaction_maturity_period_daa,
 };
-use pyo3::{exceptions::PyException, prelude::*};
+use pyo3::{
+    exceptions::{PyException, PyTimeoutError},
+    prelude::*,
+};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use workflow_core::task::sleep;
+
+// Interval between sync state checks in `wait_for_sync`.
+const SYNC_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 /// UTXO processor coordinating address tracking and UTXO updates.
 #[gen_stub_pyclass]
@@ -67,6 +75,37 @@ impl PyUtxoProcessor {
         })
     }
 
+    /// Wait until the processor reports that the node is synced (async).
+    ///
+    /// Resolves once the initial sync is complete, after which balances
+    /// and UTXO sets read from bound contexts are reliable.
+    ///
+    /// Args:
+    ///     timeout: Optional maximum time to wait, in milliseconds.
+    ///
+    /// Raises:
+    ///     TimeoutError: If the processor is not synced before the timeout elapses.
+    #[pyo3(signature = (timeout=None))]
+    fn wait_for_sync<'py>(
+        &self,
+        py: Python<'py>,
+        timeout: Option<u64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let processor = self.processor.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let deadline = timeout.map(|ms| Instant::now() + Duration::from_millis(ms));
+            while !processor.is_synced() {
+                if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                    return Err(PyTimeoutError::new_err(
+                        "Timed out waiting for UtxoProcessor to sync",
+                    ));
+                }
+                sleep(SYNC_POLL_INTERVAL).await;
+            }
+            Ok(())
+        })
+    }
+
     /// The associated RPC client.
     #[getter]
     pub fn get_rpc(&self) -> PyRpcClient {
@@ -2,8 +2,13 @@ use super::privatekey::PyPrivateKey;
 use crate::{address::PyAddress, consensus::core::network::PyNetworkType};
 use kaspa_addresses::{Address, Version};
 use kaspa_consensus_core::network::NetworkType;
+use kaspa_wallet_core::message::{PersonalMessage, SignMessageOptions};
 use kaspa_wallet_keys::{privatekey::PrivateKey, publickey::PublicKey};
-use pyo3::{exceptions::PyException, prelude::*};
+use pyo3::{
+    exceptions::PyException,
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 use std::str::FromStr;
 use zeroize::Zeroize;
@@ -114,6 +119,70 @@ impl PyKeypair {
         Ok(address.into())
     }
 
+    /// Export all key forms and the Schnorr address in one call.
+    ///
+    /// The WIF uses the Bitcoin-style encoding of a compressed key
+    /// (version byte 0x80 on mainnet, 0xef on other networks).
+    ///
+    /// Args:
+    ///     network: The network type for address and WIF encoding.
+    ///
+    /// Returns:
+    ///     dict: A dictionary with keys:
+    ///         - 'private_key' (str): The private key as hex.
+    ///         - 'wif' (str): The private key in Wallet Import Format.
+    ///         - 'public_key' (str): The full public key as hex.
+    ///         - 'xonly_public_key' (str): The x-only public key as hex.
+    ///         - 'address' (str): The Schnorr address, same as `to_address()`.
+    pub fn export<'py>(
+        &self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network: PyNetworkType,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let network_type = NetworkType::from(network);
+        let dict = PyDict::new(py);
+        dict.set_item("private_key", self.get_private_key())?;
+        dict.set_item("wif", self.to_wif(network_type))?;
+        dict.set_item("public_key", self.get_public_key())?;
+        dict.set_item("xonly_public_key", self.get_xonly_public_key())?;
+        let address = Address::new(
+            network_type.into(),
+            Version::PubKey,
+            &self.xonly_public_key.serialize(),
+        );
+        dict.set_item("address", address.to_string())?;
+        Ok(dict)
+    }
+
+    /// Sign an arbitrary message with this keypair's private key.
+    ///
+    /// Args:
+    ///     message: The message string to sign.
+    ///     no_aux_rand: If True, use deterministic signing (default: False).
+    ///
+    /// Returns:
+    ///     bytes: The 64-byte signature.
+    ///
+    /// Raises:
+    ///     Exception: If signing fails.
+    #[pyo3(signature = (message, no_aux_rand=false))]
+    pub fn sign_message<'py>(
+        &self,
+        py: Python<'py>,
+        message: &str,
+        no_aux_rand: bool,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        let mut secret_bytes = self.secret_key.secret_bytes();
+        let signature = kaspa_wallet_core::message::sign_message(
+            &PersonalMessage(message),
+            &secret_bytes,
+            &SignMessageOptions { no_aux_rand },
+        );
+        secret_bytes.zeroize();
+        let signature = signature.map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(PyBytes::new(py, &signature))
+    }
+
     /// Generate a random keypair.
     ///
     /// Returns:
@@ -158,3 +227,19 @@ impl PyKeypair {
         })
     }
 }
+
+impl PyKeypair {
+    fn to_wif(&self, network_type: NetworkType) -> String {
+        let version = match network_type {
+            NetworkType::Mainnet => 0x80,
+            _ => 0xef,
+        };
+        let mut payload = Vec::with_capacity(34);
+        payload.push(version);
+        payload.extend_from_slice(&self.secret_key.secret_bytes());
+        payload.push(0x01);
+        let wif = bs58::encode(&payload).with_check().into_string();
+        payload.zeroize();
+        wif
+    }
+}
@@ -8,10 +8,15 @@ use crate::wallet::keys::{privatekey::PyPrivateKey, xprv::PyXPrv};
 
 /// Generator for deriving private keys from an extended private key.
 ///
-/// Used for creating wallets that can sign transactions.
+/// Used for creating wallets that can sign transactions. The hardened
+/// account path and the receive/change chain keys are derived once on
+/// construction, so each `receive_key` / `change_key` lookup only performs
+/// the final non-hardened child derivation. This keeps scanning large
+/// index ranges cheap.
 #[gen_stub_pyclass]
 #[pyclass(name = "PrivateKeyGenerator")]
 pub struct PyPrivateKeyGenerator {
+    // Cached chain-level keys (`m/<purpose>'/111111'/<account>'/<chain>`)
     receive: ExtendedPrivateKey<SecretKey>,
     change: ExtendedPrivateKey<SecretKey>,
 }
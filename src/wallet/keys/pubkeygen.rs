@@ -1,13 +1,19 @@
 use kaspa_addresses::Address;
+use kaspa_bip32::{ChildNumber, DerivationPath, ExtendedPublicKey};
 use kaspa_consensus_core::network::NetworkType;
 use kaspa_wallet_core::derivation::WalletDerivationManagerTrait;
 use kaspa_wallet_keys::publickey::PublicKey;
 use kaspa_wallet_keys::result::Result;
 use kaspa_wallet_keys::{derivation::gen1::WalletDerivationManager, xpub::XPub};
-use pyo3::{exceptions::PyException, prelude::*};
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use rayon::{ThreadPoolBuilder, prelude::*};
 
 use crate::consensus::core::network::PyNetworkType;
+use crate::wallet::keys::derivation::PyDerivationPath;
 use crate::wallet::keys::xprv::PyXPrv;
 use crate::{address::PyAddress, wallet::keys::publickey::PyPublicKey};
 
@@ -20,6 +26,13 @@ use crate::{address::PyAddress, wallet::keys::publickey::PyPublicKey};
 #[derive(Clone)]
 pub struct PyPublicKeyGenerator {
     hd_wallet: WalletDerivationManager,
+    xpub: ExtendedPublicKey<secp256k1::PublicKey>,
+}
+
+impl PyPublicKeyGenerator {
+    pub fn hd_wallet(&self) -> &WalletDerivationManager {
+        &self.hd_wallet
+    }
 }
 
 #[gen_stub_pymethods]
@@ -45,7 +58,10 @@ impl PyPublicKeyGenerator {
         let hd_wallet =
             WalletDerivationManager::from_extended_public_key(xpub.clone(), cosigner_index)
                 .map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(Self { hd_wallet })
+        Ok(Self {
+            hd_wallet,
+            xpub: xpub.clone(),
+        })
     }
 
     /// Create a generator from a master extended private key.
@@ -88,9 +104,10 @@ impl PyPublicKeyGenerator {
             .derive_path(&path)
             .map_err(|err| PyException::new_err(err.to_string()))?;
         let xpub = xprv.public_key();
-        let hd_wallet = WalletDerivationManager::from_extended_public_key(xpub, cosigner_index)
-            .map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(Self { hd_wallet })
+        let hd_wallet =
+            WalletDerivationManager::from_extended_public_key(xpub.clone(), cosigner_index)
+                .map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(Self { hd_wallet, xpub })
     }
 
     /// Derive a range of receive (external) public keys.
@@ -109,6 +126,7 @@ impl PyPublicKeyGenerator {
         if start > end {
             (start, end) = (end, start)
         }
+        check_range(start, end)?;
         let pubkeys = self
             .hd_wallet
             .receive_pubkey_manager()
@@ -132,6 +150,7 @@ impl PyPublicKeyGenerator {
     ///     Exception: If derivation fails.
     #[pyo3(name = "receive_pubkey")]
     pub fn receive_pubkey(&self, index: u32) -> PyResult<PyPublicKey> {
+        check_index(index)?;
         let inner = self
             .hd_wallet
             .receive_pubkey_manager()
@@ -158,6 +177,7 @@ impl PyPublicKeyGenerator {
         if start > end {
             (start, end) = (end, start);
         }
+        check_range(start, end)?;
         let pubkeys = self
             .hd_wallet
             .receive_pubkey_manager()
@@ -181,6 +201,7 @@ impl PyPublicKeyGenerator {
     ///     Exception: If derivation fails.
     #[pyo3(name = "receive_pubkey_as_string")]
     pub fn receive_pubkey_as_string(&self, index: u32) -> PyResult<String> {
+        check_index(index)?;
         Ok(self
             .hd_wallet
             .receive_pubkey_manager()
@@ -210,6 +231,7 @@ impl PyPublicKeyGenerator {
         if start > end {
             (start, end) = (end, start);
         }
+        check_range(start, end)?;
         let network_type: NetworkType = network_type.into();
         let pubkeys = self
             .hd_wallet
@@ -225,6 +247,71 @@ impl PyPublicKeyGenerator {
         Ok(addresses)
     }
 
+    /// Derive a range of receive addresses in parallel.
+    ///
+    /// Releases the GIL and spreads derivation across a thread pool. The
+    /// result is identical to `receive_addresses` and is returned in index
+    /// order. Useful when scanning large gap-limit ranges on wallet restore.
+    ///
+    /// Args:
+    ///     network_type: The network type for address encoding.
+    ///     start: Start index (inclusive).
+    ///     end: End index (exclusive).
+    ///     threads: Optional number of worker threads. Defaults to the
+    ///         global pool, sized to the number of CPUs.
+    ///
+    /// Returns:
+    ///     list[Address]: The derived addresses.
+    ///
+    /// Raises:
+    ///     ValueError: If `threads` is 0.
+    ///     Exception: If derivation fails.
+    #[pyo3(signature = (network_type, start, end, threads=None))]
+    fn receive_addresses_parallel(
+        &self,
+        py: Python<'_>,
+        #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
+        mut start: u32,
+        mut end: u32,
+        threads: Option<usize>,
+    ) -> PyResult<Vec<PyAddress>> {
+        if start > end {
+            (start, end) = (end, start);
+        }
+        check_range(start, end)?;
+        let pool = match threads {
+            Some(0) => Err(PyValueError::new_err("`threads` must be greater than 0"))?,
+            Some(threads) => Some(
+                ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .map_err(|err| PyException::new_err(err.to_string()))?,
+            ),
+            None => None,
+        };
+        let network_type: NetworkType = network_type.into();
+        let manager = self.hd_wallet.receive_pubkey_manager();
+
+        let addresses = py
+            .detach(|| {
+                let derive = || {
+                    (start..end)
+                        .into_par_iter()
+                        .map(|index| {
+                            let pubkey = manager.derive_pubkey(index)?;
+                            PublicKey::from(pubkey).to_address(network_type)
+                        })
+                        .collect::<Result<Vec<Address>>>()
+                };
+                match pool {
+                    Some(pool) => pool.install(derive),
+                    None => derive(),
+                }
+            })
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        Ok(addresses.into_iter().map(PyAddress::from).collect())
+    }
+
     /// Derive a receive address at the given index.
     ///
     /// Args:
@@ -241,6 +328,7 @@ impl PyPublicKeyGenerator {
         #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
         index: u32,
     ) -> PyResult<PyAddress> {
+        check_index(index)?;
         let inner = PublicKey::from(
             self.hd_wallet
                 .receive_pubkey_manager()
@@ -250,7 +338,7 @@ impl PyPublicKeyGenerator {
         .to_address(NetworkType::from(network_type))
         .map_err(|err| PyException::new_err(err.to_string()))?;
 
-        Ok(PyAddress(inner))
+        Ok(inner.into())
     }
 
     /// Derive a range of receive addresses as strings.
@@ -274,6 +362,7 @@ impl PyPublicKeyGenerator {
         if start > end {
             (start, end) = (end, start);
         }
+        check_range(start, end)?;
         let network_type: NetworkType = network_type.into();
         let pubkeys = self
             .hd_wallet
@@ -307,6 +396,7 @@ impl PyPublicKeyGenerator {
         #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
         index: u32,
     ) -> PyResult<String> {
+        check_index(index)?;
         Ok(PublicKey::from(
             self.hd_wallet
                 .receive_pubkey_manager()
@@ -334,6 +424,7 @@ impl PyPublicKeyGenerator {
         if start > end {
             (start, end) = (end, start);
         }
+        check_range(start, end)?;
         let pubkeys = self
             .hd_wallet
             .change_pubkey_manager()
@@ -359,6 +450,7 @@ impl PyPublicKeyGenerator {
     ///     Exception: If derivation fails.
     #[pyo3(name = "change_pubkey")]
     pub fn change_pubkey(&self, index: u32) -> PyResult<PyPublicKey> {
+        check_index(index)?;
         let inner: PublicKey = self
             .hd_wallet
             .change_pubkey_manager()
@@ -385,6 +477,7 @@ impl PyPublicKeyGenerator {
         if start > end {
             (start, end) = (end, start);
         }
+        check_range(start, end)?;
         let pubkeys = self
             .hd_wallet
             .change_pubkey_manager()
@@ -408,6 +501,7 @@ impl PyPublicKeyGenerator {
     ///     Exception: If derivation fails.
     #[pyo3(name = "change_pubkey_as_string")]
     pub fn change_pubkey_as_string(&self, index: u32) -> PyResult<String> {
+        check_index(index)?;
         Ok(self
             .hd_wallet
             .change_pubkey_manager()
@@ -437,6 +531,7 @@ impl PyPublicKeyGenerator {
         if start > end {
             (start, end) = (end, start);
         }
+        check_range(start, end)?;
         let network_type: NetworkType = network_type.into();
         let pubkeys = self
             .hd_wallet
@@ -468,6 +563,7 @@ impl PyPublicKeyGenerator {
         #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
         index: u32,
     ) -> PyResult<PyAddress> {
+        check_index(index)?;
         let inner = PublicKey::from(
             self.hd_wallet
                 .change_pubkey_manager()
@@ -477,7 +573,7 @@ impl PyPublicKeyGenerator {
         .to_address(NetworkType::from(network_type))
         .map_err(|err| PyException::new_err(err.to_string()))?;
 
-        Ok(PyAddress(inner))
+        Ok(inner.into())
     }
 
     /// Derive a range of change addresses as strings.
@@ -501,6 +597,7 @@ impl PyPublicKeyGenerator {
         if start > end {
             (start, end) = (end, start);
         }
+        check_range(start, end)?;
         let network_type: NetworkType = network_type.into();
         let pubkeys = self
             .hd_wallet
@@ -534,6 +631,7 @@ impl PyPublicKeyGenerator {
         #[gen_stub(override_type(type_repr = "str | NetworkType"))] network_type: PyNetworkType,
         index: u32,
     ) -> PyResult<String> {
+        check_index(index)?;
         Ok(PublicKey::from(
             self.hd_wallet
                 .receive_pubkey_manager()
@@ -545,6 +643,53 @@ impl PyPublicKeyGenerator {
         .to_string())
     }
 
+    /// Derive a public key at a path relative to the generator's account key.
+    ///
+    /// Only non-hardened components are allowed, since hardened derivation
+    /// requires the private key.
+    ///
+    /// Args:
+    ///     path: A derivation path string (e.g., "m/0/5") or DerivationPath.
+    ///
+    /// Returns:
+    ///     PublicKey: The derived public key.
+    ///
+    /// Raises:
+    ///     Exception: If the path contains a hardened component or derivation fails.
+    pub fn derive_pubkey(
+        &self,
+        #[gen_stub(override_type(type_repr = "str | DerivationPath"))] path: &Bound<PyAny>,
+    ) -> PyResult<PyPublicKey> {
+        let path: DerivationPath = if let Ok(path_str) = path.extract::<String>() {
+            PyDerivationPath::new(path_str.as_str())?.into()
+        } else if let Ok(path_obj) = path.extract::<PyDerivationPath>() {
+            path_obj.into()
+        } else {
+            Err(PyException::new_err(
+                "`path` must be of type `str` or `DerivationPath`",
+            ))?
+        };
+
+        if let Some((position, child)) = path
+            .iter()
+            .enumerate()
+            .find(|(_, child)| child.is_hardened())
+        {
+            return Err(PyException::new_err(format!(
+                "Hardened derivation is not possible from a public key: path component {} (`{}`) is hardened",
+                position, child
+            )));
+        }
+
+        let xpub = self
+            .xpub
+            .clone()
+            .derive_path(&path)
+            .map_err(|err| PyException::new_err(err.to_string()))?;
+        let inner: PublicKey = xpub.public_key().into();
+        Ok(PyPublicKey(inner))
+    }
+
     /// Get the string representation of this generator.
     ///
     /// Returns:
@@ -554,3 +699,20 @@ impl PyPublicKeyGenerator {
         Ok(self.hd_wallet.to_string(None).to_string())
     }
 }
+
+fn check_index(index: u32) -> PyResult<()> {
+    if index >= ChildNumber::HARDENED_FLAG {
+        return Err(PyException::new_err(format!(
+            "Hardened derivation is not possible from a public key: index {} is hardened",
+            index
+        )));
+    }
+    Ok(())
+}
+
+fn check_range(start: u32, end: u32) -> PyResult<()> {
+    if end > ChildNumber::HARDENED_FLAG {
+        check_index(start.max(ChildNumber::HARDENED_FLAG))?;
+    }
+    Ok(())
+}
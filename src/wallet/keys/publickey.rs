@@ -1,6 +1,7 @@
-use crate::{address::PyAddress, consensus::core::network::PyNetworkType};
+use crate::{address::PyAddress, consensus::core::network::PyNetworkType, types::PyBinary};
 use kaspa_addresses::{Address, Version};
 use kaspa_consensus_core::network::NetworkType;
+use kaspa_wallet_core::message::PersonalMessage;
 use kaspa_wallet_keys::{prelude::XOnlyPublicKey, publickey::PublicKey};
 use pyo3::{exceptions::PyException, prelude::*};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
@@ -64,7 +65,7 @@ impl PyPublicKey {
             .0
             .to_address(NetworkType::from(network))
             .map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(PyAddress(address))
+        Ok(address.into())
     }
 
     /// Derive an ECDSA address from this public key.
@@ -85,7 +86,7 @@ impl PyPublicKey {
             .0
             .to_address_ecdsa(NetworkType::from(network))
             .map_err(|err| PyException::new_err(err.to_string()))?;
-        Ok(PyAddress(address))
+        Ok(address.into())
     }
 
     /// Get the x-only public key (32 bytes, no parity byte).
@@ -97,6 +98,25 @@ impl PyPublicKey {
         PyXOnlyPublicKey(self.0.xonly_public_key.into())
     }
 
+    /// Verify a message signature made with the matching private key.
+    ///
+    /// Args:
+    ///     message: The original message.
+    ///     signature: The 64-byte signature as bytes, hex, or a list of ints.
+    ///
+    /// Returns:
+    ///     bool: True if the signature is valid, False otherwise (including
+    ///         signatures of the wrong length).
+    pub fn verify_message(&self, message: &str, signature: PyBinary) -> bool {
+        signature.data.len() == 64
+            && kaspa_wallet_core::message::verify_message(
+                &PersonalMessage(message),
+                &signature.data,
+                &self.0.xonly_public_key,
+            )
+            .is_ok()
+    }
+
     /// Get the key fingerprint (first 4 bytes of hash).
     ///
     /// Returns:
@@ -175,7 +195,7 @@ impl PyXOnlyPublicKey {
     ) -> PyResult<PyAddress> {
         let payload = &self.0.inner.serialize();
         let address = Address::new(NetworkType::from(network).into(), Version::PubKey, payload);
-        Ok(PyAddress(address))
+        Ok(address.into())
     }
 
     /// Derive an ECDSA address from this x-only public key.
@@ -195,7 +215,7 @@ impl PyXOnlyPublicKey {
             Version::PubKeyECDSA,
             payload,
         );
-        Ok(PyAddress(address))
+        Ok(address.into())
     }
 
     /// Extract an x-only public key from an address.
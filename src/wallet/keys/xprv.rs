@@ -1,8 +1,8 @@
+use crate::types::PyBinary;
 use crate::wallet::keys::derivation::PyDerivationPath;
 use crate::wallet::keys::{privatekey::PyPrivateKey, xpub::PyXPub};
 use kaspa_bip32::Error;
 use kaspa_bip32::{ChildNumber, ExtendedPrivateKey};
-use kaspa_utils::hex::FromHex;
 use kaspa_wallet_keys::prelude::PrivateKey;
 use kaspa_wallet_keys::xpub::XPub;
 use pyo3::{exceptions::PyException, prelude::*};
@@ -26,31 +26,55 @@ impl PyXPrv {
     }
 }
 
+impl From<ExtendedPrivateKey<SecretKey>> for PyXPrv {
+    fn from(value: ExtendedPrivateKey<SecretKey>) -> Self {
+        Self(value)
+    }
+}
+
 #[gen_stub_pymethods]
 #[pymethods]
 impl PyXPrv {
-    /// Create an XPrv from a seed hex string.
+    /// Create an XPrv from a seed.
     ///
     /// Args:
-    ///     seed: A hex-encoded seed (typically from Mnemonic.to_seed()).
+    ///     seed: A hex-encoded seed (typically from Mnemonic.to_seed()),
+    ///         or the seed as bytes.
     ///
     /// Returns:
     ///     XPrv: A new XPrv instance.
     ///
     /// Raises:
+    ///     ValueError: If the seed hex is invalid.
     ///     Exception: If the seed is invalid.
     #[new]
-    fn try_new(seed: &str) -> PyResult<PyXPrv> {
-        let seed_bytes = Vec::<u8>::from_hex(seed)
-            .map_err(|e| PyErr::new::<PyException, _>(format!("{}", e)))?;
+    fn try_new(seed: PyBinary) -> PyResult<PyXPrv> {
+        Self::from_seed(seed)
+    }
 
-        let inner = ExtendedPrivateKey::<SecretKey>::new(seed_bytes)
+    /// Create an XPrv from raw seed bytes.
+    ///
+    /// Args:
+    ///     seed: The seed as bytes, a hex string, or a list of ints
+    ///         (e.g. a BIP-39 seed derived with a passphrase).
+    ///
+    /// Returns:
+    ///     XPrv: The master XPrv for the seed.
+    ///
+    /// Raises:
+    ///     Exception: If the seed is invalid.
+    #[staticmethod]
+    pub fn from_seed(seed: PyBinary) -> PyResult<PyXPrv> {
+        let inner = ExtendedPrivateKey::<SecretKey>::new(seed.data)
             .map_err(|err: Error| PyException::new_err(err.to_string()))?;
         Ok(Self(inner))
     }
 
     /// Create an XPrv from a serialized xprv string.
     ///
+    /// Any private key prefix is accepted ("kprv", "ktrv", "xprv", "tprv", ...).
+    /// The version bytes are checked against the prefix.
+    ///
     /// Args:
     ///     xprv: A Base58-encoded extended private key string.
     ///
@@ -68,6 +92,21 @@ impl PyXPrv {
         ))
     }
 
+    /// Create an XPrv from a serialized string. Alias of `from_xprv`.
+    ///
+    /// Args:
+    ///     xprv: A Base58-encoded extended private key string.
+    ///
+    /// Returns:
+    ///     XPrv: A new XPrv instance.
+    ///
+    /// Raises:
+    ///     Exception: If the string or its version bytes are invalid.
+    #[staticmethod]
+    pub fn from_string(xprv: &str) -> PyResult<PyXPrv> {
+        Self::from_xprv_str(xprv)
+    }
+
     /// Derive a child key at the given index.
     ///
     /// Args:
@@ -121,8 +160,11 @@ impl PyXPrv {
 
     /// Serialize to string with custom prefix.
     ///
+    /// The version bytes are those of the given prefix, e.g. "ktrv" for
+    /// testnet keys.
+    ///
     /// Args:
-    ///     prefix: The key prefix (e.g., "kprv", "xprv").
+    ///     prefix: The key prefix (e.g., "kprv", "ktrv", "xprv").
     ///
     /// Returns:
     ///     str: The serialized extended private key.
@@ -14,8 +14,12 @@ from kaspa import (
     Address,
     RpcClient,
     Resolver,
+    Transaction,
+    sign_transaction,
 )
 
+from tests.helpers import TEST_ADDRESS, make_transaction
+
 
 # =============================================================================
 # Test Vectors - Deterministic values for reproducible tests
@@ -71,6 +75,22 @@ def known_keypair(known_private_key) -> Keypair:
     return known_private_key.to_keypair()
 
 
+@pytest.fixture
+def signed_transaction(known_private_key) -> Transaction:
+    """Return a transaction spending two UTXOs of the known key, signed by it.
+
+    It pays 600_000_000 sompi, with 390_000_000 sompi change at output index 1.
+    """
+    tx = make_transaction(
+        [1_000_000_000, 500_000_000],
+        [
+            {"address": TEST_ADDRESS, "amount": 600_000_000},
+            {"address": TEST_ADDRESS, "amount": 390_000_000},
+        ],
+    )
+    return sign_transaction(tx, [known_private_key], False)
+
+
 # =============================================================================
 # XPrv/XPub Fixtures
 # =============================================================================
@@ -107,3 +127,16 @@ async def testnet_rpc_client():
     await client.connect()
     yield client
     await client.disconnect()
+
+
+@pytest_asyncio.fixture(scope="session")
+async def testnet_json_rpc_client():
+    """
+    Session-scoped async fixture for a JSON-encoded RPC client connected to testnet.
+
+    Used by tests of features that require JSON encoding, such as `RpcClient.call`.
+    """
+    client = RpcClient(resolver=Resolver(), network_id="testnet-10", encoding="json")
+    await client.connect()
+    yield client
+    await client.disconnect()
@@ -0,0 +1,45 @@
+"""
+Shared helpers for building test UTXOs and transactions.
+"""
+
+from kaspa import UtxoEntryReference, create_transaction
+
+TEST_ADDRESS = "kaspa:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jkdskewva"
+TEST_SCRIPT = "20dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659ac"
+
+
+def make_utxo_entries(amounts, script=TEST_SCRIPT, block_daa_score=0, is_coinbase=False):
+    """Build UtxoEntryReference objects paying TEST_ADDRESS with the given amounts.
+
+    `block_daa_score` and `is_coinbase` apply to every entry, or per entry when
+    given as a list the same length as `amounts`.
+    """
+    block_daa_scores = _per_entry(block_daa_score, len(amounts))
+    coinbase_flags = _per_entry(is_coinbase, len(amounts))
+    return [
+        UtxoEntryReference.from_dict({
+            "address": TEST_ADDRESS,
+            "outpoint": {"transactionId": f"{index + 1:064x}", "index": 0},
+            "amount": amount,
+            "scriptPublicKey": {"version": 0, "script": script},
+            "blockDaaScore": block_daa_scores[index],
+            "isCoinbase": coinbase_flags[index],
+        })
+        for index, amount in enumerate(amounts)
+    ]
+
+
+def _per_entry(value, count):
+    return list(value) if isinstance(value, (list, tuple)) else [value] * count
+
+
+def make_transaction(amounts=(1_000_000_000,), outputs=None, script=TEST_SCRIPT, **kwargs):
+    """Create a mainnet transaction spending make_utxo_entries(amounts, script).
+
+    Pays 900_000_000 sompi to TEST_ADDRESS unless `outputs` is given. Other
+    keyword arguments are passed on to create_transaction.
+    """
+    if outputs is None:
+        outputs = [{"address": TEST_ADDRESS, "amount": 900_000_000}]
+    kwargs.setdefault("network_id", "mainnet")
+    return create_transaction(make_utxo_entries(amounts, script), outputs, 0, **kwargs)
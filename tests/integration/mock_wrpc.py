@@ -0,0 +1,98 @@
+"""
+Minimal local WebSocket servers speaking JSON wRPC, for tests that must not
+reach a real node.
+"""
+
+import asyncio
+import base64
+import hashlib
+import json
+import struct
+
+WEBSOCKET_GUID = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11"
+
+
+async def accept_websocket(reader, writer):
+    """Complete the server side of a WebSocket handshake."""
+    request = (await reader.readuntil(b"\r\n\r\n")).decode()
+    key = next(
+        line.split(":", 1)[1].strip()
+        for line in request.split("\r\n")
+        if line.lower().startswith("sec-websocket-key:")
+    )
+    accept = base64.b64encode(hashlib.sha1((key + WEBSOCKET_GUID).encode()).digest()).decode()
+    writer.write((
+        "HTTP/1.1 101 Switching Protocols\r\n"
+        "Upgrade: websocket\r\n"
+        "Connection: Upgrade\r\n"
+        f"Sec-WebSocket-Accept: {accept}\r\n\r\n"
+    ).encode())
+    await writer.drain()
+
+
+async def read_websocket_frame(reader):
+    """Read one masked client frame, returning its opcode and payload."""
+    header = await reader.readexactly(2)
+    opcode = header[0] & 0x0F
+    length = header[1] & 0x7F
+    if length == 126:
+        (length,) = struct.unpack(">H", await reader.readexactly(2))
+    elif length == 127:
+        (length,) = struct.unpack(">Q", await reader.readexactly(8))
+    mask = await reader.readexactly(4)
+    payload = await reader.readexactly(length)
+    return opcode, bytes(byte ^ mask[index % 4] for index, byte in enumerate(payload))
+
+
+def websocket_text_frame(text):
+    """Build an unmasked server text frame."""
+    payload = text.encode()
+    if len(payload) < 126:
+        header = struct.pack(">BB", 0x81, len(payload))
+    elif len(payload) < 1 << 16:
+        header = struct.pack(">BBH", 0x81, 126, len(payload))
+    else:
+        header = struct.pack(">BBQ", 0x81, 127, len(payload))
+    return header + payload
+
+
+async def start_silent_websocket_server():
+    """Start a local WebSocket server that accepts connections but never replies."""
+
+    async def handle(reader, writer):
+        await accept_websocket(reader, writer)
+        # Swallow every frame without responding
+        while await reader.read(4096):
+            pass
+        writer.close()
+
+    return await asyncio.start_server(handle, "127.0.0.1", 0)
+
+
+async def start_json_rpc_server(respond, received):
+    """Start a local JSON wRPC server answering each request with `respond(request)`.
+
+    Every decoded request is appended to `received` before it is answered.
+    """
+
+    async def handle(reader, writer):
+        await accept_websocket(reader, writer)
+        try:
+            while True:
+                opcode, payload = await read_websocket_frame(reader)
+                if opcode == 0x8:
+                    break
+                if opcode != 0x1:
+                    continue
+                request = json.loads(payload)
+                received.append(request)
+                writer.write(websocket_text_frame(json.dumps({
+                    "id": request["id"],
+                    "params": respond(request),
+                })))
+                await writer.drain()
+        except asyncio.IncompleteReadError:
+            pass
+        writer.close()
+
+    return await asyncio.start_server(handle, "127.0.0.1", 0)
@@ -2,16 +2,31 @@
 Integration tests for RPC client functionality.
 
 These tests require network access and connect to the Kaspa testnet.
+Node admin tests additionally require a local node started with `--unsaferpc`,
+reachable at the wRPC URL in the KASPA_LOCAL_RPC_URL environment variable,
+and are skipped otherwise.
 """
 
 import pytest
 import asyncio
+import os
+import time
 
 from kaspa import (
     RpcClient,
     Resolver,
     Address,
     Encoding,
+    RpcTimeoutError,
+)
+
+from tests.integration.mock_wrpc import start_json_rpc_server, start_silent_websocket_server
+
+LOCAL_RPC_URL = os.environ.get("KASPA_LOCAL_RPC_URL")
+
+requires_local_node = pytest.mark.skipif(
+    LOCAL_RPC_URL is None,
+    reason="KASPA_LOCAL_RPC_URL not set",
 )
 
 
@@ -92,6 +107,31 @@ class TestRpcClientConnection:
         assert node_id is not None
 
 
+class TestRpcCallTimeout:
+    """Tests for per-call RPC timeouts."""
+
+    async def test_call_exceeding_timeout_raises(self):
+        """Test a call the node never answers raises RpcTimeoutError."""
+        server = await start_silent_websocket_server()
+        port = server.sockets[0].getsockname()[1]
+        client = RpcClient(url=f"ws://127.0.0.1:{port}", encoding="json")
+        try:
+            await client.connect(strategy="fallback", timeout_duration=5000)
+
+            with pytest.raises(RpcTimeoutError):
+                await client.get_info(timeout=200)
+            with pytest.raises(TimeoutError):
+                await client.get_block({"hash": "0" * 64, "includeTransactions": False}, timeout=200)
+        finally:
+            await client.disconnect()
+            server.close()
+
+    async def test_call_within_timeout(self, testnet_rpc_client):
+        """Test a call answered before the timeout returns normally."""
+        result = await testnet_rpc_client.get_info(timeout=30_000)
+        assert isinstance(result, dict)
+
+
 class TestRpcClientCalls:
     """Tests for RPC calls."""
 
@@ -120,6 +160,12 @@ class TestRpcClientCalls:
         result = await testnet_rpc_client.get_coin_supply()
         assert isinstance(result, dict)
 
+    async def test_get_coin_supply_bounds(self, testnet_rpc_client):
+        """Test circulating supply is positive and within max supply."""
+        result = await testnet_rpc_client.get_coin_supply()
+        assert result["circulatingSompi"] > 0
+        assert result["circulatingSompi"] <= result["maxSompi"]
+
     async def test_get_sink(self, testnet_rpc_client):
         """Test get_sink RPC call."""
         result = await testnet_rpc_client.get_sink()
@@ -181,6 +227,7 @@ class TestRpcClientCalls:
         """Test get_connected_peer_info RPC call."""
         result = await testnet_rpc_client.get_connected_peer_info()
         assert isinstance(result, dict)
+        assert isinstance(result["peerInfo"], list)
 
     async def test_get_peer_addresses(self, testnet_rpc_client):
         """Test get_peer_addresses RPC call."""
@@ -194,6 +241,21 @@ class TestRpcClientCalls:
         })
         assert isinstance(result, dict)
 
+    async def test_estimate_network_hashes_per_second_positive(self, testnet_rpc_client):
+        """Test the estimated hashrate is positive, with and without a start hash."""
+        result = await testnet_rpc_client.estimate_network_hashes_per_second({
+            "windowSize": 1000,
+            "startHash": None,
+        })
+        assert result["networkHashesPerSecond"] > 0
+
+        sink = await testnet_rpc_client.get_sink()
+        result = await testnet_rpc_client.estimate_network_hashes_per_second({
+            "windowSize": 1000,
+            "startHash": sink["sink"],
+        })
+        assert result["networkHashesPerSecond"] > 0
+
     async def test_get_mempool_entries(self, testnet_rpc_client):
         """Test get_mempool_entries RPC call."""
         result = await testnet_rpc_client.get_mempool_entries({
@@ -212,3 +274,136 @@ class TestRpcClientCalls:
             "filterTransactionPool": False
         })
         assert isinstance(result, dict)
+
+    async def test_get_virtual_chain_from_block(self, testnet_rpc_client):
+        """Test get_virtual_chain_from_block RPC call response shape."""
+        sink = (await testnet_rpc_client.get_sink())["sink"]
+
+        result = await testnet_rpc_client.get_virtual_chain_from_block({
+            "startHash": sink,
+            "includeAcceptedTransactionIds": True,
+        })
+        assert isinstance(result, dict)
+        assert isinstance(result["removedChainBlockHashes"], list)
+        assert isinstance(result["addedChainBlockHashes"], list)
+        assert isinstance(result["acceptedTransactionIds"], list)
+
+    async def test_get_daa_score_timestamp_estimate(self, testnet_rpc_client):
+        """Test the timestamp estimate for the current DAA score is near now."""
+        dag_info = await testnet_rpc_client.get_block_dag_info()
+
+        result = await testnet_rpc_client.get_daa_score_timestamp_estimate({
+            "daaScores": [dag_info["virtualDaaScore"]]
+        })
+        assert len(result["timestamps"]) == 1
+        # Timestamps are in milliseconds
+        assert abs(result["timestamps"][0] - time.time() * 1000) < 10 * 60 * 1000
+
+    async def test_get_metrics(self, testnet_rpc_client):
+        """Test get_metrics returns the requested top-level metric groups."""
+        result = await testnet_rpc_client.get_metrics({
+            "processMetrics": True,
+            "connectionMetrics": True,
+            "bandwidthMetrics": False,
+            "consensusMetrics": True,
+            "storageMetrics": False,
+            "customMetrics": False,
+        })
+        assert isinstance(result, dict)
+        assert "serverTime" in result
+        assert "processMetrics" in result
+        assert "connectionMetrics" in result
+        assert "consensusMetrics" in result
+
+
+class TestRpcRawCall:
+    """Tests for the raw RpcClient.call escape hatch."""
+
+    async def test_call_by_wrpc_name(self, testnet_json_rpc_client):
+        """Test the raw call reaches an RPC method by its wRPC name."""
+        result = await testnet_json_rpc_client.call("getServerInfo", {})
+        assert isinstance(result, dict)
+        assert "serverVersion" in result
+
+    async def test_call_by_snake_case_name(self, testnet_json_rpc_client):
+        """Test the raw call accepts snake_case names and request params."""
+        sink = (await testnet_json_rpc_client.call("get_sink"))["sink"]
+
+        result = await testnet_json_rpc_client.call("get_block", {
+            "hash": sink,
+            "includeTransactions": False,
+        })
+        assert isinstance(result, dict)
+
+    async def test_call_method_without_wrapper(self):
+        """Test the raw call sends a method the client has no method for, untouched."""
+        assert not hasattr(RpcClient, "subscribe")
+
+        received = []
+        server = await start_json_rpc_server(
+            lambda request: {"method": request["method"], "params": request["params"]},
+            received,
+        )
+        port = server.sockets[0].getsockname()[1]
+        client = RpcClient(url=f"ws://127.0.0.1:{port}", encoding="json")
+        try:
+            await client.connect(strategy="fallback", timeout_duration=5000)
+
+            params = {"anything": [1, "two", None], "nested": {"value": 3}}
+            result = await client.call("subscribe", params, timeout=5000)
+
+            sent = [request for request in received if request["method"].lower() == "subscribe"]
+            assert len(sent) == 1
+            assert sent[0]["params"] == params
+            assert result == {"method": sent[0]["method"], "params": params}
+        finally:
+            await client.disconnect()
+            server.close()
+
+    async def test_call_unknown_method(self, testnet_json_rpc_client):
+        """Test the raw call rejects names that are not wRPC methods."""
+        with pytest.raises(ValueError):
+            testnet_json_rpc_client.call("getSomethingThatDoesNotExist", {})
+
+    async def test_call_requires_json_encoding(self, testnet_rpc_client):
+        """Test the raw call is rejected on a Borsh-encoded client."""
+        with pytest.raises(ValueError, match="JSON"):
+            testnet_rpc_client.call("getServerInfo", {})
+
+
+@requires_local_node
+class TestNodeAdmin:
+    """Tests for peer management RPCs against a local node."""
+
+    TEST_IP = "203.0.113.7"
+
+    async def _client(self):
+        client = RpcClient(url=LOCAL_RPC_URL)
+        await client.connect()
+        return client
+
+    async def test_ban_and_unban(self):
+        """Test banning and unbanning a test IP."""
+        client = await self._client()
+        try:
+            ban = await client.ban({"ip": self.TEST_IP})
+            unban = await client.unban({"ip": self.TEST_IP})
+        finally:
+            await client.disconnect()
+
+        assert isinstance(ban, dict)
+        assert isinstance(unban, dict)
+
+    async def test_add_peer(self):
+        """Test adding a non-permanent peer."""
+        client = await self._client()
+        try:
+            result = await client.add_peer({
+                "peerAddress": f"{self.TEST_IP}:16111",
+                "isPermanent": False,
+            })
+        finally:
+            await client.disconnect()
+
+        assert isinstance(result, dict)
+
@@ -4,10 +4,14 @@ Integration tests for RPC subscription functionality.
 These tests require network access and connect to the Kaspa testnet.
 """
 
+import os
+
 import pytest
 import asyncio
 
-from kaspa import RpcClient, Resolver, Address
+from kaspa import RpcClient, Resolver, Address, NotificationEvent, PrivateKey, create_transactions
+
+FUNDED_PRIVATE_KEY = os.environ.get("KASPA_TESTNET_PRIVATE_KEY")
 
 
 # Simple subscriptions that take no arguments
@@ -70,6 +74,22 @@ class TestEventListeners:
         # All listeners should be removed without error
         assert True
 
+    async def test_notification_event_accepted_by_listener(self, testnet_rpc_client):
+        """Test NotificationEvent values are accepted as listener events."""
+        def callback(event_data):
+            pass
+
+        events = [
+            NotificationEvent.BlockAdded,
+            NotificationEvent.UtxosChanged,
+            NotificationEvent.VirtualDaaScoreChanged,
+            NotificationEvent.PruningPointUtxoSetOverride,
+            NotificationEvent.Connect,
+        ]
+        for event in events:
+            testnet_rpc_client.add_event_listener(event, callback)
+        testnet_rpc_client.remove_all_event_listeners()
+
 
 class TestSimpleSubscriptions:
     """Tests for simple subscribe/unsubscribe operations that take no arguments."""
@@ -136,6 +156,65 @@ class TestUtxoSubscription:
         assert True
 
 
+class TestScopedUtxosChangedListener:
+    """Tests for address-scoped UTXO change listeners."""
+
+    async def test_add_and_remove(self, testnet_rpc_client):
+        """Test registering and removing a scoped listener."""
+        test_address = Address(
+            "kaspatest:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jhtkdksae")
+
+        def callback(event_data):
+            pass
+
+        testnet_rpc_client.add_utxos_changed_listener([test_address], callback)
+        testnet_rpc_client.remove_event_listener("utxos-changed", callback)
+
+    @pytest.mark.skipif(FUNDED_PRIVATE_KEY is None, reason="KASPA_TESTNET_PRIVATE_KEY not set")
+    async def test_other_address_not_notified(self, testnet_rpc_client):
+        """Test a change for an unrelated address doesn't trigger a scoped callback."""
+        private_key = PrivateKey(FUNDED_PRIVATE_KEY)
+        funded_address = private_key.to_address("testnet")
+        other_address = PrivateKey("1" * 64).to_address("testnet")
+
+        utxos = await testnet_rpc_client.get_utxos_by_addresses({
+            "addresses": [funded_address.to_string()]
+        })
+        if not utxos["entries"]:
+            pytest.skip("funded address has no UTXOs")
+
+        loop = asyncio.get_running_loop()
+        funded_event = asyncio.Event()
+        funded_events = []
+        other_events = []
+
+        def on_funded(event_data):
+            funded_events.append(event_data)
+            loop.call_soon_threadsafe(funded_event.set)
+
+        testnet_rpc_client.add_utxos_changed_listener([funded_address], on_funded)
+        testnet_rpc_client.add_utxos_changed_listener([other_address], other_events.append)
+        await testnet_rpc_client.subscribe_utxos_changed([funded_address, other_address])
+
+        result = create_transactions(
+            network_id="testnet-10",
+            entries=utxos["entries"],
+            change_address=funded_address,
+            outputs=[{"address": funded_address, "amount": 100_000_000}],
+        )
+        for pending in result["transactions"]:
+            pending.sign([private_key])
+        await result["transactions"].submit_all(testnet_rpc_client)
+
+        await asyncio.wait_for(funded_event.wait(), timeout=30.0)
+        await testnet_rpc_client.unsubscribe_utxos_changed([funded_address, other_address])
+
+        assert other_events == []
+        for event in funded_events:
+            for entry in event["added"] + event["removed"]:
+                assert entry["address"] == funded_address.to_string()
+
+
 class TestEventReceiving:
     """Tests for actually receiving events (may take time)."""
 
@@ -154,3 +233,25 @@ class TestEventReceiving:
 
         await asyncio.wait_for(event_received.wait(), timeout=30.0)
         assert len(received_events) > 0
+
+    async def test_block_added_event_kind_and_payload(self, testnet_rpc_client):
+        """Test a received event's kind matches its type and exposes its payload."""
+        received_events = []
+        event_received = asyncio.Event()
+
+        def callback(event_data):
+            received_events.append(event_data)
+            event_received.set()
+
+        testnet_rpc_client.add_event_listener(NotificationEvent.BlockAdded, callback)
+        await testnet_rpc_client.subscribe_block_added()
+        try:
+            await asyncio.wait_for(event_received.wait(), timeout=30.0)
+        finally:
+            await testnet_rpc_client.unsubscribe_block_added()
+            testnet_rpc_client.remove_event_listener(NotificationEvent.BlockAdded, callback)
+
+        event = received_events[0]
+        assert event["event"].kind == event["type"]
+        assert "block" in NotificationEvent.as_block_added(event)
+        assert NotificationEvent.as_utxos_changed(event) is None
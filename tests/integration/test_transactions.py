@@ -0,0 +1,186 @@
+"""
+Integration tests for building and submitting transactions.
+
+These tests require network access and connect to the Kaspa testnet, except
+the dry-run tests, which run against a local mock RPC server. Submission tests
+additionally require a funded testnet private key in the
+KASPA_TESTNET_PRIVATE_KEY environment variable and are skipped otherwise.
+"""
+
+import os
+
+import pytest
+
+from kaspa import PrivateKey, RpcClient, create_transactions, send, wait_for_acceptance
+
+from tests.integration.mock_wrpc import start_json_rpc_server
+
+FUNDED_PRIVATE_KEY = os.environ.get("KASPA_TESTNET_PRIVATE_KEY")
+
+requires_funded_key = pytest.mark.skipif(
+    FUNDED_PRIVATE_KEY is None,
+    reason="KASPA_TESTNET_PRIVATE_KEY not set",
+)
+
+
+@requires_funded_key
+class TestSubmitAll:
+    """Tests for PendingTransactionBatch.submit_all with live RPC."""
+
+    async def test_submit_chain(self, testnet_rpc_client):
+        """Test a batch is submitted in order and all ids are returned."""
+        private_key = PrivateKey(FUNDED_PRIVATE_KEY)
+        address = private_key.to_address("testnet")
+
+        utxos = await testnet_rpc_client.get_utxos_by_addresses({
+            "addresses": [address.to_string()]
+        })
+        entries = utxos["entries"]
+        if not entries:
+            pytest.skip("funded address has no UTXOs")
+
+        result = create_transactions(
+            network_id="testnet-10",
+            entries=entries,
+            change_address=address,
+            outputs=[{"address": address, "amount": 100_000_000}],
+        )
+        batch = result["transactions"]
+        for pending in batch:
+            pending.sign([private_key])
+
+        ids = await batch.submit_all(testnet_rpc_client)
+
+        assert ids == [pending.id for pending in batch]
+
+
+@requires_funded_key
+class TestSend:
+    """Tests for the send helper with live RPC."""
+
+    async def test_send_to_self(self, testnet_rpc_client):
+        """Test a small payment is built, signed, and submitted."""
+        private_key = PrivateKey(FUNDED_PRIVATE_KEY)
+        address = private_key.to_address("testnet")
+
+        ids = await send(
+            testnet_rpc_client,
+            [private_key],
+            outputs=[{"address": address, "amount": 100_000_000}],
+            change_address=address,
+            network="testnet-10",
+        )
+
+        assert len(ids) >= 1
+        assert all(len(txid) == 64 for txid in ids)
+
+
+class TestSendDryRun:
+    """Tests for send with dry_run against a mock RPC server."""
+
+    @staticmethod
+    def _respond(address, submitted):
+        def respond(request):
+            method = request["method"].lower()
+            if method == "getutxosbyaddresses":
+                spk = address.to_script_public_key()
+                return {"entries": [{
+                    "address": address.to_string(),
+                    "outpoint": {"transactionId": "ab" * 32, "index": 0},
+                    "utxoEntry": {
+                        "amount": 1_000_000_000,
+                        "scriptPublicKey": "0000" + spk.script,
+                        "blockDaaScore": 0,
+                        "isCoinbase": False,
+                    },
+                }]}
+            if method == "getblockdaginfo":
+                return {
+                    "network": "testnet-10",
+                    "blockCount": 1000,
+                    "headerCount": 1000,
+                    "tipHashes": ["00" * 32],
+                    "difficulty": 1.0,
+                    "pastMedianTime": 0,
+                    "virtualParentHashes": ["00" * 32],
+                    "pruningPointHash": "00" * 32,
+                    "virtualDaaScore": 1000,
+                    "sink": "00" * 32,
+                }
+            if method == "submittransaction":
+                submitted.append(request)
+                return {"transactionId": "cd" * 32}
+            return {}
+
+        return respond
+
+    async def test_dry_run_does_not_submit(self, known_private_key):
+        """Test dry_run returns signed transactions without submitting any of them."""
+        address = known_private_key.to_address("testnet")
+        received = []
+        submitted = []
+        server = await start_json_rpc_server(self._respond(address, submitted), received)
+        port = server.sockets[0].getsockname()[1]
+        client = RpcClient(url=f"ws://127.0.0.1:{port}", encoding="json")
+        try:
+            await client.connect(strategy="fallback", timeout_duration=5000)
+
+            result = await send(
+                client,
+                [known_private_key],
+                outputs=[{"address": address, "amount": 100_000_000}],
+                change_address=address,
+                network="testnet-10",
+                dry_run=True,
+            )
+        finally:
+            await client.disconnect()
+            server.close()
+
+        batch = result["transactions"]
+        assert len(batch) >= 1
+        assert result["summary"].transactions == len(batch)
+        for pending in batch:
+            assert pending.transaction.verify_signatures()
+        assert any(request["method"].lower() == "getutxosbyaddresses" for request in received)
+        assert submitted == []
+
+
+class TestWaitForAcceptance:
+    """Tests for wait_for_acceptance with live RPC."""
+
+    async def test_invalid_transaction_id(self, testnet_rpc_client):
+        """Test a malformed transaction id raises ValueError."""
+        with pytest.raises(ValueError):
+            await wait_for_acceptance(testnet_rpc_client, "not-a-txid", 1_000)
+
+    async def test_unknown_transaction_times_out(self, testnet_rpc_client):
+        """Test a transaction that is never submitted is not reported accepted."""
+        assert await wait_for_acceptance(testnet_rpc_client, "ab" * 32, 2_000) is False
+
+    @requires_funded_key
+    async def test_submitted_transaction_accepted(self, testnet_rpc_client):
+        """Test a submitted transaction is reported accepted."""
+        private_key = PrivateKey(FUNDED_PRIVATE_KEY)
+        address = private_key.to_address("testnet")
+        # Capture the sink before submitting so an early acceptance isn't missed
+        start_hash = (await testnet_rpc_client.get_sink())["sink"]
+
+        ids = await send(
+            testnet_rpc_client,
+            [private_key],
+            outputs=[{"address": address, "amount": 100_000_000}],
+            change_address=address,
+            network="testnet-10",
+        )
+
+        assert await wait_for_acceptance(
+            testnet_rpc_client, ids[-1], 60_000, start_hash=start_hash
+        ) is True
+
+    async def test_invalid_start_hash(self, testnet_rpc_client):
+        """Test a malformed start hash raises ValueError."""
+        with pytest.raises(ValueError):
+            await wait_for_acceptance(
+                testnet_rpc_client, "ab" * 32, 1_000, start_hash="not-a-hash"
+            )
@@ -2,14 +2,48 @@
 Integration tests for UTXO context functionality.
 
 These tests require network access and connect to the Kaspa testnet.
+Tests that fund addresses additionally require a funded testnet private key
+in the KASPA_TESTNET_PRIVATE_KEY environment variable and are skipped otherwise.
 """
 
+import asyncio
+import os
+
 import pytest
 
-from kaspa import NetworkId, UtxoContext, UtxoProcessor
+from kaspa import Keypair, NetworkId, PrivateKey, UtxoContext, UtxoProcessor, send, wait_for_acceptance
 
 TEST_ADDRESS = "kaspatest:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jhtkdksae"
 
+FUNDED_PRIVATE_KEY = os.environ.get("KASPA_TESTNET_PRIVATE_KEY")
+
+requires_funded_key = pytest.mark.skipif(
+    FUNDED_PRIVATE_KEY is None,
+    reason="KASPA_TESTNET_PRIVATE_KEY not set",
+)
+
+
+async def fund_addresses(rpc, outputs):
+    """Pay the given outputs from the funded key and wait until they are accepted."""
+    private_key = PrivateKey(FUNDED_PRIVATE_KEY)
+    start_hash = (await rpc.get_sink())["sink"]
+    ids = await send(
+        rpc,
+        [private_key],
+        outputs=outputs,
+        change_address=private_key.to_address("testnet"),
+        network="testnet-10",
+    )
+    assert await wait_for_acceptance(rpc, ids[-1], 60_000, start_hash=start_hash) is True
+
+
+async def wait_for_mature(context, count):
+    """Wait for fresh UTXOs to move from pending to the mature set."""
+    for _ in range(60):
+        if context.mature_length == count:
+            return
+        await asyncio.sleep(1)
+
 
 class TestUtxoContext:
     """Tests for UtxoProcessor/UtxoContext with live RPC."""
@@ -37,6 +71,93 @@ class TestUtxoContext:
         finally:
             await processor.stop()
 
+    async def test_wait_for_sync_then_balance(self, testnet_rpc_client):
+        processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
+        await processor.start()
+        try:
+            await processor.wait_for_sync(timeout=30_000)
+            context = UtxoContext(processor)
+            await context.track_addresses([TEST_ADDRESS])
+
+            assert context.balance is not None
+        finally:
+            await processor.stop()
+
+    @requires_funded_key
+    async def test_utxos_by_address(self, testnet_rpc_client):
+        first = Keypair.random().to_address("testnet").to_string()
+        second = Keypair.random().to_address("testnet").to_string()
+
+        # One UTXO for the first address and two for the second
+        await fund_addresses(testnet_rpc_client, [
+            {"address": first, "amount": 100_000_000},
+            {"address": second, "amount": 110_000_000},
+            {"address": second, "amount": 120_000_000},
+        ])
+
+        processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
+        await processor.start()
+        try:
+            context = UtxoContext(processor)
+            await context.track_addresses([first, second])
+            await wait_for_mature(context, 3)
+
+            grouped = context.utxos_by_address()
+            assert set(grouped.keys()) == {first, second}
+            assert len(grouped[first]) == 1
+            assert len(grouped[second]) == 2
+            assert sorted(entry.amount for entry in grouped[second]) == [110_000_000, 120_000_000]
+            for address, entries in grouped.items():
+                assert all(entry.address.to_string() == address for entry in entries)
+        finally:
+            await processor.stop()
+
+    @requires_funded_key
+    async def test_balance_kas(self, testnet_rpc_client):
+        address = Keypair.random().to_address("testnet").to_string()
+        await fund_addresses(testnet_rpc_client, [{"address": address, "amount": 100_000_001}])
+
+        processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
+        await processor.start()
+        try:
+            context = UtxoContext(processor)
+            await context.track_addresses([address])
+            await wait_for_mature(context, 1)
+
+            balance = context.balance
+            assert balance.mature == 100_000_001
+            assert balance.mature_kas == "1.00000001"
+            assert balance.pending_kas == "0"
+            assert balance.outgoing_kas == "0"
+        finally:
+            await processor.stop()
+
+    async def test_iter_mature_pages(self, testnet_rpc_client):
+        processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
+        await processor.start()
+        try:
+            context = UtxoContext(processor)
+            await context.track_addresses([TEST_ADDRESS])
+
+            pages = list(context.iter_mature(100))
+            assert all(0 < len(page) <= 100 for page in pages)
+            assert sum(len(page) for page in pages) == context.mature_length
+
+            ids = [(e.outpoint.transaction_id, e.outpoint.index) for page in pages for e in page]
+            expected = [
+                (e.outpoint.transaction_id, e.outpoint.index)
+                for e in context.mature_range(0, context.mature_length)
+            ]
+            assert ids == expected
+        finally:
+            await processor.stop()
+
+    async def test_iter_mature_invalid_page_size(self, testnet_rpc_client):
+        processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
+        context = UtxoContext(processor)
+        with pytest.raises(ValueError):
+            context.iter_mature(0)
+
     async def test_mature_range_invalid_range(self, testnet_rpc_client):
         processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
         await processor.start()
@@ -57,6 +178,12 @@ class TestUtxoContext:
         finally:
             await processor.stop()
 
+    async def test_track_addresses_network_mismatch(self, testnet_rpc_client):
+        processor = UtxoProcessor(testnet_rpc_client, NetworkId("mainnet"))
+        context = UtxoContext(processor)
+        with pytest.raises(Exception, match=TEST_ADDRESS):
+            await context.track_addresses([TEST_ADDRESS])
+
     async def test_context_invalid_id(self, testnet_rpc_client):
         processor = UtxoProcessor(testnet_rpc_client, NetworkId("testnet-10"))
         await processor.start()
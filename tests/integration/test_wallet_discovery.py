@@ -0,0 +1,62 @@
+"""
+Integration tests for discovering used account addresses.
+
+These tests require network access and connect to the Kaspa testnet.
+Scanning an account with activity additionally requires a testnet account
+extended public key (kpub) in the KASPA_TESTNET_XPUB environment variable
+and is skipped otherwise.
+"""
+
+import os
+
+import pytest
+
+from kaspa import Mnemonic, PublicKeyGenerator, XPrv, address_indices_with_utxos
+
+FUNDED_XPUB = os.environ.get("KASPA_TESTNET_XPUB")
+
+
+class TestAddressIndicesWithUtxos:
+    """Tests for address_indices_with_utxos with live RPC."""
+
+    async def test_fresh_account_has_no_used_addresses(self, testnet_rpc_client):
+        """Test a newly generated account reports no used addresses."""
+        xprv = XPrv(Mnemonic.random().to_seed())
+        generator = PublicKeyGenerator.from_master_xprv(xprv, False, 0)
+
+        result = await address_indices_with_utxos(
+            testnet_rpc_client, generator, "testnet", gap_limit=5
+        )
+
+        assert result == {"receive": [], "change": []}
+
+    async def test_zero_gap_limit_raises(self, testnet_rpc_client):
+        """Test a gap limit of 0 is rejected."""
+        xprv = XPrv(Mnemonic.random().to_seed())
+        generator = PublicKeyGenerator.from_master_xprv(xprv, False, 0)
+
+        with pytest.raises(ValueError):
+            await address_indices_with_utxos(testnet_rpc_client, generator, "testnet", 0)
+
+    @pytest.mark.skipif(FUNDED_XPUB is None, reason="KASPA_TESTNET_XPUB not set")
+    async def test_account_with_activity(self, testnet_rpc_client):
+        """Test used indices are reported in order with UTXOs for their address."""
+        generator = PublicKeyGenerator.from_xpub(FUNDED_XPUB)
+
+        result = await address_indices_with_utxos(testnet_rpc_client, generator, "testnet")
+
+        used = result["receive"] + result["change"]
+        if not used:
+            pytest.skip("account has no UTXOs")
+        for branch, derive in (
+            (result["receive"], generator.receive_address),
+            (result["change"], generator.change_address),
+        ):
+            indices = [item["index"] for item in branch]
+            assert indices == sorted(indices)
+            for item in branch:
+                assert item["address"] == derive("testnet", item["index"])
+                assert item["utxos"]
+                assert all(
+                    utxo.address == item["address"] for utxo in item["utxos"]
+                )
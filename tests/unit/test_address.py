@@ -4,7 +4,17 @@ Unit tests for the Address class.
 
 import pytest
 
-from kaspa import Address, PublicKey, ScriptPublicKey, pay_to_address_script, address_from_script_public_key
+from kaspa import (
+    Address,
+    AddressBook,
+    AddressNetworkMismatchError,
+    AddressVersion,
+    NetworkType,
+    PublicKey,
+    ScriptPublicKey,
+    pay_to_address_script,
+    address_from_script_public_key,
+)
 from tests.conftest import TEST_MAINNET_ADDRESS
 
 
@@ -119,8 +129,141 @@ class TestScriptPublicKeyAddress:
         spk = pay_to_address_script(known_mainnet_address)
         assert isinstance(spk, ScriptPublicKey)
 
+    def test_pay_to_address_script_matching_network(self, known_mainnet_address):
+        """Test an address matching the expected network produces the same script."""
+        spk = pay_to_address_script(known_mainnet_address, "mainnet")
+        assert spk.script == pay_to_address_script(known_mainnet_address).script
+        assert pay_to_address_script(known_mainnet_address, NetworkType.Mainnet).script == spk.script
+
+    def test_pay_to_address_script_network_mismatch(self, known_keypair):
+        """Test a testnet address with a mainnet expectation raises."""
+        testnet_address = known_keypair.to_address("testnet")
+
+        with pytest.raises(AddressNetworkMismatchError, match="kaspatest"):
+            pay_to_address_script(testnet_address, "mainnet")
+        with pytest.raises(ValueError):
+            pay_to_address_script(testnet_address, NetworkType.Mainnet)
+
     def test_address_from_script_public_key_roundtrip(self, known_mainnet_address):
         """Test roundtrip: address -> ScriptPublicKey -> address."""
         spk = pay_to_address_script(known_mainnet_address)
         recovered_address = address_from_script_public_key(spk, "mainnet")
         assert recovered_address.to_string() == known_mainnet_address.to_string()
+
+    def test_to_script_public_key_roundtrip(self, known_mainnet_address):
+        """Test Address.to_script_public_key decodes back to the same address."""
+        spk = known_mainnet_address.to_script_public_key()
+
+        assert spk.script == pay_to_address_script(known_mainnet_address).script
+        assert address_from_script_public_key(spk, "mainnet") == known_mainnet_address
+
+    def test_to_script_public_key_cached(self, known_mainnet_address):
+        """Test repeated calls return equal scripts and leave equality unaffected."""
+        first = known_mainnet_address.to_script_public_key()
+        second = known_mainnet_address.to_script_public_key()
+
+        assert first.script == second.script
+        assert known_mainnet_address == Address(known_mainnet_address.to_string())
+
+    def test_to_script_public_key_ecdsa(self, known_keypair):
+        """Test the script of an ECDSA address round-trips."""
+        address = known_keypair.to_address_ecdsa("mainnet")
+
+        spk = address.to_script_public_key()
+
+        assert address_from_script_public_key(spk, "mainnet") == address
+
+
+class TestAddressVersion:
+    """Tests for AddressVersion int and name mapping."""
+
+    @pytest.mark.parametrize("version,value,name", [
+        (AddressVersion.PubKey, 0, "PubKey"),
+        (AddressVersion.PubKeyECDSA, 1, "PubKeyECDSA"),
+        (AddressVersion.ScriptHash, 8, "ScriptHash"),
+    ])
+    def test_value_and_name_roundtrip(self, version, value, name):
+        """Test mapping a version to its byte and name and back."""
+        assert version.value == value
+        assert AddressVersion.from_int(value) == version
+        assert str(version) == name
+
+    def test_from_int_unknown_raises(self):
+        """Test an unknown version byte raises ValueError."""
+        with pytest.raises(ValueError):
+            AddressVersion.from_int(2)
+
+    def test_matches_address_version(self):
+        """Test the name matches the Address.version string."""
+        address = Address(TEST_MAINNET_ADDRESS)
+        assert str(AddressVersion.PubKey) == address.version
+
+
+class TestAddressBook:
+    """Tests for AddressBook label lookups."""
+
+    def _book(self, known_keypair):
+        book = AddressBook()
+        book.add(TEST_MAINNET_ADDRESS, "savings")
+        book.add(known_keypair.to_address_ecdsa("mainnet"), "exchange")
+        return book
+
+    def test_resolve_by_label_and_address(self, known_keypair):
+        """Test two labeled addresses resolve in both directions."""
+        book = self._book(known_keypair)
+        ecdsa_address = known_keypair.to_address_ecdsa("mainnet")
+
+        assert len(book) == 2
+        assert book.get_address("savings") == Address(TEST_MAINNET_ADDRESS)
+        assert book.get_address("exchange") == ecdsa_address
+        assert book.get_label(Address(TEST_MAINNET_ADDRESS)) == "savings"
+        assert book.get_label(ecdsa_address.to_string()) == "exchange"
+
+    def test_missing_lookups(self, known_keypair):
+        """Test unknown labels and addresses return None."""
+        book = self._book(known_keypair)
+
+        assert book.get_address("unknown") is None
+        assert book.get_label(known_keypair.to_address("testnet")) is None
+
+    def test_relabel_address(self, known_keypair):
+        """Test adding an address again replaces its label."""
+        book = self._book(known_keypair)
+
+        book.add(TEST_MAINNET_ADDRESS, "cold storage")
+
+        assert len(book) == 2
+        assert book.get_label(TEST_MAINNET_ADDRESS) == "cold storage"
+        assert book.get_address("savings") is None
+
+    def test_duplicate_label(self, known_keypair):
+        """Test a label cannot name two addresses."""
+        book = self._book(known_keypair)
+
+        with pytest.raises(ValueError, match="savings"):
+            book.add(known_keypair.to_address_ecdsa("mainnet"), "savings")
+        assert book.get_label(known_keypair.to_address_ecdsa("mainnet")) == "exchange"
+
+    def test_remove(self, known_keypair):
+        """Test removing an address frees its label."""
+        book = self._book(known_keypair)
+
+        assert book.remove(TEST_MAINNET_ADDRESS) == "savings"
+        assert book.remove(TEST_MAINNET_ADDRESS) is None
+        assert book.get_address("savings") is None
+        assert len(book) == 1
+
+    def test_dict_roundtrip(self, known_keypair):
+        """Test to_dict/from_dict round-trip."""
+        book = self._book(known_keypair)
+
+        d = book.to_dict()
+        restored = AddressBook.from_dict(d)
+
+        assert d[TEST_MAINNET_ADDRESS] == "savings"
+        assert restored.to_dict() == d
+
+    def test_invalid_address(self):
+        """Test an invalid address string raises."""
+        with pytest.raises(Exception):
+            AddressBook().add("not-an-address", "label")
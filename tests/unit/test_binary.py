@@ -0,0 +1,72 @@
+"""
+Unit tests for Binary argument coercion.
+"""
+
+import pytest
+
+from kaspa import ScriptPublicKey, Mnemonic
+
+
+class TestBinaryAcceptedForms:
+    """Tests for the accepted Binary input forms."""
+
+    @pytest.mark.parametrize("value", [
+        "deadbeef",
+        "DEADBEEF",
+        "0xdeadbeef",
+        "0Xdeadbeef",
+        bytes([0xde, 0xad, 0xbe, 0xef]),
+        bytearray([0xde, 0xad, 0xbe, 0xef]),
+        [0xde, 0xad, 0xbe, 0xef],
+    ])
+    def test_accepted_forms(self, value):
+        """Test each accepted form decodes to the same bytes."""
+        spk = ScriptPublicKey(0, value)
+        assert spk.script == "deadbeef"
+
+    def test_empty_values(self):
+        """Test empty hex, prefix-only hex, and empty bytes."""
+        assert ScriptPublicKey(0, "").script == ""
+        assert ScriptPublicKey(0, "0x").script == ""
+        assert ScriptPublicKey(0, b"").script == ""
+
+
+class TestBinaryRejectedForms:
+    """Tests for Binary input validation."""
+
+    @pytest.mark.parametrize("value", ["abc", "0xabc", "zz", "0xzz"])
+    def test_invalid_hex_raises_value_error(self, value):
+        """Test odd-length and non-hex strings raise ValueError."""
+        with pytest.raises(ValueError):
+            ScriptPublicKey(0, value)
+
+    @pytest.mark.parametrize("value", [[256], [-1], ["a"]])
+    def test_invalid_list_items_raise_value_error(self, value):
+        """Test out-of-range or non-integer list items raise ValueError."""
+        with pytest.raises(ValueError):
+            ScriptPublicKey(0, value)
+
+    @pytest.mark.parametrize("value", [123, 1.5, None, {"a": 1}])
+    def test_unsupported_types_raise_value_error(self, value):
+        """Test unsupported types raise ValueError."""
+        with pytest.raises(ValueError):
+            ScriptPublicKey(0, value)
+
+
+class TestBinarySetters:
+    """Tests for setters that accept Binary values."""
+
+    def test_mnemonic_entropy_setter_accepts_bytes(self):
+        """Test setting mnemonic entropy from bytes and prefixed hex."""
+        mnemonic = Mnemonic.random(12)
+        mnemonic.entropy = bytes(16)
+        assert mnemonic.entropy == "00" * 16
+
+        mnemonic.entropy = "0x" + "ff" * 16
+        assert mnemonic.entropy == "ff" * 16
+
+    def test_mnemonic_entropy_setter_rejects_bad_length(self):
+        """Test setting entropy with an invalid length raises ValueError."""
+        mnemonic = Mnemonic.random(12)
+        with pytest.raises(ValueError):
+            mnemonic.entropy = bytes(10)
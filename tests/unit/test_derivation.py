@@ -4,7 +4,7 @@ Unit tests for XPrv, XPub, and DerivationPath classes.
 
 import pytest
 
-from kaspa import XPrv, XPub, DerivationPath, PrivateKey, PublicKey
+from kaspa import XPrv, XPub, DerivationPath, PrivateKey, PublicKey, PublicKeyGenerator, derive_account
 from tests.conftest import TEST_MASTER_XPRV
 
 
@@ -27,6 +27,31 @@ class TestXPrvCreation:
         with pytest.raises(Exception):
             XPrv("invalid_seed")
 
+    def test_from_seed_bip32_test_vector(self):
+        """Test XPrv.from_seed() against BIP-32 test vector 1."""
+        seed = bytes.fromhex("000102030405060708090a0b0c0d0e0f")
+        xprv = XPrv.from_seed(seed)
+        assert xprv.into_string("xprv") == (
+            "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi"
+        )
+        assert xprv.to_string() == (
+            "kprv5y2qurMHCsXYqr9oKku3Ry75DcZgdraE3SFPPh1SKp4qKtr61qQeNypYTGztwUUiVauHWmjxaQXeUKHxj4QCuDG4ULpZHkvBoH9XX19ynXm"
+        )
+
+    def test_from_seed_matches_constructor(self, known_mnemonic):
+        """Test XPrv.from_seed() with hex and bytes matches XPrv(seed)."""
+        seed = known_mnemonic.to_seed()
+        expected = XPrv(seed).to_string()
+        assert XPrv.from_seed(seed).to_string() == expected
+        assert XPrv.from_seed(bytes.fromhex(seed)).to_string() == expected
+
+    def test_from_string_roundtrip_testnet_prefix(self, known_xprv_from_mnemonic):
+        """Test XPrv.from_string() accepts a ktrv-prefixed key."""
+        ktrv = known_xprv_from_mnemonic.into_string("ktrv")
+        xprv = XPrv.from_string(ktrv)
+        assert xprv.into_string("ktrv") == ktrv
+        assert xprv.to_string() == known_xprv_from_mnemonic.to_string()
+
 
 class TestXPrvProperties:
     """Tests for XPrv properties."""
@@ -274,3 +299,49 @@ class TestDerivationConsistency:
         addr2 = pubkey_from_xpub.to_address("mainnet")
 
         assert addr1.to_string() == addr2.to_string()
+
+
+class TestDeriveAccount:
+    """Tests for the derive_account one-shot helper."""
+
+    def test_known_first_addresses(self, known_mnemonic):
+        """Test the known mnemonic produces its known first addresses."""
+        account = derive_account(known_mnemonic, "mainnet")
+
+        assert account["derivation_path"] == "m/44'/111111'/0'"
+        assert account["receive_address"].to_string() == (
+            "kaspa:qpu92skxut9ltmj2pugjfnac6zs4npmjzqura2e4dfu29clqltu95p3tg2dr3"
+        )
+        assert account["change_address"].to_string() == (
+            "kaspa:qqrvtndmu2h68q6npvk9d3w89eq7vuulkasxp2t26z7098rpsvf4qvvexy53r"
+        )
+
+    def test_account_index_and_network(self, known_mnemonic):
+        """Test the account index and network type are applied."""
+        account = derive_account(known_mnemonic, "testnet", account_index=1)
+
+        assert account["derivation_path"] == "m/44'/111111'/1'"
+        assert account["receive_address"].to_string() == (
+            "kaspatest:qzuurkxy82y6jwgmfpyu8nj93g8f00qxhdt4fcnr2z4yf2xdp5seuy3f7dp38"
+        )
+
+    def test_matches_public_key_generator(self, known_mnemonic, known_xprv_from_mnemonic):
+        """Test the account keys match the step-by-step derivation."""
+        account = derive_account(known_mnemonic, "mainnet")
+        generator = PublicKeyGenerator.from_master_xprv(
+            known_xprv_from_mnemonic.to_string(), False, 0
+        )
+
+        assert isinstance(account["xprv"], XPrv)
+        assert isinstance(account["xpub"], XPub)
+        assert account["xprv"].to_xpub().into_string("kpub") == account["xpub"].into_string("kpub")
+        assert account["receive_address"].to_string() == generator.receive_address_as_string(
+            "mainnet", 0
+        )
+
+    def test_passphrase_changes_account(self, known_mnemonic):
+        """Test a passphrase derives a different account."""
+        plain = derive_account(known_mnemonic, "mainnet")
+        protected = derive_account(known_mnemonic, "mainnet", passphrase="secret")
+
+        assert plain["receive_address"].to_string() != protected["receive_address"].to_string()
@@ -94,6 +94,45 @@ class TestTransactionInputDict:
 
         assert original == restored
 
+    def test_input_from_dict_validate_standard(self):
+        """Test validation accepts an input within the standardness limits."""
+        outpoint = TransactionOutpoint(Hash("a" * 64), 5)
+        d = TransactionInput(outpoint, "ab" * 1650, 0, 15).to_dict()
+
+        assert TransactionInput.from_dict(d, validate=True) == TransactionInput.from_dict(d)
+
+    def test_input_from_dict_validate_sig_op_count(self):
+        """Test validation rejects an over-limit sig-op count on a P2SH spend."""
+        outpoint = TransactionOutpoint(Hash("a" * 64), 5)
+        utxo = UtxoEntryReference.from_dict({
+            "address": "kaspa:qr0lr4ml9fn3chekrqmjdkergxl93l4wrk3dankcgvjq776s9wn9jkdskewva",
+            "outpoint": {"transactionId": "b" * 64, "index": 0},
+            "amount": 100000000,
+            "scriptPublicKey": {"version": 0, "script": "aa20" + "cd" * 32 + "87"},
+            "blockDaaScore": 0,
+            "isCoinbase": False,
+        })
+        d = TransactionInput(outpoint, "deadbeef", 0, 16, utxo).to_dict()
+
+        assert TransactionInput.from_dict(d).sig_op_count == 16
+        with pytest.raises(ValueError, match="Sig-op count 16"):
+            TransactionInput.from_dict(d, validate=True)
+
+    def test_input_from_dict_validate_sig_op_count_non_p2sh(self):
+        """Test the P2SH sig-op limit is not applied to other inputs."""
+        outpoint = TransactionOutpoint(Hash("a" * 64), 5)
+        d = TransactionInput(outpoint, "deadbeef", 0, 16).to_dict()
+
+        assert TransactionInput.from_dict(d, validate=True).sig_op_count == 16
+
+    def test_input_from_dict_validate_signature_script_size(self):
+        """Test validation rejects an oversized signature script."""
+        outpoint = TransactionOutpoint(Hash("a" * 64), 5)
+        d = TransactionInput(outpoint, "ab" * 1651, 0, 1).to_dict()
+
+        with pytest.raises(ValueError, match="1651 bytes"):
+            TransactionInput.from_dict(d, validate=True)
+
 
 class TestTransactionDict:
     """Tests for Transaction to_dict/from_dict methods."""
@@ -251,3 +290,44 @@ class TestUtxoEntryReferenceDict:
         restored = UtxoEntryReference.from_dict(d)
 
         assert original == restored
+
+
+class TestScriptPublicKeyVersionRange:
+    """Tests for out-of-range scriptPublicKey versions in from_dict."""
+
+    SCRIPT = "20852be1b87fca94453a35027c550a3ccdbebb5913106029f3a8bf18152bf93bffac"
+
+    def _utxo_dict(self, version):
+        return {
+            "outpoint": {"transactionId": "a" * 64, "index": 0},
+            "amount": 1000000,
+            "scriptPublicKey": {"version": version, "script": self.SCRIPT},
+            "blockDaaScore": 12345,
+            "isCoinbase": False,
+        }
+
+    @pytest.mark.parametrize("version", [2**64, 65536, -1])
+    def test_output_version_out_of_range(self, version):
+        """Test TransactionOutput.from_dict names the field in the error."""
+        d = {"value": 1000000, "scriptPublicKey": {"version": version, "script": "51"}}
+
+        with pytest.raises(ValueError, match=rf"`scriptPublicKey.version` must be between 0 and 65535, got {version}"):
+            TransactionOutput.from_dict(d)
+
+    @pytest.mark.parametrize("version", [2**64, 65536, -1])
+    def test_utxo_entry_version_out_of_range(self, version):
+        """Test UtxoEntry.from_dict names the field in the error."""
+        with pytest.raises(ValueError, match=r"`scriptPublicKey.version` must be between 0 and 65535"):
+            UtxoEntry.from_dict(self._utxo_dict(version))
+
+    @pytest.mark.parametrize("version", [2**64, 65536, -1])
+    def test_utxo_entry_reference_version_out_of_range(self, version):
+        """Test UtxoEntryReference.from_dict names the field in the error."""
+        with pytest.raises(ValueError, match=r"`scriptPublicKey.version` must be between 0 and 65535"):
+            UtxoEntryReference.from_dict(self._utxo_dict(version))
+
+    def test_max_version_accepted(self):
+        """Test the largest u16 version is still accepted."""
+        entry = UtxoEntry.from_dict(self._utxo_dict(65535))
+
+        assert entry.script_public_key == ScriptPublicKey(65535, self.SCRIPT)
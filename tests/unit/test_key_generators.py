@@ -2,12 +2,15 @@
 Unit tests for PublicKeyGenerator and PrivateKeyGenerator classes.
 """
 
+import pytest
+
 from kaspa import (
     PublicKeyGenerator,
     PrivateKeyGenerator,
     PublicKey,
     PrivateKey,
     Address,
+    XPrv,
 )
 from tests.conftest import TEST_MASTER_XPRV
 
@@ -151,6 +154,60 @@ class TestPublicKeyGeneratorReceiveAddresses:
             assert addr_str.startswith("kaspa:")
 
 
+class TestPublicKeyGeneratorParallelAddresses:
+    """Tests for PublicKeyGenerator parallel address derivation."""
+
+    def test_parallel_matches_serial(self):
+        """Test that parallel derivation matches serial receive_addresses output."""
+        pubkey_gen = PublicKeyGenerator.from_master_xprv(
+            TEST_MASTER_XPRV,
+            is_multisig=False,
+            account_index=0
+        )
+
+        serial = pubkey_gen.receive_addresses("mainnet", 0, 1000)
+        parallel = pubkey_gen.receive_addresses_parallel("mainnet", 0, 1000)
+
+        assert [a.to_string() for a in parallel] == [a.to_string() for a in serial]
+
+    def test_parallel_with_explicit_threads(self):
+        """Test parallel derivation with an explicit thread count and offset range."""
+        pubkey_gen = PublicKeyGenerator.from_master_xprv(
+            TEST_MASTER_XPRV,
+            is_multisig=False,
+            account_index=1
+        )
+
+        serial = pubkey_gen.receive_addresses("testnet", 250, 500)
+        parallel = pubkey_gen.receive_addresses_parallel("testnet", 250, 500, threads=3)
+
+        assert [a.to_string() for a in parallel] == [a.to_string() for a in serial]
+
+    def test_parallel_swapped_range(self):
+        """Test that a reversed range is normalized like the serial method."""
+        pubkey_gen = PublicKeyGenerator.from_master_xprv(
+            TEST_MASTER_XPRV,
+            is_multisig=False,
+            account_index=0
+        )
+
+        parallel = pubkey_gen.receive_addresses_parallel("mainnet", 20, 10)
+        serial = pubkey_gen.receive_addresses("mainnet", 10, 20)
+
+        assert [a.to_string() for a in parallel] == [a.to_string() for a in serial]
+
+    def test_parallel_zero_threads_raises(self):
+        """Test that a thread count of 0 raises ValueError."""
+        pubkey_gen = PublicKeyGenerator.from_master_xprv(
+            TEST_MASTER_XPRV,
+            is_multisig=False,
+            account_index=0
+        )
+
+        with pytest.raises(ValueError):
+            pubkey_gen.receive_addresses_parallel("mainnet", 0, 10, threads=0)
+
+
 class TestPublicKeyGeneratorChangeKeys:
     """Tests for PublicKeyGenerator change key generation."""
 
@@ -225,6 +282,46 @@ class TestPublicKeyGeneratorDifferentNetworks:
         assert address.prefix == "kaspatest"
 
 
+class TestPublicKeyGeneratorDerivePath:
+    """Tests for PublicKeyGenerator path derivation."""
+
+    def test_derive_pubkey_non_hardened_path(self):
+        """Test deriving a public key along a non-hardened path."""
+        pubkey_gen = PublicKeyGenerator.from_master_xprv(
+            TEST_MASTER_XPRV,
+            is_multisig=False,
+            account_index=0
+        )
+
+        pubkey = pubkey_gen.derive_pubkey("m/0/3")
+        assert isinstance(pubkey, PublicKey)
+        assert pubkey.to_string() == pubkey_gen.receive_pubkey(3).to_string()
+
+    def test_derive_pubkey_hardened_path_raises(self):
+        """Test that a hardened path component is rejected."""
+        pubkey_gen = PublicKeyGenerator.from_master_xprv(
+            TEST_MASTER_XPRV,
+            is_multisig=False,
+            account_index=0
+        )
+
+        with pytest.raises(Exception, match="path component 1"):
+            pubkey_gen.derive_pubkey("m/1/0'")
+
+    def test_hardened_index_raises(self):
+        """Test that hardened indexes are rejected with the offending index."""
+        pubkey_gen = PublicKeyGenerator.from_master_xprv(
+            TEST_MASTER_XPRV,
+            is_multisig=False,
+            account_index=0
+        )
+
+        with pytest.raises(Exception, match="index 2147483648"):
+            pubkey_gen.receive_pubkey(2**31)
+        with pytest.raises(Exception, match="index 2147483648"):
+            pubkey_gen.change_pubkeys(2**31 - 1, 2**31 + 1)
+
+
 class TestPublicKeyGeneratorToString:
     """Tests for PublicKeyGenerator serialization."""
 
@@ -342,6 +439,43 @@ class TestPrivateKeyGeneratorKeys:
         assert key0.to_string() != key1.to_string()
 
 
+class TestPrivateKeyGeneratorScan:
+    """Benchmark-style tests for scanning many indices with PrivateKeyGenerator."""
+
+    SCAN_COUNT = 500
+
+    def test_scan_matches_single_shot_derivation(self):
+        """Test that 500 cached derivations match full-path derivation."""
+        privkey_gen = PrivateKeyGenerator(
+            xprv=TEST_MASTER_XPRV,
+            is_multisig=False,
+            account_index=0
+        )
+        xprv = XPrv.from_xprv(TEST_MASTER_XPRV)
+
+        cached = [privkey_gen.receive_key(i).to_string() for i in range(self.SCAN_COUNT)]
+        single_shot = [
+            xprv.derive_path(f"m/44'/111111'/0'/0/{i}").to_private_key().to_string()
+            for i in range(self.SCAN_COUNT)
+        ]
+
+        assert cached == single_shot
+        assert len(set(cached)) == self.SCAN_COUNT
+
+    def test_change_scan_matches_single_shot_derivation(self):
+        """Test that cached change key derivation matches full-path derivation."""
+        privkey_gen = PrivateKeyGenerator(
+            xprv=TEST_MASTER_XPRV,
+            is_multisig=False,
+            account_index=2
+        )
+        xprv = XPrv.from_xprv(TEST_MASTER_XPRV)
+
+        for i in range(0, self.SCAN_COUNT, 50):
+            expected = xprv.derive_path(f"m/44'/111111'/2'/1/{i}").to_private_key()
+            assert privkey_gen.change_key(i).to_string() == expected.to_string()
+
+
 class TestKeyGeneratorConsistency:
     """Tests for consistency between PublicKeyGenerator and PrivateKeyGenerator."""
 
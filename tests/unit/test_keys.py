@@ -157,6 +157,29 @@ class TestKeypairProperties:
         assert isinstance(xonly, str)
 
 
+class TestKeypairExport:
+    """Tests for Keypair.export()."""
+
+    def test_export_bundle(self, known_keypair):
+        """Test that the export bundle matches the individual accessors."""
+        bundle = known_keypair.export("mainnet")
+        assert bundle["private_key"] == known_keypair.private_key
+        assert bundle["public_key"] == known_keypair.public_key
+        assert bundle["xonly_public_key"] == known_keypair.xonly_public_key
+        assert bundle["address"] == known_keypair.to_address("mainnet").to_string()
+
+    def test_export_wif(self, known_keypair):
+        """Test the WIF encoding of a known private key."""
+        bundle = known_keypair.export("mainnet")
+        assert bundle["wif"] == "L3P9ePHYrQLegLDX5Cqe9HtdpxZc1QMyFFZRDR7Ny5ArbPcTZrnj"
+
+    def test_export_testnet(self, known_keypair):
+        """Test exporting for testnet."""
+        bundle = known_keypair.export("testnet")
+        assert bundle["address"].startswith("kaspatest:")
+        assert bundle["wif"] != known_keypair.export("mainnet")["wif"]
+
+
 class TestKeyConsistency:
     """Tests for consistency between different key representations."""
 
@@ -57,6 +57,18 @@ class TestMnemonicValidation:
         """Test that validate() returns False for an invalid phrase."""
         assert Mnemonic.validate("invalid phrase") is False
 
+    def test_validate_rejects_bad_checksum(self):
+        """Test that swapping a single word breaks the checksum."""
+        valid = " ".join(["abandon"] * 11 + ["about"])
+        swapped = " ".join(["abandon"] * 12)
+        assert Mnemonic.validate(valid) is True
+        assert Mnemonic.validate(swapped) is False
+
+    def test_validate_rejects_bad_word_count(self):
+        """Test that an unsupported word count is rejected."""
+        phrase = " ".join(TEST_MNEMONIC_PHRASE.split()[:23])
+        assert Mnemonic.validate(phrase) is False
+
     def test_validate_with_language_parameter(self):
         """Test validate() with explicit Language parameter."""
         # English is the default/only supported language
@@ -139,3 +151,25 @@ class TestMnemonicDeterminism:
         # Generate again and verify consistency
         seed2 = known_mnemonic.to_seed()
         assert seed == seed2
+
+
+class TestLanguageWordList:
+    """Tests for Language word list access."""
+
+    def test_english_word_list(self):
+        """Test the English word list has 2048 entries starting with 'abandon'."""
+        words = Language.English.word_list()
+        assert len(words) == 2048
+        assert words[0] == "abandon"
+        assert words[-1] == "zoo"
+
+    def test_word_at(self):
+        """Test word_at returns words by index and None when out of range."""
+        assert Language.English.word_at(0) == "abandon"
+        assert Language.English.word_at(2047) == "zoo"
+        assert Language.English.word_at(2048) is None
+
+    def test_word_list_matches_mnemonic_words(self, known_mnemonic):
+        """Test every word of a valid phrase is in the word list."""
+        words = set(Language.English.word_list())
+        assert all(word in words for word in known_mnemonic.phrase.split())
@@ -0,0 +1,68 @@
+"""
+Unit tests for NetworkId and NetworkType.
+"""
+
+from kaspa import NetworkId, NetworkType, known_networks
+
+
+class TestNetworkIdEquality:
+    """Tests for NetworkId comparison against NetworkId, str, and NetworkType."""
+
+    def test_equal_to_network_id(self):
+        """Test NetworkId instances compare by value."""
+        assert NetworkId("testnet-10") == NetworkId("testnet-10")
+        assert NetworkId("testnet-10") != NetworkId("testnet-11")
+        assert NetworkId("mainnet") == NetworkId(NetworkType.Mainnet)
+
+    def test_equal_to_string(self):
+        """Test NetworkId compares equal to its string form."""
+        assert NetworkId("testnet-10") == "testnet-10"
+        assert NetworkId("mainnet") == "mainnet"
+        assert NetworkId("testnet-10") != "testnet-11"
+        assert NetworkId("testnet-10") != "mainnet"
+
+    def test_equal_to_network_type(self):
+        """Test NetworkId compares equal to its NetworkType regardless of suffix."""
+        assert NetworkId("mainnet") == NetworkType.Mainnet
+        assert NetworkId("testnet-10") == NetworkType.Testnet
+        assert NetworkId("testnet-10") != NetworkType.Mainnet
+
+    def test_not_equal_to_other_values(self):
+        """Test invalid strings and unrelated types compare unequal."""
+        assert NetworkId("mainnet") != "not-a-network"
+        assert NetworkId("mainnet") != 1
+        assert NetworkId("mainnet") != None  # noqa: E711
+
+    def test_unsupported_types_defer_to_other_operand(self):
+        """Test unsupported types fall back to the other operand's comparison."""
+
+        class MatchesEverything:
+            def __eq__(self, other):
+                return True
+
+        assert NetworkId("mainnet") == MatchesEverything()
+        assert MatchesEverything() == NetworkId("mainnet")
+
+
+class TestKnownNetworks:
+    """Tests for known_networks."""
+
+    def test_contains_mainnet_and_testnet(self):
+        """Test mainnet and at least one testnet are listed."""
+        networks = [network.to_string() for network in known_networks()]
+        assert "mainnet" in networks
+        assert any(network.startswith("testnet-") for network in networks)
+
+    def test_excludes_local_networks(self):
+        """Test devnet and simnet are not listed."""
+        networks = [network.to_string() for network in known_networks()]
+        assert networks[0] == "mainnet"
+        assert all(network.startswith("testnet-") for network in networks[1:])
+
+    def test_entries_round_trip(self):
+        """Test each entry round-trips through its string form."""
+        networks = known_networks()
+        assert len(networks) == len(set(network.to_string() for network in networks))
+        for network in networks:
+            assert isinstance(network, NetworkId)
+            assert NetworkId(network.to_string()) == network
@@ -0,0 +1,83 @@
+"""
+Unit tests for NotificationEvent.
+"""
+
+import pytest
+
+from kaspa import NotificationEvent
+
+
+EVENT_KINDS = [
+    (NotificationEvent.All, "All"),
+    (NotificationEvent.BlockAdded, "BlockAdded"),
+    (NotificationEvent.VirtualChainChanged, "VirtualChainChanged"),
+    (NotificationEvent.FinalityConflict, "FinalityConflict"),
+    (NotificationEvent.FinalityConflictResolved, "FinalityConflictResolved"),
+    (NotificationEvent.UtxosChanged, "UtxosChanged"),
+    (NotificationEvent.SinkBlueScoreChanged, "SinkBlueScoreChanged"),
+    (NotificationEvent.VirtualDaaScoreChanged, "VirtualDaaScoreChanged"),
+    (NotificationEvent.PruningPointUtxoSetOverride, "PruningPointUtxoSetOverride"),
+    (NotificationEvent.NewBlockTemplate, "NewBlockTemplate"),
+]
+
+CONTROL_EVENTS = [NotificationEvent.Connect, NotificationEvent.Disconnect]
+
+
+class TestNotificationEventKind:
+    """Tests for NotificationEvent.kind and is_control."""
+
+    @pytest.mark.parametrize("event,kind", EVENT_KINDS)
+    def test_kind(self, event, kind):
+        """Test each notification variant reports the event dict's type name."""
+        assert event.kind == kind
+
+    def test_kinds_are_unique(self):
+        """Test a callback can branch on kind without ambiguity."""
+        events = [event for event, _ in EVENT_KINDS] + CONTROL_EVENTS
+        kinds = [event.kind for event in events]
+        assert all(kinds)
+        assert len(set(kinds)) == len(kinds)
+
+    @pytest.mark.parametrize("event", [event for event, _ in EVENT_KINDS] + CONTROL_EVENTS)
+    def test_is_control(self, event):
+        """Test only Connect and Disconnect are connection events."""
+        assert event.is_control is (event in CONTROL_EVENTS)
+
+
+class TestNotificationEventPayload:
+    """Tests for the per-kind payload accessors."""
+
+    def test_as_utxos_changed(self):
+        """Test a UtxosChanged event dict yields its added and removed entries."""
+        event = {
+            "type": NotificationEvent.UtxosChanged.kind,
+            "event": NotificationEvent.UtxosChanged,
+            "added": [{"amount": 1}],
+            "removed": [],
+        }
+
+        assert NotificationEvent.as_utxos_changed(event) == {
+            "added": [{"amount": 1}],
+            "removed": [],
+        }
+        assert NotificationEvent.as_block_added(event) is None
+
+    def test_as_block_added(self):
+        """Test a BlockAdded event dict yields its notification data."""
+        data = {"block": {"header": {}}}
+        event = {
+            "type": NotificationEvent.BlockAdded.kind,
+            "event": NotificationEvent.BlockAdded,
+            "data": data,
+        }
+
+        assert NotificationEvent.as_block_added(event) == data
+        assert NotificationEvent.as_utxos_changed(event) is None
+
+    def test_unrelated_event(self):
+        """Test accessors return None for other events and dicts without an event."""
+        event = {"type": NotificationEvent.Connect.kind, "event": NotificationEvent.Connect}
+
+        assert NotificationEvent.as_utxos_changed(event) is None
+        assert NotificationEvent.as_block_added(event) is None
+        assert NotificationEvent.as_block_added({}) is None
@@ -8,9 +8,14 @@ from kaspa import (
     ScriptBuilder,
     Opcodes,
     ScriptPublicKey,
+    ScriptHash,
     Address,
     pay_to_script_hash_script,
     pay_to_script_hash_signature_script,
+    create_data_script,
+    create_lock_time_script,
+    create_sequence_lock_script,
+    address_from_script_public_key,
     is_script_pay_to_pubkey,
     is_script_pay_to_pubkey_ecdsa,
     is_script_pay_to_script_hash,
@@ -122,6 +127,38 @@ class TestScriptBuilderOutput:
         script = builder.drain()
         assert isinstance(script, str)
 
+    def test_drain_returns_hex_and_clears(self):
+        """Test draining returns hex for non-UTF-8 bytes and empties the builder."""
+        builder = ScriptBuilder()
+        builder.add_ops([Opcodes.OpDup, Opcodes.OpCheckSig])
+
+        assert builder.drain() == "76ac"
+        assert len(builder) == 0
+
+    def test_to_bytes_and_len(self):
+        """Test script bytes and length match the expected opcode sequence."""
+        builder = ScriptBuilder()
+        builder.add_op(Opcodes.OpDup)
+        builder.add_data("deadbeef")
+        builder.add_op(Opcodes.OpCheckSig)
+
+        assert builder.to_bytes() == bytes([0x76, 0x04, 0xDE, 0xAD, 0xBE, 0xEF, 0xAC])
+        assert len(builder) == 7
+        assert builder.to_bytes().hex() == builder.to_string()
+
+    def test_exceeding_max_script_size_raises(self):
+        """Test that growing a script beyond 10,000 bytes raises."""
+        builder = ScriptBuilder()
+        with pytest.raises(Exception):
+            for _ in range(25):
+                builder.add_data(bytes(500))
+        assert len(builder) <= 10_000
+
+    def test_from_script_exceeding_max_size_raises(self):
+        """Test that from_script rejects scripts over the maximum size."""
+        with pytest.raises(Exception, match="exceeds the maximum"):
+            ScriptBuilder.from_script(bytes(10_001))
+
 
 class TestScriptBuilderP2SH:
     """Tests for ScriptBuilder P2SH (Pay-to-Script-Hash) functionality."""
@@ -219,6 +256,89 @@ class TestScriptHelperFunctions:
         result = pay_to_script_hash_signature_script(redeem_script, signature)
         assert isinstance(result, str)
 
+    def test_create_data_script(self):
+        """Test that a data carrier script is OP_RETURN followed by the data."""
+        data = b"commitment"
+        spk = create_data_script(data)
+
+        assert isinstance(spk, ScriptPublicKey)
+        assert spk.script == "6a" + "0a" + data.hex()
+
+    def test_create_data_script_is_nonstandard(self):
+        """Test that a data carrier script matches no spendable script class."""
+        spk = create_data_script("deadbeef")
+
+        assert not is_script_pay_to_pubkey(spk.script)
+        assert not is_script_pay_to_pubkey_ecdsa(spk.script)
+        assert not is_script_pay_to_script_hash(spk.script)
+        with pytest.raises(Exception):
+            address_from_script_public_key(spk, "mainnet")
+
+    def test_create_data_script_too_large_raises(self):
+        """Test that data beyond the maximum element size is rejected."""
+        with pytest.raises(Exception):
+            create_data_script(bytes(521))
+
+
+class TestScriptHash:
+    """Tests for the ScriptHash P2SH helper."""
+
+    def test_script_public_key_matches_helper(self):
+        """Test the locking script matches pay_to_script_hash_script."""
+        script_hash = ScriptHash("51")
+
+        assert script_hash.redeem_script == "51"
+        assert script_hash.script_public_key == pay_to_script_hash_script("51")
+        assert is_script_pay_to_script_hash(script_hash.script_public_key.script)
+
+    def test_address(self):
+        """Test the P2SH address for a redeem script."""
+        script_hash = ScriptHash(bytes([0x51]))
+        address = script_hash.address("testnet")
+
+        assert isinstance(address, Address)
+        assert address.version == "ScriptHash"
+        assert address.prefix == "kaspatest"
+        assert address_from_script_public_key(
+            script_hash.script_public_key, "testnet"
+        ).to_string() == address.to_string()
+
+    def test_signature_script(self):
+        """Test signatures are pushed in order ahead of the redeem script."""
+        script_hash = ScriptHash("51")
+        sig1 = "aa" * 65
+        sig2 = "bb" * 65
+
+        script = script_hash.signature_script([sig1, sig2])
+        assert script == "41" + sig1 + "41" + sig2 + "0151"
+
+
+class TestTimeLockScripts:
+    """Tests for CLTV/CSV time-lock script helpers."""
+
+    def test_create_lock_time_script(self):
+        """Test the lock-time push and CLTV opcode precede the redeem script."""
+        redeem_script = ScriptBuilder().add_ops([Opcodes.OpDup, Opcodes.OpCheckSig]).to_string()
+        script = create_lock_time_script(1000, redeem_script)
+
+        lock_time_push = "02e803"
+        assert script == lock_time_push + f"{Opcodes.OpCheckLockTimeVerify.value:02x}" + redeem_script
+
+    def test_create_sequence_lock_script(self):
+        """Test the sequence push and CSV opcode precede the redeem script."""
+        script = create_sequence_lock_script(1000, "51")
+
+        assert script == "02e803" + f"{Opcodes.OpCheckSequenceVerify.value:02x}" + "51"
+
+    def test_lock_time_script_matches_builder(self):
+        """Test the helper matches a hand-assembled ScriptBuilder script."""
+        builder = ScriptBuilder()
+        builder.add_lock_time(123456)
+        builder.add_op(Opcodes.OpCheckLockTimeVerify)
+        builder.add_op(Opcodes.OpTrue)
+
+        assert create_lock_time_script(123456, "51") == builder.to_string()
+
 
 class TestScriptTypeDetection:
     """Tests for script type detection functions."""
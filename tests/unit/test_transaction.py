@@ -2,6 +2,8 @@
 Unit tests for Transaction creation, signing, and related functionality.
 """
 
+from concurrent.futures import ThreadPoolExecutor
+
 import pytest
 
 from kaspa import (
@@ -10,25 +12,49 @@ from kaspa import (
     TransactionOutput,
     TransactionOutpoint,
     ScriptPublicKey,
+    SubnetworkId,
     UtxoEntry,
     UtxoEntries,
     UtxoEntryReference,
     PrivateKey,
+    Keypair,
     Address,
     Generator,
+    InsufficientFundsError,
     PaymentOutput,
     Hash,
+    NetworkId,
     sign_transaction,
+    sign_transactions,
     create_input_signature,
     create_transaction,
     create_transactions,
     estimate_transactions,
     calculate_transaction_mass,
     calculate_transaction_fee,
+    calculate_storage_mass,
+    update_transaction_mass,
     maximum_standard_transaction_mass,
+    fee_for_mass,
+    fee_rate_for_fee,
+    minimum_relay_fee,
     SighashType,
+    sompi_to_kaspa_string,
+    signature_to_compact,
+    signature_from_compact,
+    transaction_diff,
+    verify_script,
+    pay_to_address_script,
+    select_coins,
+    bump_fee,
+    attach_utxos,
+    pay_to_script_hash_script,
+    check_transaction_standard,
+    NonStandardTransactionError,
 )
 
+from tests.helpers import TEST_ADDRESS, TEST_SCRIPT, make_transaction, make_utxo_entries
+
 
 class TestTransactionOutpoint:
     """Tests for TransactionOutpoint class."""
@@ -87,6 +113,38 @@ class TestScriptPublicKey:
         script = spk.script
         assert isinstance(script, str)
 
+    def test_script_public_key_from_combined(self):
+        """Test round-tripping the combined version-prefixed hex form."""
+        combined = "0000" + TEST_SCRIPT
+        spk = ScriptPublicKey.from_combined(combined)
+
+        assert spk == ScriptPublicKey(0, TEST_SCRIPT)
+        assert "0000" + spk.script == combined
+        assert ScriptPublicKey.from_combined(bytes.fromhex(combined)) == spk
+
+        # Matches the combined form accepted from RPC-style UTXO dicts
+        reference = UtxoEntryReference.from_dict({
+            "outpoint": {"transactionId": "01" * 32, "index": 0},
+            "amount": 1,
+            "scriptPublicKey": combined,
+            "blockDaaScore": 0,
+            "isCoinbase": False,
+        })
+        assert reference.script_public_key == spk
+
+    def test_script_public_key_from_combined_version(self):
+        """Test the version prefix is parsed big-endian."""
+        spk = ScriptPublicKey.from_combined("0102" + "51")
+
+        assert spk == ScriptPublicKey(0x0102, "51")
+        assert spk != ScriptPublicKey(0x0201, "51")
+
+    @pytest.mark.parametrize("data", ["", "00", b"\x00"])
+    def test_script_public_key_from_combined_too_short(self, data):
+        """Test data without a full version prefix raises ValueError."""
+        with pytest.raises(ValueError, match="at least 2 bytes"):
+            ScriptPublicKey.from_combined(data)
+
 
 class TestTransactionOutput:
     """Tests for TransactionOutput class."""
@@ -208,6 +266,557 @@ class TestTransaction:
         # (coinbase transactions have specific subnetwork_id)
         assert isinstance(tx.is_coinbase(), bool)
 
+    def test_transaction_id_updates_after_output_change(self):
+        """Test reading id after changing outputs returns the updated id."""
+        tx = make_transaction()
+        original_id = tx.id
+
+        outputs = tx.outputs
+        outputs[0].value += 1
+        tx.outputs = outputs
+
+        assert tx.id != original_id
+        assert tx.id == tx.finalize().to_string()
+
+    def test_transaction_id_updates_after_element_change(self):
+        """Test reading id after editing an output in place returns the updated id."""
+        tx = make_transaction()
+        original_id = tx.id
+
+        tx.outputs[0].value += 1
+
+        assert tx.id != original_id
+        assert tx.id == tx.finalize().to_string()
+
+    def test_transaction_id_updates_after_setters(self):
+        """Test reading id after other mutating setters returns the updated id."""
+        tx = make_transaction()
+        seen = {tx.id}
+
+        tx.payload = "deadbeef"
+        seen.add(tx.id)
+        tx.lock_time = 100
+        seen.add(tx.id)
+        tx.version = 1
+        seen.add(tx.id)
+
+        assert len(seen) == 4
+        assert tx.id == tx.finalize().to_string()
+        assert tx.to_dict()["id"] == tx.id
+
+    def test_transaction_id_shared_between_wrappers(self):
+        """Test a change through one wrapper updates the id read through another."""
+        result = create_transactions(
+            network_id="mainnet",
+            entries=make_utxo_entries([1_000_000_000]),
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": TEST_ADDRESS, "amount": 100_000_000}],
+        )
+        pending = result["transactions"][0]
+        first, second = pending.transaction, pending.transaction
+        original_id = second.id
+
+        first.payload = "deadbeef"
+
+        assert second.id != original_id
+        assert second.id == first.id == pending.id
+
+    def test_transaction_equality_ignores_finalization_order(self):
+        """Test identical transactions are equal regardless of stored ids."""
+        tx1 = Transaction(
+            0,
+            [TransactionInput(TransactionOutpoint(Hash("0" * 64), 0), "", 0, 1)],
+            [TransactionOutput(1000000, ScriptPublicKey(0, "51"))],
+            0,
+            "0" * 40,
+            0,
+            "",
+            0,
+        )
+        data = tx1.to_dict()
+        data["id"] = "ab" * 32
+        tx2 = Transaction.from_dict(data)
+
+        assert tx2.id != tx1.id
+        assert tx1 == tx2
+
+        tx1.payload = "cafe"
+        tx2.payload = "cafe"
+        first = tx2.finalize()
+        assert tx1 == tx2
+        assert tx1.finalize() == first
+        assert tx1.id == tx2.id
+
+    @pytest.mark.parametrize("length", [19, 21])
+    def test_transaction_subnetwork_id_wrong_length(self, length):
+        """Test constructor and setter errors report expected and actual lengths."""
+        outpoint = TransactionOutpoint(Hash("0" * 64), 0)
+        input = TransactionInput(outpoint, "", 0, 1)
+        output = TransactionOutput(1000000, ScriptPublicKey(0, "51"))
+        subnetwork_id = "00" * length
+
+        with pytest.raises(ValueError, match=rf"20 bytes, got {length} bytes"):
+            Transaction(0, [input], [output], 0, subnetwork_id, 0, "", 0)
+
+        tx = Transaction(0, [input], [output], 0, "0" * 40, 0, "", 0)
+        with pytest.raises(ValueError, match=rf"20 bytes, got {length} bytes"):
+            tx.subnetwork_id = subnetwork_id
+
+        data = tx.to_dict()
+        data["subnetworkId"] = subnetwork_id
+        with pytest.raises(ValueError, match=rf"20 bytes, got {length} bytes"):
+            Transaction.from_dict(data)
+
+    def test_transaction_payload_bytes(self):
+        """Test payload_bytes matches the hex payload decoded to bytes."""
+        outpoint = TransactionOutpoint(Hash("0" * 64), 0)
+        input = TransactionInput(outpoint, "", 0, 1)
+        output = TransactionOutput(1000000, ScriptPublicKey(0, "51"))
+        payload = bytes(range(256)) * 16
+
+        tx = Transaction(0, [input], [output], 0, "0" * 40, 0, payload, 0)
+
+        assert isinstance(tx.payload_bytes, bytes)
+        assert tx.payload_bytes == bytes.fromhex(tx.payload)
+        assert tx.payload_bytes == payload
+
+    def test_transaction_empty_payload_bytes(self):
+        """Test payload_bytes is empty for a transaction without payload."""
+        outpoint = TransactionOutpoint(Hash("0" * 64), 0)
+        input = TransactionInput(outpoint, "", 0, 1)
+        output = TransactionOutput(1000000, ScriptPublicKey(0, "51"))
+
+        tx = Transaction(0, [input], [output], 0, "0" * 40, 0, "", 0)
+
+        assert tx.payload_bytes == b""
+
+    def test_transaction_outpoint_for_output(self):
+        """Test deriving the outpoint of an output to chain a follow-up spend."""
+        tx = make_transaction(outputs=[
+            {"address": TEST_ADDRESS, "amount": 500_000_000},
+            {"address": TEST_ADDRESS, "amount": 400_000_000},
+        ])
+
+        outpoint = tx.outpoint_for_output(0)
+        assert outpoint.transaction_id == tx.id
+        assert outpoint.index == 0
+        assert tx.outpoint_for_output(1) == TransactionOutpoint(Hash(tx.id), 1)
+
+        with pytest.raises(IndexError):
+            tx.outpoint_for_output(2)
+
+    def test_transaction_outpoint_for_output_after_change(self):
+        """Test the outpoint uses the id recomputed after a setter."""
+        tx = make_transaction()
+        original_id = tx.id
+
+        tx.lock_time = 1
+        outpoint = tx.outpoint_for_output(0)
+
+        assert outpoint.transaction_id != original_id
+        assert outpoint.transaction_id == tx.id
+
+    def test_set_input_utxo_enables_signing(self, known_private_key):
+        """Test attaching UTXOs to a transaction loaded without them allows signing."""
+        entries = make_utxo_entries([1_000_000_000, 500_000_000])
+        tx_dict = create_transaction(
+            entries, [{"address": TEST_ADDRESS, "amount": 1_200_000_000}], 0, network_id="mainnet"
+        ).to_dict()
+        for input in tx_dict["inputs"]:
+            input["utxo"] = None
+        tx = Transaction.from_dict(tx_dict)
+        assert all(input.utxo is None for input in tx.inputs)
+
+        original_id = tx.id
+        for index, entry in enumerate(entries):
+            tx.set_input_utxo(index, entry)
+
+        assert tx.id == original_id
+        signed = sign_transaction(tx, [known_private_key], True)
+        assert signed.verify_signatures() is True
+
+    def test_set_input_utxo_rejects_mismatch(self):
+        """Test set_input_utxo validates the index and the UTXO outpoint."""
+        entries = make_utxo_entries([1_000_000_000, 500_000_000])
+        tx = create_transaction(entries, [{"address": TEST_ADDRESS, "amount": 1_200_000_000}], 0, network_id="mainnet")
+
+        with pytest.raises(IndexError):
+            tx.set_input_utxo(2, entries[0])
+        with pytest.raises(ValueError, match="outpoint"):
+            tx.set_input_utxo(0, entries[1])
+
+    def test_attach_utxos_by_outpoint(self, known_private_key):
+        """Test attach_utxos matches entries to inputs by outpoint, in any order."""
+        entries = make_utxo_entries([1_000_000_000, 500_000_000])
+        tx_dict = create_transaction(
+            entries, [{"address": TEST_ADDRESS, "amount": 1_200_000_000}], 0, network_id="mainnet"
+        ).to_dict()
+        for input in tx_dict["inputs"]:
+            input["utxo"] = None
+        tx = Transaction.from_dict(tx_dict)
+
+        attach_utxos(tx, list(reversed(entries)))
+
+        assert all(input.utxo.outpoint == input.previous_outpoint for input in tx.inputs)
+        signed = sign_transaction(tx, [known_private_key], True)
+        assert signed.verify_signatures() is True
+
+    def test_attach_utxos_unmatched_input_raises(self):
+        """Test a missing UTXO raises and leaves the transaction unchanged."""
+        entries = make_utxo_entries([1_000_000_000, 500_000_000])
+        tx_dict = create_transaction(
+            entries, [{"address": TEST_ADDRESS, "amount": 1_200_000_000}], 0, network_id="mainnet"
+        ).to_dict()
+        for input in tx_dict["inputs"]:
+            input["utxo"] = None
+        tx = Transaction.from_dict(tx_dict)
+
+        with pytest.raises(ValueError, match="input 1"):
+            attach_utxos(tx, entries[:1])
+        assert all(input.utxo is None for input in tx.inputs)
+
+
+class TestSubnetworkId:
+    """Tests for SubnetworkId."""
+
+    def _transaction(self, subnetwork_id):
+        outpoint = TransactionOutpoint(Hash("0" * 64), 0)
+        input = TransactionInput(outpoint, "", 0, 1)
+        output = TransactionOutput(1000000, ScriptPublicKey(0, "51"))
+        return Transaction(0, [input], [output], 0, subnetwork_id, 0, "", 0)
+
+    def test_coinbase_matches_coinbase_transaction(self):
+        """Test SubnetworkId.coinbase() matches a coinbase transaction's subnetwork."""
+        tx = self._transaction(bytes(SubnetworkId.coinbase()))
+
+        assert tx.is_coinbase() is True
+        assert SubnetworkId.from_hex(tx.subnetwork) == SubnetworkId.coinbase()
+
+    def test_native_is_not_coinbase(self):
+        """Test the native subnetwork is all zeros and not coinbase."""
+        native = SubnetworkId.native()
+        tx = self._transaction(str(native))
+
+        assert str(native) == "00" * 20
+        assert native.to_string() == tx.subnetwork
+        assert len(bytes(native)) == 20
+        assert tx.is_coinbase() is False
+        assert native != SubnetworkId.coinbase()
+
+    @pytest.mark.parametrize("value", ["00" * 19, "00" * 21, "zz" * 20, "0" * 39])
+    def test_from_hex_invalid(self, value):
+        """Test from_hex rejects invalid hex and ids that are not 20 bytes."""
+        with pytest.raises(ValueError):
+            SubnetworkId.from_hex(value)
+
+
+class TestTransactionGasValidation:
+    """Tests for gas validation on native and built-in subnetworks."""
+
+    def _args(self, subnetwork_id, gas):
+        outpoint = TransactionOutpoint(Hash("0" * 64), 0)
+        input = TransactionInput(outpoint, "", 0, 1)
+        output = TransactionOutput(1000000, ScriptPublicKey(0, "51"))
+        return (0, [input], [output], 0, subnetwork_id, gas, "", 0)
+
+    def test_native_nonzero_gas_raises_when_validating(self):
+        """Test a native-subnetwork transaction with gas raises with validation on."""
+        args = self._args(str(SubnetworkId.native()), 100)
+
+        with pytest.raises(ValueError, match="gas must be 0"):
+            Transaction(*args, validate=True)
+
+        tx = Transaction(*args)
+        assert tx.gas == 100
+        with pytest.raises(ValueError, match="gas must be 0"):
+            tx.finalize(validate=True)
+
+    def test_native_zero_gas_passes(self):
+        """Test validation accepts a native-subnetwork transaction without gas."""
+        tx = Transaction(*self._args(str(SubnetworkId.native()), 0), validate=True)
+
+        assert tx.finalize(validate=True).to_string() == tx.id
+
+    def test_custom_subnetwork_allows_gas(self):
+        """Test gas is allowed on a non-built-in subnetwork."""
+        subnetwork_id = "ff" + "00" * 19
+
+        tx = Transaction(*self._args(subnetwork_id, 100), validate=True)
+
+        assert tx.finalize(validate=True).to_string() == tx.id
+
+
+class TestTransactionAddresses:
+    """Tests for Transaction.addresses."""
+
+    def _transaction(self):
+        standard = make_utxo_entries([1_000_000_000])[0]
+        nonstandard = UtxoEntryReference.from_output(
+            Hash("ff" * 32), 0, TransactionOutput(500_000_000, ScriptPublicKey(0, "51")), 0, False
+        )
+        inputs = [
+            TransactionInput(utxo.outpoint, "", 0, 1, utxo)
+            for utxo in (standard, nonstandard)
+        ]
+        output = TransactionOutput(1_400_000_000, ScriptPublicKey(0, TEST_SCRIPT))
+        return Transaction(0, inputs, [output], 0, "0" * 40, 0, "", 0)
+
+    def test_unknown_scripts_dropped_by_default(self):
+        """Test only decodable input scripts are returned by default."""
+        addresses = self._transaction().addresses("mainnet")
+
+        assert [address.to_string() for address in addresses] == [TEST_ADDRESS]
+
+    def test_unknown_scripts(self):
+        """Test a nonstandard input script is reported instead of vanishing."""
+        tx = self._transaction()
+
+        assert [script.script for script in tx.unknown_scripts()] == ["51"]
+
+    def test_no_unknown_scripts(self):
+        """Test a transaction spending only standard scripts has no unknown scripts."""
+        tx = make_transaction()
+
+        assert tx.unknown_scripts() == []
+
+
+class TestStructuralEquality:
+    """Tests for __eq__ on Transaction, TransactionInput, TransactionOutput, and UtxoEntry."""
+
+    def _transaction(self, payload="", sequence=0):
+        tx = make_transaction(payload=payload)
+        inputs = tx.inputs
+        inputs[0].sequence = sequence
+        tx.inputs = inputs
+        return tx
+
+    def test_transaction_equal_when_independently_built(self):
+        """Test separately built identical transactions are equal."""
+        assert self._transaction() == self._transaction()
+        assert Transaction.from_dict(self._transaction().to_dict()) == self._transaction()
+
+    def test_transaction_self_equality(self):
+        """Test a transaction equals itself and shares equality with its clones."""
+        tx = self._transaction()
+        assert tx == tx
+        assert tx.inputs[0] == tx.inputs[0]
+        assert tx.outputs[0] == tx.outputs[0]
+
+    def test_transaction_field_differences(self):
+        """Test differing payload, input, and output fields break equality."""
+        assert self._transaction(payload="aa") != self._transaction()
+        assert self._transaction(sequence=1) != self._transaction()
+
+        tx = self._transaction()
+        outputs = tx.outputs
+        outputs[0].value += 1
+        tx.outputs = outputs
+        assert tx != self._transaction()
+
+    def test_input_equality(self):
+        """Test input equality covers the signature script and UTXO entry."""
+        a = self._transaction().inputs[0]
+        b = self._transaction().inputs[0]
+        assert a == b
+
+        b.signature_script = "51"
+        assert a != b
+
+        detached = TransactionInput(a.previous_outpoint, "", a.sequence, a.sig_op_count)
+        assert detached != a
+
+    def test_output_equality(self):
+        """Test output equality compares value and script public key."""
+        assert TransactionOutput(1000, ScriptPublicKey(0, "51")) == TransactionOutput(
+            1000, ScriptPublicKey(0, "51")
+        )
+        assert TransactionOutput(1000, ScriptPublicKey(0, "51")) != TransactionOutput(
+            1001, ScriptPublicKey(0, "51")
+        )
+        assert TransactionOutput(1000, ScriptPublicKey(0, "51")) != TransactionOutput(
+            1000, ScriptPublicKey(0, "52")
+        )
+
+    def test_utxo_entry_equality(self):
+        """Test UTXO entry equality compares every field."""
+        a = make_utxo_entries([1_000_000_000])[0].entry
+        b = make_utxo_entries([1_000_000_000])[0].entry
+        c = make_utxo_entries([1_000_000_001])[0].entry
+        assert a == b
+        assert a != c
+
+
+class TestTransactionVerifySignatures:
+    """Tests for Transaction.verify_signatures."""
+
+    def test_signed_transaction_verifies(self, signed_transaction):
+        """Test a correctly signed transaction verifies."""
+        assert signed_transaction.verify_signatures() is True
+
+    def test_unsigned_transaction_fails(self):
+        """Test a transaction without signature scripts does not verify."""
+        tx = make_transaction()
+
+        assert tx.verify_signatures() is False
+
+    def test_tampered_transaction_fails(self, signed_transaction):
+        """Test changing an output after signing invalidates the signatures."""
+        outputs = signed_transaction.outputs
+        outputs[0].value += 1
+        signed_transaction.outputs = outputs
+
+        assert signed_transaction.verify_signatures() is False
+
+    def test_missing_utxo_raises(self):
+        """Test verification raises when inputs lack UTXO entries."""
+        outpoint = TransactionOutpoint(Hash("0" * 64), 0)
+        input = TransactionInput(outpoint, "", 0, 1)
+        output = TransactionOutput(1000, ScriptPublicKey(0, "51"))
+        tx = Transaction(0, [input], [output], 0, "0" * 40, 0, "", 0)
+
+        with pytest.raises(Exception):
+            tx.verify_signatures()
+
+
+class TestSignTransactions:
+    """Tests for sign_transactions bulk signing."""
+
+    def _unsigned_transactions(self, count):
+        txs = []
+        for i in range(count):
+            txs.append(make_transaction(
+                [1_000_000_000 + i, 500_000_000],
+                [{"address": TEST_ADDRESS, "amount": 1_200_000_000}],
+            ))
+        return txs
+
+    def test_sign_three_transactions(self, known_private_key):
+        """Test a batch of three transactions is fully signed."""
+        txs = self._unsigned_transactions(3)
+
+        signed = sign_transactions(txs, [known_private_key])
+
+        assert len(signed) == 3
+        for tx in signed:
+            assert all(input.signature_script_as_hex for input in tx.inputs)
+            assert tx.verify_signatures() is True
+
+    def test_matches_single_signing(self, known_private_key):
+        """Test bulk signing preserves order and matches sign_transaction."""
+        batch = sign_transactions(self._unsigned_transactions(3), [known_private_key], True)
+        single = [
+            sign_transaction(tx, [known_private_key], True)
+            for tx in self._unsigned_transactions(3)
+        ]
+
+        assert [tx.id for tx in batch] == [tx.id for tx in single]
+
+    def test_empty_batch(self, known_private_key):
+        """Test an empty batch returns an empty list."""
+        assert sign_transactions([], [known_private_key]) == []
+
+    def test_verify_failure_raises(self):
+        """Test verification failure with the wrong key raises."""
+        txs = self._unsigned_transactions(2)
+
+        with pytest.raises(Exception):
+            sign_transactions(txs, [PrivateKey("1" * 64)], True)
+
+
+class TestConcurrentSigning:
+    """Tests for signing and mass calculation from multiple Python threads."""
+
+    def _sign_batch(self, private_key, count):
+        signed = []
+        for i in range(count):
+            tx = make_transaction(
+                [1_000_000_000 + i, 500_000_000],
+                [{"address": TEST_ADDRESS, "amount": 1_200_000_000}],
+            )
+            calculate_transaction_mass("mainnet", tx)
+            signed.append(sign_transaction(tx, [private_key], True))
+        return signed
+
+    def test_two_threads_sign_concurrently(self, known_private_key):
+        """Test two threads can sign transactions at the same time without deadlock."""
+        with ThreadPoolExecutor(max_workers=2) as executor:
+            futures = [executor.submit(self._sign_batch, known_private_key, 20) for _ in range(2)]
+            results = [future.result(timeout=60) for future in futures]
+
+        for signed in results:
+            assert len(signed) == 20
+            assert all(tx.verify_signatures() for tx in signed)
+        assert [tx.id for tx in results[0]] == [tx.id for tx in results[1]]
+
+
+class TestVerifyScript:
+    """Tests for verify_script helper function."""
+
+    def test_valid_spend_succeeds(self, signed_transaction):
+        """Test a valid pay-to-pubkey spend executes successfully."""
+        spk = pay_to_address_script(Address(TEST_ADDRESS))
+
+        for index, input in enumerate(signed_transaction.inputs):
+            assert verify_script(input.signature_script_as_hex, spk, signed_transaction, index) is True
+
+    def test_wrong_signature_fails(self, signed_transaction):
+        """Test a signature for a different input fails execution."""
+        spk = pay_to_address_script(Address(TEST_ADDRESS))
+        other_signature = signed_transaction.inputs[1].signature_script_as_hex
+
+        assert verify_script(other_signature, spk, signed_transaction, 0) is False
+
+    def test_input_index_out_of_range(self, signed_transaction):
+        """Test an out of range input index raises IndexError."""
+        spk = pay_to_address_script(Address(TEST_ADDRESS))
+
+        with pytest.raises(IndexError):
+            verify_script("", spk, signed_transaction, len(signed_transaction.inputs))
+
+
+class TestTransactionDiff:
+    """Tests for transaction_diff helper function."""
+
+    def _transaction(self, output_values, payload=""):
+        outpoint = TransactionOutpoint(Hash("0" * 64), 0)
+        input = TransactionInput(outpoint, "", 0, 1)
+        outputs = [TransactionOutput(value, ScriptPublicKey(0, "51")) for value in output_values]
+        return Transaction(0, [input], outputs, 0, "0" * 40, 0, payload, 0)
+
+    def test_identical_transactions(self):
+        """Test identical transactions report no differences."""
+        a = self._transaction([1000, 2000, 3000])
+        b = self._transaction([1000, 2000, 3000])
+
+        assert transaction_diff(a, b) == {"fields": [], "inputs": [], "outputs": []}
+
+    def test_changed_output_reports_index(self):
+        """Test a single changed output reports exactly that output index."""
+        a = self._transaction([1000, 2000, 3000])
+        b = self._transaction([1000, 2500, 3000])
+
+        diff = transaction_diff(a, b)
+
+        assert diff["outputs"] == [1]
+        assert diff["inputs"] == []
+
+    def test_extra_output_reports_index(self):
+        """Test an output present in only one transaction is reported."""
+        a = self._transaction([1000])
+        b = self._transaction([1000, 2000])
+
+        assert transaction_diff(a, b)["outputs"] == [1]
+
+    def test_changed_field(self):
+        """Test a changed top-level field is reported by name."""
+        a = self._transaction([1000])
+        b = self._transaction([1000], payload="abcd")
+
+        diff = transaction_diff(a, b)
+
+        assert "payload" in diff["fields"]
+        assert diff["outputs"] == []
+
 
 class TestPaymentOutput:
     """Tests for PaymentOutput class."""
@@ -218,6 +827,177 @@ class TestPaymentOutput:
         # This test verifies the class exists
         assert PaymentOutput is not None
 
+    def test_payment_output_construction(self):
+        """Test creating a PaymentOutput from an Address or string."""
+        from_str = PaymentOutput(TEST_ADDRESS, 1_000)
+        from_address = PaymentOutput(Address(TEST_ADDRESS), 1_000)
+
+        assert from_str == from_address
+        assert from_str.address.to_string() == TEST_ADDRESS
+        assert from_str.amount == 1_000
+
+    def test_payment_output_invalid_address(self):
+        """Test an invalid address raises."""
+        with pytest.raises(Exception):
+            PaymentOutput("not-an-address", 1_000)
+
+    def test_payment_output_int(self):
+        """Test PaymentOutput converts to its amount with int()."""
+        outputs = [PaymentOutput(TEST_ADDRESS, amount) for amount in (1_000, 2_500, 40)]
+
+        assert int(outputs[0]) == 1_000
+        assert sum(int(output) for output in outputs) == 3_540
+
+    def test_payment_output_accepted_as_output(self):
+        """Test PaymentOutput objects can be passed as transaction outputs."""
+        tx = make_transaction(outputs=[PaymentOutput(TEST_ADDRESS, 900_000_000)])
+
+        assert tx.outputs[0].value == 900_000_000
+
+    def test_to_transaction_output(self):
+        """Test converting to a TransactionOutput keeps the value and address script."""
+        output = PaymentOutput(TEST_ADDRESS, 900_000_000).to_transaction_output()
+
+        assert isinstance(output, TransactionOutput)
+        assert output.value == 900_000_000
+        assert output.script_public_key.script == TEST_SCRIPT
+        assert output.script_public_key.version == 0
+
+
+class TestUtxoEntryInt:
+    """Tests for int() coercion of UTXO entries."""
+
+    def test_sum_utxo_entries(self):
+        """Test summing UtxoEntry and UtxoEntryReference amounts via int()."""
+        amounts = [1_000, 250_000, 3]
+        references = make_utxo_entries(amounts)
+
+        assert sum(int(reference) for reference in references) == sum(amounts)
+        assert sum(int(reference.entry) for reference in references) == sum(amounts)
+        assert int(references[1].entry) == references[1].entry.amount
+
+
+class TestUtxoEntryBytes:
+    """Tests for UtxoEntry and UtxoEntries binary serialization."""
+
+    def test_utxo_entry_roundtrip(self):
+        """Test a UtxoEntry survives a to_bytes/from_bytes round-trip."""
+        entry = make_utxo_entries([123_456])[0].entry
+
+        data = entry.to_bytes()
+
+        assert isinstance(data, bytes)
+        assert UtxoEntry.from_bytes(data) == entry
+
+    def test_utxo_entry_without_address_roundtrip(self):
+        """Test entries without an address round-trip."""
+        tx_id = Hash("ab" * 32)
+        output = TransactionOutput(5_000, ScriptPublicKey(0, TEST_SCRIPT))
+        entry = UtxoEntryReference.from_output(tx_id, 3, output, 42, True).entry
+
+        restored = UtxoEntry.from_bytes(entry.to_bytes())
+
+        assert restored == entry
+        assert restored.address is None
+        assert restored.is_coinbase is True
+
+    def test_utxo_entries_roundtrip(self):
+        """Test a UtxoEntries collection of three entries round-trips."""
+        entries = UtxoEntries(make_utxo_entries([1_000, 2_000, 3_000]))
+
+        data = entries.to_bytes()
+        restored = UtxoEntries.from_bytes(data)
+
+        assert restored == entries
+        assert restored.amount() == 6_000
+        assert [item.outpoint for item in restored.items] == [item.outpoint for item in entries.items]
+        assert len(data) < len(str(entries.to_dict()))
+
+    def test_from_bytes_invalid(self):
+        """Test truncated data raises ValueError."""
+        data = UtxoEntries(make_utxo_entries([1_000, 2_000, 3_000])).to_bytes()
+
+        with pytest.raises(ValueError, match="Invalid UTXO entry bytes"):
+            UtxoEntries.from_bytes(data[:-10])
+        with pytest.raises(ValueError, match="Invalid UTXO entry bytes"):
+            UtxoEntry.from_bytes(b"\x01\x02")
+
+
+class TestUtxoEntryReferenceFromOutput:
+    """Tests for UtxoEntryReference.from_output."""
+
+    def test_from_output_matches_source(self):
+        """Test the reference carries the output's amount and script."""
+        tx = make_transaction()
+        output = tx.outputs[0]
+
+        reference = UtxoEntryReference.from_output(Hash(tx.id), 0, output, 1_234, False)
+
+        assert reference.amount == output.value
+        assert reference.script_public_key == output.script_public_key
+        assert reference.outpoint == tx.outpoint_for_output(0)
+        assert reference.block_daa_score == 1_234
+        assert reference.is_coinbase is False
+        assert reference.address is None
+
+    def test_from_output_can_be_spent(self, known_private_key):
+        """Test chaining a signed follow-up transaction off a new output."""
+        tx = make_transaction()
+        reference = UtxoEntryReference.from_output(Hash(tx.id), 0, tx.outputs[0], 0, False)
+
+        follow_up = create_transaction([reference], [{"address": TEST_ADDRESS, "amount": 800_000_000}], 0, network_id="mainnet")
+        follow_up = sign_transaction(follow_up, [known_private_key], False)
+
+        assert follow_up.inputs[0].previous_outpoint.transaction_id == tx.id
+        assert follow_up.verify_signatures() is True
+
+
+class TestUtxoEntryMaturity:
+    """Tests for UtxoEntry.is_mature."""
+
+    def _entry(self, block_daa_score, is_coinbase):
+        return UtxoEntry.from_dict({
+            "address": TEST_ADDRESS,
+            "outpoint": {"transactionId": "01" * 32, "index": 0},
+            "amount": 1_000_000_000,
+            "scriptPublicKey": {"version": 0, "script": TEST_SCRIPT},
+            "blockDaaScore": block_daa_score,
+            "isCoinbase": is_coinbase,
+        })
+
+    def test_fresh_coinbase_is_immature(self):
+        """Test a coinbase UTXO from the current block is not yet spendable."""
+        entry = self._entry(1_000_000, True)
+
+        assert entry.is_mature(1_000_000, "mainnet") is False
+        assert entry.is_mature(1_000_001, "testnet-10") is False
+
+    def test_deep_coinbase_is_mature(self):
+        """Test a coinbase UTXO far below the current DAA score is spendable."""
+        entry = self._entry(1_000_000, True)
+
+        assert entry.is_mature(100_000_000, "mainnet") is True
+        assert entry.is_mature(100_000_000, NetworkId("testnet-10")) is True
+
+    def test_coinbase_matures_after_user_outputs(self):
+        """Test the coinbase maturity period is longer than the user one."""
+        coinbase = self._entry(1_000_000, True)
+        user = self._entry(1_000_000, False)
+
+        scores = range(1_000_000, 1_100_000, 10)
+        first_user = next(score for score in scores if user.is_mature(score, "mainnet"))
+        first_coinbase = next(score for score in scores if coinbase.is_mature(score, "mainnet"))
+
+        assert first_coinbase > first_user
+        assert user.is_mature(100_000_000, "mainnet") is True
+
+    def test_unknown_network(self):
+        """Test a testnet without a known suffix raises ValueError."""
+        entry = self._entry(0, True)
+
+        with pytest.raises(ValueError):
+            entry.is_mature(100, "testnet-99")
+
 
 class TestTransactionMass:
     """Tests for transaction mass calculations."""
@@ -227,6 +1007,134 @@ class TestTransactionMass:
         max_mass = maximum_standard_transaction_mass()
         assert max_mass > 0
 
+    def test_fee_for_mass(self):
+        """Test converting a fee rate to an absolute fee."""
+        assert fee_for_mass(2036, 1.0) == 2036
+        assert fee_for_mass(2036, 2.5) == 5090
+        # Fractional sompi are rounded up
+        assert fee_for_mass(3, 0.5) == 2
+
+    def test_fee_rate_for_fee(self):
+        """Test the inverse conversion from fee to fee rate."""
+        assert fee_rate_for_fee(5090, 2036) == 2.5
+        assert fee_rate_for_fee(fee_for_mass(1234, 3.0), 1234) == 3.0
+
+    def test_fee_conversion_invalid_input(self):
+        """Test invalid fee rate and zero mass raise ValueError."""
+        with pytest.raises(ValueError):
+            fee_for_mass(1000, -1.0)
+        with pytest.raises(ValueError):
+            fee_for_mass(1000, float("nan"))
+        with pytest.raises(ValueError):
+            fee_rate_for_fee(1000, 0)
+
+    def test_minimum_relay_fee_matches_generator_fee(self):
+        """Test the generator settles on the minimum relay fee at the default fee rate."""
+        entries = make_utxo_entries([1_000_000_000])
+
+        result = create_transactions(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": TEST_ADDRESS, "amount": 100_000_000}],
+        )
+        pending = result["transactions"][0]
+        min_fee = minimum_relay_fee("mainnet", pending.transaction)
+
+        assert min_fee > 0
+        assert pending.fee_amount == min_fee
+        assert sum(e.amount for e in entries) - sum(o.value for o in pending.transaction.outputs) == min_fee
+
+    @pytest.mark.parametrize("mass_fn", [
+        calculate_transaction_mass,
+        update_transaction_mass,
+        calculate_transaction_fee,
+        minimum_relay_fee,
+    ])
+    def test_mass_functions_reject_empty_transaction(self, mass_fn):
+        """Test mass functions raise a descriptive ValueError for a transaction with no inputs."""
+        tx = Transaction(0, [], [], 0, "0" * 40, 0, "", 0)
+
+        with pytest.raises(ValueError, match="no inputs"):
+            mass_fn("mainnet", tx)
+
+    def test_mass_functions_reject_no_inputs_with_outputs(self):
+        """Test a transaction with outputs but no inputs is still rejected."""
+        output = TransactionOutput(1000, ScriptPublicKey(0, "51"))
+        tx = Transaction(0, [], [output], 0, "0" * 40, 0, "", 0)
+
+        with pytest.raises(ValueError, match="no inputs"):
+            calculate_transaction_mass("mainnet", tx)
+
+    @pytest.mark.parametrize("mass_fn", [
+        calculate_transaction_mass,
+        update_transaction_mass,
+        calculate_transaction_fee,
+        minimum_relay_fee,
+    ])
+    def test_mass_functions_reject_no_outputs(self, mass_fn):
+        """Test mass functions raise a descriptive ValueError for a transaction with no outputs."""
+        tx = create_transaction(make_utxo_entries([1_000_000_000]), [], 0, auto_mass=False)
+
+        with pytest.raises(ValueError, match="no outputs"):
+            mass_fn("mainnet", tx)
+
+    def test_storage_mass_rejects_empty_inputs(self):
+        """Test calculate_storage_mass raises ValueError without input values."""
+        with pytest.raises(ValueError, match="no input values"):
+            calculate_storage_mass("mainnet", [], [1000])
+        with pytest.raises(ValueError, match="no input values"):
+            calculate_storage_mass("mainnet", [], [])
+
+    def test_storage_mass_rejects_empty_outputs(self):
+        """Test calculate_storage_mass raises ValueError without output values."""
+        with pytest.raises(ValueError, match="no output values"):
+            calculate_storage_mass("mainnet", [1000], [])
+
+
+class TestCheckTransactionStandard:
+    """Tests for check_transaction_standard."""
+
+    def test_standard_transaction_passes(self):
+        """Test an ordinary payment passes all standardness rules."""
+        tx = make_transaction()
+
+        assert check_transaction_standard(tx, NetworkId("mainnet")) is None
+
+    def test_dust_output(self):
+        """Test an output below the dust threshold is reported as dust."""
+        tx = make_transaction(outputs=[{"address": TEST_ADDRESS, "amount": 500}])
+
+        with pytest.raises(NonStandardTransactionError, match="dust") as exc_info:
+            check_transaction_standard(tx, NetworkId("mainnet"))
+        assert exc_info.value.rule == "dust"
+
+    def test_mass_exceeds_maximum(self):
+        """Test a small output from a large input exceeds the standard mass."""
+        tx = make_transaction(outputs=[{"address": TEST_ADDRESS, "amount": 1_000_000}], auto_mass=False)
+
+        with pytest.raises(NonStandardTransactionError) as exc_info:
+            check_transaction_standard(tx, NetworkId("mainnet"))
+        assert exc_info.value.rule == "mass"
+
+    def test_sig_op_count(self):
+        """Test a P2SH spend declaring too many signature operations is reported."""
+        tx = make_transaction(script="aa20" + "cd" * 32 + "87", sig_op_count=16)
+
+        with pytest.raises(NonStandardTransactionError) as exc_info:
+            check_transaction_standard(tx, NetworkId("mainnet"))
+        assert exc_info.value.rule == "sig_op_count"
+
+    def test_sig_op_count_within_p2sh_limit(self):
+        """Test a P2SH spend declaring the maximum standard sig-op count passes."""
+        tx = make_transaction(script="aa20" + "cd" * 32 + "87", sig_op_count=15)
+
+        assert check_transaction_standard(tx, NetworkId("mainnet")) is None
+
+    def test_is_value_error(self):
+        """Test the exception can be caught as a ValueError."""
+        assert issubclass(NonStandardTransactionError, ValueError)
+
 
 class TestSighashType:
     """Tests for SighashType enum."""
@@ -236,13 +1144,509 @@ class TestSighashType:
         assert SighashType is not None
 
 
+class TestSignatureCompact:
+    """Tests for compact signature helpers."""
+
+    def _signature(self, known_private_key, sighash_type=SighashType.All):
+        tx = make_transaction()
+        return create_input_signature(tx, 0, known_private_key, sighash_type)
+
+    def test_round_trip(self, known_private_key):
+        """Test a signature survives conversion to compact form and back."""
+        signature = self._signature(known_private_key)
+
+        compact = signature_to_compact(signature)
+
+        assert len(bytes.fromhex(compact)) == 64
+        assert compact == signature[2:130]
+        assert signature_from_compact(compact) == signature
+
+    def test_round_trip_preserves_sighash_type(self, known_private_key):
+        """Test the sighash type byte is restored when encoding."""
+        signature = self._signature(known_private_key, SighashType.Single)
+
+        compact = signature_to_compact(signature)
+
+        assert signature_from_compact(compact, SighashType.Single) == signature
+        assert signature_from_compact(compact) != signature
+
+    def test_accepts_unprefixed_signature(self, known_private_key):
+        """Test a 65-byte signature without push opcode is accepted."""
+        signature = self._signature(known_private_key)
+
+        assert signature_to_compact(signature[2:]) == signature[2:130]
+
+    def test_invalid_length_raises(self):
+        """Test malformed signatures raise ValueError."""
+        with pytest.raises(ValueError):
+            signature_to_compact("00" * 10)
+        with pytest.raises(ValueError):
+            signature_from_compact("00" * 63)
+
+    def test_invalid_push_opcode_raises(self, known_private_key):
+        """Test a 66-byte signature with the wrong push opcode raises ValueError."""
+        signature = self._signature(known_private_key)
+
+        with pytest.raises(ValueError):
+            signature_to_compact("40" + signature[2:])
+
+    def test_invalid_sighash_type_raises(self, known_private_key):
+        """Test an unknown sighash type byte raises ValueError."""
+        signature = self._signature(known_private_key)
+
+        with pytest.raises(ValueError):
+            signature_to_compact(signature[:-2] + "ff")
+
+
 class TestCreateTransaction:
     """Tests for create_transaction helper function."""
-    # TODO
-    pass
+
+    def test_auto_mass_sets_mass(self):
+        """Test the created transaction has its mass set by default."""
+        entries = make_utxo_entries([1_000_000_000])
+        outputs = [{"address": TEST_ADDRESS, "amount": 900_000_000}]
+
+        tx = create_transaction(entries, outputs, 0, network_id="mainnet")
+
+        assert tx.mass > 0
+        assert tx.mass == calculate_transaction_mass("mainnet", tx)
+
+    def test_auto_mass_disabled(self):
+        """Test mass stays zero when auto_mass is disabled."""
+        entries = make_utxo_entries([1_000_000_000])
+        outputs = [{"address": TEST_ADDRESS, "amount": 900_000_000}]
+
+        tx = create_transaction(entries, outputs, 0, auto_mass=False)
+
+        assert tx.mass == 0
+
+    def test_auto_mass_without_network(self):
+        """Test mass is left unset instead of assuming a network when network_id is omitted."""
+        entries = make_utxo_entries([1_000_000_000])
+        outputs = [{"address": TEST_ADDRESS, "amount": 900_000_000}]
+
+        tx = create_transaction(entries, outputs, 0)
+
+        assert tx.mass == 0
+
+    def test_auto_mass_uses_sig_op_count(self):
+        """Test the mass accounts for the declared signatures per input."""
+        entries = make_utxo_entries([1_000_000_000])
+        outputs = [{"address": TEST_ADDRESS, "amount": 900_000_000}]
+
+        single = create_transaction(entries, outputs, 0, network_id="mainnet")
+        multi = create_transaction(entries, outputs, 0, sig_op_count=3, network_id="mainnet")
+
+        assert multi.mass > single.mass
+        assert multi.mass == calculate_transaction_mass("mainnet", multi, 3)
+
+
+class TestBumpFee:
+    """Tests for bump_fee replace-by-fee helper."""
+
+    def test_change_shrinks_by_bump(self, signed_transaction):
+        """Test only the change output shrinks, by exactly the additional fee."""
+        bumped = bump_fee(signed_transaction, 5_000, 1)
+
+        assert [output.value for output in bumped.outputs] == [600_000_000, 389_995_000]
+        assert bumped.id != signed_transaction.id
+
+    def test_signatures_cleared(self, signed_transaction, known_private_key):
+        """Test the replacement has no signatures and can be signed again."""
+        bumped = bump_fee(signed_transaction, 5_000, 1)
+
+        assert all(input.signature_script_as_hex is None for input in bumped.inputs)
+        signed = sign_transaction(bumped, [known_private_key], False)
+        assert signed.verify_signatures() is True
+
+    def test_original_unchanged(self, signed_transaction):
+        """Test the original transaction keeps its outputs and signatures."""
+        bump_fee(signed_transaction, 5_000, 1)
+
+        assert signed_transaction.outputs[1].value == 390_000_000
+        assert signed_transaction.verify_signatures() is True
+
+    def test_index_out_of_range(self, signed_transaction):
+        """Test a missing change output raises IndexError."""
+        with pytest.raises(IndexError):
+            bump_fee(signed_transaction, 5_000, 2)
+
+    def test_fee_exceeds_change(self, signed_transaction):
+        """Test a bump the change output cannot cover raises ValueError."""
+        with pytest.raises(ValueError):
+            bump_fee(signed_transaction, 390_000_000, 1)
+
+
+class TestSelectCoins:
+    """Tests for select_coins helper function."""
+
+    def _change_script(self):
+        return pay_to_address_script(Address(TEST_ADDRESS))
+
+    def test_exact_match_no_change(self):
+        """Test an exact match selects one UTXO and produces no change."""
+        entries = make_utxo_entries([300_000_000, 500_000_000, 200_000_000])
+
+        result = select_coins(entries, 500_000_000, 0.0, self._change_script())
+
+        assert [entry.amount for entry in result["inputs"]] == [500_000_000]
+        assert result["fee"] == 0
+        assert result["change"] == 0
+
+    def test_selection_with_change(self):
+        """Test selected amounts cover the target, fee, and change exactly."""
+        entries = make_utxo_entries([300_000_000, 500_000_000, 200_000_000])
+
+        result = select_coins(entries, 600_000_000, 1.0, self._change_script())
+        selected = sum(entry.amount for entry in result["inputs"])
+
+        assert len(result["inputs"]) == 2
+        assert result["fee"] > 0
+        assert result["change"] > 0
+        assert selected == 600_000_000 + result["fee"] + result["change"]
+
+    def test_insufficient_funds(self):
+        """Test selection raises when UTXOs cannot cover target and fee."""
+        entries = make_utxo_entries([300_000_000, 200_000_000])
+
+        with pytest.raises(Exception, match="Insufficient funds"):
+            select_coins(entries, 500_000_000, 1.0, self._change_script())
+
+    def test_insufficient_funds_amounts(self):
+        """Test the raised error carries available, required and deficit amounts."""
+        entries = make_utxo_entries([300_000_000, 200_000_000])
+
+        with pytest.raises(InsufficientFundsError) as exc_info:
+            select_coins(entries, 500_000_000, 1.0, self._change_script())
+
+        err = exc_info.value
+        assert err.available == 500_000_000
+        assert err.required > 500_000_000
+        assert err.deficit == err.required - err.available
+
+    def test_invalid_fee_rate(self):
+        """Test a negative fee rate raises ValueError."""
+        entries = make_utxo_entries([300_000_000])
+
+        with pytest.raises(ValueError):
+            select_coins(entries, 100_000_000, -1.0, self._change_script())
+
+
+class TestCoinbaseMaturitySelection:
+    """Tests for skipping immature coinbase UTXOs during selection."""
+
+    CURRENT_DAA_SCORE = 10_000_000
+
+    def _entries(self):
+        # An immature coinbase output holds the largest amount, so it would be
+        # picked first without filtering
+        current = self.CURRENT_DAA_SCORE
+        return make_utxo_entries(
+            [500_000_000, 300_000_000, 200_000_000],
+            block_daa_score=[current, current - 1_000_000, current],
+            is_coinbase=[True, True, False],
+        )
+
+    def test_select_coins_skips_immature_coinbase(self):
+        """Test select_coins only selects mature coinbase UTXOs."""
+        change_script = pay_to_address_script(Address(TEST_ADDRESS))
+
+        unfiltered = select_coins(self._entries(), 300_000_000, 0.0, change_script)
+        filtered = select_coins(
+            self._entries(), 300_000_000, 0.0, change_script,
+            current_daa_score=self.CURRENT_DAA_SCORE,
+        )
+
+        assert [entry.amount for entry in unfiltered["inputs"]] == [500_000_000]
+        assert [entry.amount for entry in filtered["inputs"]] == [300_000_000]
+
+    def test_select_coins_immature_coinbase_not_counted(self):
+        """Test immature coinbase funds do not count towards the target."""
+        change_script = pay_to_address_script(Address(TEST_ADDRESS))
+
+        with pytest.raises(Exception, match="Insufficient funds"):
+            select_coins(
+                self._entries(), 600_000_000, 1.0, change_script,
+                current_daa_score=self.CURRENT_DAA_SCORE,
+            )
+
+    def test_generator_skips_immature_coinbase(self):
+        """Test the Generator never spends an immature coinbase UTXO."""
+        generator = Generator(
+            network_id=NetworkId("mainnet"),
+            entries=self._entries(),
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": TEST_ADDRESS, "amount": 400_000_000}],
+            current_daa_score=self.CURRENT_DAA_SCORE,
+        )
+
+        spent = [entry.amount for tx in generator for entry in tx.get_utxo_entries()]
+
+        assert 500_000_000 not in spent
+        assert sorted(spent) == [200_000_000, 300_000_000]
+
+
+class TestCreateTransactions:
+    """Tests for create_transactions helper function."""
+
+    def test_returns_ordered_batch(self):
+        """Test compounding transactions precede the final transaction in the batch."""
+        entries = make_utxo_entries([100_000_000] * 200)
+
+        result = create_transactions(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": TEST_ADDRESS, "amount": 15_000_000_000}],
+        )
+        batch = result["transactions"]
+
+        assert len(batch) > 1
+        assert len(list(batch)) == len(batch)
+        assert [tx.id for tx in batch] == [tx.id for tx in batch.transactions]
+        assert batch[-1].transaction_type == "final"
+        assert all(batch[i].transaction_type == "batch" for i in range(len(batch) - 1))
+
+    def test_batch_index_out_of_range(self):
+        """Test indexing past the end of the batch raises IndexError."""
+        entries = make_utxo_entries([1_000_000_000])
+
+        result = create_transactions(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": TEST_ADDRESS, "amount": 100_000_000}],
+        )
+
+        with pytest.raises(IndexError):
+            result["transactions"][len(result["transactions"])]
 
 
 class TestGenerator:
     """Tests for Generator class."""
-    # TODO
-    pass
+
+    def test_priority_fee_increases_fee(self):
+        """Test priority_fee increases the final transaction fee by exactly that amount."""
+        entries = make_utxo_entries([1_000_000_000])
+        outputs = [{"address": TEST_ADDRESS, "amount": 100_000_000}]
+
+        base = Generator(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=outputs,
+        ).estimate()
+        tipped = Generator(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=outputs,
+            priority_fee=5_000,
+        ).estimate()
+
+        assert tipped.fees == base.fees + 5_000
+
+    def test_priority_entries_spent_first(self):
+        """Test priority entries appear as inputs of the first transaction."""
+        entries = make_utxo_entries([1_000_000_000, 2_000_000_000])
+        priority = UtxoEntryReference.from_dict({
+            "address": TEST_ADDRESS,
+            "outpoint": {"transactionId": "ee" * 32, "index": 3},
+            "amount": 150_000_000,
+            "scriptPublicKey": {"version": 0, "script": TEST_SCRIPT},
+            "blockDaaScore": 0,
+            "isCoinbase": False,
+        })
+
+        generator = Generator(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": TEST_ADDRESS, "amount": 100_000_000}],
+            priority_entries=[priority],
+        )
+        first = next(iter(generator))
+        spent = [entry.outpoint for entry in first.get_utxo_entries()]
+
+        assert priority.outpoint in spent
+
+    def test_spends_from_multiple_addresses(self, known_private_key):
+        """Test UTXOs from two addresses keep their sources and sign with both keys."""
+        other_key = PrivateKey("2" * 64)
+        other_address = other_key.to_address("mainnet")
+        other_entry = UtxoEntryReference.from_dict({
+            "address": other_address.to_string(),
+            "outpoint": {"transactionId": "dd" * 32, "index": 0},
+            "amount": 1_000_000_000,
+            "scriptPublicKey": {
+                "version": 0,
+                "script": pay_to_address_script(other_address).script,
+            },
+            "blockDaaScore": 0,
+            "isCoinbase": False,
+        })
+        entries = make_utxo_entries([1_000_000_000]) + [other_entry]
+
+        generator = Generator(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": TEST_ADDRESS, "amount": 1_500_000_000}],
+        )
+        pending = list(generator)
+
+        assert len(pending) == 1
+        tx = pending[0]
+        sources = {address.to_string() for address in tx.addresses()}
+        assert sources == {TEST_ADDRESS, other_address.to_string()}
+        assert {address.to_string() for address in tx.input_addresses()} == sources
+
+        tx.sign([known_private_key, other_key])
+        assert tx.transaction.verify_signatures() is True
+
+    def test_change_script_receives_change(self):
+        """Test change is paid to the provided change script."""
+        change_script = pay_to_script_hash_script("51")
+        generator = Generator(
+            network_id="mainnet",
+            entries=make_utxo_entries([1_000_000_000]),
+            outputs=[{"address": TEST_ADDRESS, "amount": 100_000_000}],
+            change_script=change_script,
+        )
+        pending = list(generator)
+
+        assert len(pending) == 1
+        outputs = pending[0].transaction.outputs
+        change = [output for output in outputs if output.script_public_key.script != TEST_SCRIPT]
+        assert len(change) == 1
+        assert change[0].script_public_key.script == change_script.script
+        assert change[0].value == pending[0].change_amount
+
+    def test_change_script_nonstandard_raises(self):
+        """Test a change script without an address raises ValueError."""
+        with pytest.raises(ValueError, match="change_script"):
+            Generator(
+                network_id="mainnet",
+                entries=make_utxo_entries([1_000_000_000]),
+                outputs=[{"address": TEST_ADDRESS, "amount": 100_000_000}],
+                change_script=ScriptPublicKey(0, "51"),
+            )
+
+    def test_insufficient_funds_error(self):
+        """Test generating beyond the available entries raises InsufficientFundsError."""
+        generator = Generator(
+            network_id="mainnet",
+            entries=make_utxo_entries([100_000_000]),
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": TEST_ADDRESS, "amount": 500_000_000}],
+        )
+
+        with pytest.raises(InsufficientFundsError) as exc_info:
+            generator.estimate()
+
+        err = exc_info.value
+        assert err.available == 100_000_000
+        assert err.deficit >= 400_000_000
+        assert err.deficit == err.required - err.available
+
+    def test_change_destination_required(self):
+        """Test omitting both change_address and change_script raises ValueError."""
+        with pytest.raises(ValueError):
+            Generator(
+                network_id="mainnet",
+                entries=make_utxo_entries([1_000_000_000]),
+                outputs=[{"address": TEST_ADDRESS, "amount": 100_000_000}],
+            )
+
+    def test_no_change_sweeps_to_single_output(self):
+        """Test no_change sends all funds from a single UTXO to the payment output."""
+        entries = make_utxo_entries([1_000_000_000])
+        recipient = Keypair.random().to_address("mainnet")
+
+        generator = Generator(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": recipient, "amount": 100_000_000}],
+            no_change=True,
+        )
+        pending = list(generator)
+
+        assert len(pending) == 1
+        outputs = pending[0].transaction.outputs
+        assert len(outputs) == 1
+        assert outputs[0].value == 1_000_000_000 - pending[0].fee_amount
+
+    def test_no_change_multiple_outputs_raises(self):
+        """Test no_change rejects more than one payment output."""
+        entries = make_utxo_entries([1_000_000_000])
+
+        with pytest.raises(Exception):
+            Generator(
+                network_id="mainnet",
+                entries=entries,
+                change_address=Address(TEST_ADDRESS),
+                outputs=[
+                    {"address": TEST_ADDRESS, "amount": 100_000_000},
+                    {"address": TEST_ADDRESS, "amount": 200_000_000},
+                ],
+                no_change=True,
+            )
+
+    def test_payload_attached_and_adds_mass(self):
+        """Test a payload is attached to the transaction and increases its mass."""
+        entries = make_utxo_entries([1_000_000_000])
+        outputs = [{"address": TEST_ADDRESS, "amount": 100_000_000}]
+        payload = bytes(range(100))
+
+        plain = list(Generator(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=outputs,
+        ))
+        with_payload = list(Generator(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=outputs,
+            payload=payload,
+        ))
+
+        assert with_payload[0].transaction.payload == payload.hex()
+        assert with_payload[0].mass > plain[0].mass
+
+    def test_summary_reports_compound_transactions(self):
+        """Test a send requiring compounding reports compound transactions and fees."""
+        entries = make_utxo_entries([100_000_000] * 200)
+
+        generator = Generator(
+            network_id="mainnet",
+            entries=entries,
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": TEST_ADDRESS, "amount": 15_000_000_000}],
+        )
+        pending = list(generator)
+        summary = generator.summary()
+
+        assert summary.compound_transactions > 0
+        assert summary.compound_transactions == summary.transactions - 1
+        assert summary.compound_fees == sum(
+            tx.fee_amount for tx in pending if tx.transaction_type == "batch"
+        )
+        assert summary.compound_fees < summary.fees
+
+    def test_summary_total_fees_kas(self):
+        """Test the aggregate fees render as an exact KAS decimal string."""
+        generator = Generator(
+            network_id="mainnet",
+            entries=make_utxo_entries([1_000_000_000]),
+            change_address=Address(TEST_ADDRESS),
+            outputs=[{"address": TEST_ADDRESS, "amount": 100_000_000}],
+        )
+        list(generator)
+        summary = generator.summary()
+
+        assert summary.fees > 0
+        assert summary.total_fees_kas == sompi_to_kaspa_string(summary.fees)
+        assert summary.total_fees_kas == f"0.{summary.fees:08d}".rstrip("0")
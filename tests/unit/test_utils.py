@@ -2,20 +2,29 @@
 Unit tests for utility functions.
 """
 
+import hashlib
+
 import pytest
 
 from kaspa import (
     kaspa_to_sompi,
     sompi_to_kaspa,
+    sompi_to_kaspa_string,
+    sompi_to_kaspa_string_with_decimals,
     sompi_to_kaspa_string_with_suffix,
     sign_message,
     verify_message,
+    verify_message_by_address,
+    verify_messages,
     Address,
+    Keypair,
     PrivateKey,
     PublicKey,
     Hash,
     AccountKind,
     create_multisig_address,
+    merge_hashes,
+    merkle_root,
 )
 
 
@@ -47,6 +56,33 @@ class TestKaspaSompiConversions:
         result = kaspa_to_sompi(0.0)
         assert result == 0
 
+    def test_kaspa_to_sompi_max_supply(self):
+        """Test converting the maximum supply is accepted."""
+        result = kaspa_to_sompi(29_000_000_000.0)
+        assert result == 29_000_000_000 * 100_000_000
+
+    def test_kaspa_to_sompi_nan(self):
+        """Test NaN is rejected."""
+        with pytest.raises(ValueError):
+            kaspa_to_sompi(float("nan"))
+
+    def test_kaspa_to_sompi_infinity(self):
+        """Test infinity is rejected."""
+        with pytest.raises(ValueError):
+            kaspa_to_sompi(float("inf"))
+        with pytest.raises(ValueError):
+            kaspa_to_sompi(float("-inf"))
+
+    def test_kaspa_to_sompi_negative(self):
+        """Test negative amounts are rejected."""
+        with pytest.raises(ValueError):
+            kaspa_to_sompi(-1.0)
+
+    def test_kaspa_to_sompi_exceeds_max_supply(self):
+        """Test amounts above the maximum supply are rejected."""
+        with pytest.raises(ValueError):
+            kaspa_to_sompi(29_000_000_001.0)
+
 
 class TestSompiToKaspa:
     """Tests for Sompi to Kaspa conversions."""
@@ -93,6 +129,37 @@ class TestSompiToKaspaString:
         assert "KAS" in result
 
 
+class TestSompiToKaspaStringNoSuffix:
+    """Tests for suffix-free Sompi to Kaspa string conversion."""
+
+    def test_sompi_to_kaspa_string(self):
+        """Test formatting without a network suffix."""
+        assert sompi_to_kaspa_string(150_000_000) == "1.5"
+
+    def test_sompi_to_kaspa_string_exact(self):
+        """Test formatting is exact for small and large values."""
+        assert sompi_to_kaspa_string(1) == "0.00000001"
+        assert sompi_to_kaspa_string(12_345_678_901) == "123.45678901"
+        assert sompi_to_kaspa_string(0) == "0"
+
+    def test_with_decimals_min(self):
+        """Test min_decimals pads trailing zeros."""
+        assert sompi_to_kaspa_string_with_decimals(150_000_000, min_decimals=2) == "1.50"
+        assert sompi_to_kaspa_string_with_decimals(100_000_000, min_decimals=2) == "1.00"
+
+    def test_with_decimals_max(self):
+        """Test max_decimals truncates extra digits."""
+        assert sompi_to_kaspa_string_with_decimals(12_345_678_901, max_decimals=2) == "123.45"
+        assert sompi_to_kaspa_string_with_decimals(199_999_999, max_decimals=0) == "1"
+
+    def test_with_decimals_invalid(self):
+        """Test invalid decimal bounds raise ValueError."""
+        with pytest.raises(ValueError):
+            sompi_to_kaspa_string_with_decimals(1, max_decimals=9)
+        with pytest.raises(ValueError):
+            sompi_to_kaspa_string_with_decimals(1, min_decimals=4, max_decimals=2)
+
+
 class TestRoundTrip:
     """Tests for round-trip conversions."""
 
@@ -171,6 +238,56 @@ class TestMessageSigning:
         # With no_aux_rand, signatures should be deterministic
         assert sig1 == sig2
 
+    def test_verify_message_by_address(self):
+        """Test verifying a keypair's signature against its address."""
+        keypair = Keypair.random()
+        message = "Hello Kaspa!"
+        signature = sign_message(message, PrivateKey(keypair.private_key))
+
+        address = keypair.to_address("mainnet")
+        assert verify_message_by_address(message, signature, address) is True
+        assert verify_message_by_address(message, signature, address.to_string()) is True
+        assert verify_message_by_address(
+            message, signature, keypair.to_address_ecdsa("mainnet")
+        ) is True
+
+    def test_verify_message_by_address_wrong_address(self, known_private_key):
+        """Test verifying against a different signer's address returns False."""
+        message = "Hello Kaspa!"
+        signature = sign_message(message, known_private_key)
+
+        other_address = Keypair.random().to_address("mainnet")
+        assert verify_message_by_address(message, signature, other_address) is False
+
+    def test_verify_message_by_address_script_hash_raises(self, known_private_key):
+        """Test that ScriptHash addresses cannot be used for verification."""
+        message = "Hello Kaspa!"
+        signature = sign_message(message, known_private_key)
+
+        multisig = create_multisig_address(
+            1, [known_private_key.to_public_key()], "mainnet"
+        )
+        with pytest.raises(Exception, match="ScriptHash"):
+            verify_message_by_address(message, signature, multisig)
+
+    def test_verify_messages_batch(self, known_private_key, known_public_key):
+        """Test batch verification returns one result per item in order."""
+        signature = sign_message("first", known_private_key)
+        other_key = PrivateKey("1" * 64).to_public_key()
+
+        results = verify_messages([
+            ("first", signature, known_public_key),
+            ("second", signature, known_public_key),
+            ("first", bytes.fromhex(signature), known_public_key),
+            ("first", signature, other_key),
+            ("first", "aa", known_public_key),
+        ])
+        assert results == [True, False, True, False, False]
+
+    def test_verify_messages_empty(self):
+        """Test batch verification of an empty list."""
+        assert verify_messages([]) == []
+
     def test_sign_message_empty_string(self, known_private_key):
         """Test signing an empty message."""
         message = ""
@@ -178,6 +295,31 @@ class TestMessageSigning:
 
         assert isinstance(signature, str)
 
+    def test_keypair_sign_public_key_verify(self):
+        """Test signing with a Keypair and verifying with its PublicKey object."""
+        keypair = Keypair.random()
+        public_key = PublicKey(keypair.public_key)
+        message = "Hello Kaspa!"
+
+        signature = keypair.sign_message(message)
+
+        assert isinstance(signature, bytes)
+        assert len(signature) == 64
+        assert public_key.verify_message(message, signature) is True
+        assert public_key.verify_message("Wrong message", signature) is False
+        assert public_key.verify_message(message, signature[:32]) is False
+        assert verify_message(message, signature.hex(), public_key) is True
+
+    def test_keypair_sign_message_no_aux_rand(self):
+        """Test Keypair.sign_message matches the free function when deterministic."""
+        keypair = Keypair.random()
+        message = "Deterministic signing"
+
+        signature = keypair.sign_message(message, no_aux_rand=True)
+
+        expected = sign_message(message, PrivateKey(keypair.private_key), no_aux_rand=True)
+        assert signature.hex() == expected
+
 
 class TestHash:
     """Tests for Hash class."""
@@ -196,6 +338,64 @@ class TestHash:
         result = hash_obj.to_string()
         assert isinstance(result, str)
 
+    def test_hash_int_round_trip(self):
+        """Test a known hash round-trips through its big-endian integer form."""
+        hex_str = "00000000000000000000000000000000000000000000000000000000000001ff"
+        hash_obj = Hash(hex_str)
+
+        value = hash_obj.to_int()
+
+        assert value == 0x1FF
+        assert value == int.from_bytes(bytes(hash_obj), "big")
+        assert Hash.from_int(value) == hash_obj
+        assert Hash.from_int(0).to_string() == "0" * 64
+        assert Hash.from_int(2**256 - 1).to_string() == "f" * 64
+
+    def test_hash_from_int_out_of_range(self):
+        """Test integers outside 0..2**256 - 1 raise ValueError."""
+        with pytest.raises(ValueError):
+            Hash.from_int(-1)
+        with pytest.raises(ValueError):
+            Hash.from_int(2**256)
+
+
+class TestMerkle:
+    """Tests for merge_hashes and merkle_root."""
+
+    LEFT = Hash("01" * 32)
+    RIGHT = Hash("02" * 32)
+    # blake2b(LEFT || RIGHT, digest_size=32, key=b"MerkleBranchHash")
+    TWO_LEAF_ROOT = "c46dab3a891a57d2c71c84187595b756134df28011990a526c1c0416b7231c2e"
+
+    def test_merge_hashes_known_value(self):
+        """Test merging two hashes matches the keyed BLAKE2b branch hash."""
+        merged = merge_hashes(self.LEFT, self.RIGHT)
+
+        assert merged.to_string() == self.TWO_LEAF_ROOT
+        expected = hashlib.blake2b(
+            bytes(self.LEFT) + bytes(self.RIGHT), digest_size=32, key=b"MerkleBranchHash"
+        )
+        assert merged.to_string() == expected.hexdigest()
+
+    def test_merkle_root_two_leaves(self):
+        """Test the root of a two-leaf tree is the merge of both leaves."""
+        assert merkle_root([self.LEFT, self.RIGHT]).to_string() == self.TWO_LEAF_ROOT
+
+    def test_merkle_root_odd_leaves_pads_with_zero_hash(self):
+        """Test a missing right sibling is replaced by the zero hash."""
+        third = Hash("03" * 32)
+        zero = Hash("00" * 32)
+
+        expected = merge_hashes(
+            merge_hashes(self.LEFT, self.RIGHT), merge_hashes(third, zero)
+        )
+        assert merkle_root([self.LEFT, self.RIGHT, third]) == expected
+
+    def test_merkle_root_single_and_empty(self):
+        """Test a single leaf is its own root and an empty list yields the zero hash."""
+        assert merkle_root([self.LEFT]) == self.LEFT
+        assert merkle_root([]).to_string() == "0" * 64
+
 
 class TestAccountKind:
     """Tests for AccountKind class."""